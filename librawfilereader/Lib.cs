@@ -7,13 +7,16 @@ using ThermoFisher.CommonCore.Data.Interfaces;
 using ThermoFisher.CommonCore.RawFileReader;
 using ThermoFisher.CommonCore.Data.FilterEnums;
 using ThermoFisher.CommonCore.RandomAccessReaderPlugin;
+using PeakOptions = ThermoFisher.CommonCore.Data.PeakOptions;
 
 using Google.FlatBuffers;
 using System.Collections.Generic;
+using System.Collections.Concurrent;
 using System.Runtime.CompilerServices;
 using System.Linq;
 using System.Text;
 using System.Buffers.Binary;
+using System.Globalization;
 
 namespace librawfilereader
 {
@@ -24,6 +27,9 @@ namespace librawfilereader
         public double TargetMZ;
         public double UpperMZ;
 
+        /// <summary>
+        /// Center a window `isolationWidth` wide, the full width, on `monoisotopicMZ` shifted by `isolationOffset`
+        /// </summary>
         public IsolationWindow(double isolationWidth, double monoisotopicMZ, double isolationOffset)
         {
             LowerMZ = monoisotopicMZ + isolationOffset - isolationWidth / 2;
@@ -45,6 +51,9 @@ namespace librawfilereader
         public IsolationWindow IsolationWindow;
         public int MasterScanNumber;
         public ActivationProperties Activation;
+        public float Intensity;
+        /// <summary>The index of the scan filter reaction that activated this precursor</summary>
+        public int ReactionIndex;
     }
 
     public struct AcquisitionProperties
@@ -148,10 +157,20 @@ namespace librawfilereader
         FileNotFound,
         InvalidFormat,
         HandleNotFound,
+        ControllerNotFound,
 
         Error = 999
     }
 
+    /// <summary>
+    /// Thrown when a handle token does not refer to an open RAW file, reported to Rust as
+    /// `RawFileReaderError.HandleNotFound`
+    /// </summary>
+    public class HandleNotFoundException : Exception
+    {
+        public HandleNotFoundException(IntPtr handleToken) : base($"No open RAW file for handle {handleToken}") { }
+    }
+
     /// <summary>
     /// Represent a reader for a Thermo RAW file that packages spectra and metadata
     /// into FlatBuffers for ease of exchange.
@@ -167,7 +186,13 @@ namespace librawfilereader
         /// MS level
         /// </summary>
         Dictionary<int, List<int?>> PreviousMSLevels;
-        Dictionary<short, uint> MSLevelCounts;
+
+        /// <summary>
+        /// The number of spectra at each MS level, which requires visiting every scan filter
+        /// so it is built the first time it is needed when the file is opened lazily
+        /// </summary>
+        Lazy<Dictionary<short, uint>> MSLevelCountsIndex;
+        Dictionary<short, uint> MSLevelCounts => MSLevelCountsIndex.Value;
 
         /// <summary>
         /// An index look up mapping trailer keys by index that lets us avoid
@@ -189,7 +214,14 @@ namespace librawfilereader
         /// <summary>
         /// A static look up of instrument configurations to a unique identifier
         /// </summary>
-        public Dictionary<(MassAnalyzer, IonizationMode), long> InstrumentConfigsByComponents;
+        public Dictionary<(MassAnalyzer, IonizationMode), long> InstrumentConfigsByComponents => InstrumentConfigsIndex.Value;
+        Lazy<Dictionary<(MassAnalyzer, IonizationMode), long>> InstrumentConfigsIndex;
+
+        /// <summary>
+        /// Whether the indices that require visiting every scan are deferred until they are
+        /// first needed instead of being built when the file is opened
+        /// </summary>
+        public readonly bool LazyIndexing;
 
         /// <summary>
         /// Whether or not to include the actual mass spectrum data in the message buffers.
@@ -203,17 +235,50 @@ namespace librawfilereader
         /// </summary>
         public bool CentroidSpectra = false;
 
-        public RawFileReader(string path)
+        /// <summary>
+        /// The 1-based number of the MS controller this reader reads spectra from. Most files
+        /// only have a single MS controller.
+        /// </summary>
+        public int MSControllerNumber = 1;
+
+        /// <summary>
+        /// The tolerance used to match a precursor's m/z in its parent scan when looking up the
+        /// precursor's intensity. A tolerance of zero or less disables the lookup. The lookup is
+        /// only done when the spectrum signal is requested too.
+        /// </summary>
+        public double PrecursorIntensityTolerance = 20.0;
+
+        /// <summary>
+        /// Whether `PrecursorIntensityTolerance` is in parts-per-million (`true`) or in Daltons (`false`)
+        /// </summary>
+        public bool PrecursorIntensityTolerancePPM = true;
+
+        /// <summary>
+        /// Whether to look up the precursor's monoisotopic m/z (`true`) or the m/z selected for
+        /// isolation by the scan filter (`false`) in the parent scan
+        /// </summary>
+        public bool PrecursorIntensityUseMonoisotopicMZ = true;
+
+        /// <summary>
+        /// The signal of the most recently read parent scan, as consecutive MSn scans usually
+        /// share the same parent.
+        /// </summary>
+        (int ScanNumber, double[] Positions, double[] Intensities) ParentScanCache = (-1, Array.Empty<double>(), Array.Empty<double>());
+        readonly object ParentScanCacheLock = new();
+
+        public RawFileReader(string path, int msControllerNumber = 1, bool lazyIndexing = false)
         {
             Path = path;
+            MSControllerNumber = msControllerNumber;
+            LazyIndexing = lazyIndexing;
             Manager = RawFileReaderAdapter.RandomAccessThreadedFileFactory(Path, RandomAccessFileManager.Instance);
             // Manager = RawFileReaderAdapter.ThreadedFileFactory(Path);
             // Handle = RawFileReaderAdapter.FileFactory(Path);
             Status = RawFileReaderError.Ok;
-            InstrumentConfigsByComponents = new();
+            InstrumentConfigsIndex = new(FindAllMassAnalyzers);
             PreviousMSLevels = new();
             TrailerMap = new();
-            MSLevelCounts = new();
+            MSLevelCountsIndex = new(BuildScanTypeMap);
             Status = Configure();
         }
 
@@ -227,12 +292,26 @@ namespace librawfilereader
         IRawDataPlus GetHandle()
         {
             var accessor = GetHandleRaw();
-            accessor.SelectInstrument(Device.MS, 1);
+            accessor.SelectInstrument(Device.MS, MSControllerNumber);
             accessor.IncludeReferenceAndExceptionData = true;
             return accessor;
             // return Handle;
         }
 
+        IRawDataPlus GetHandleForDevice(Device device, int deviceNumber)
+        {
+            var accessor = GetHandleRaw();
+            accessor.SelectInstrument(device, deviceNumber);
+            return accessor;
+        }
+
+        /// <summary>
+        /// Count the number of devices of a particular kind that recorded data in this file
+        /// </summary>
+        public int DeviceCount(Device device) {
+            return GetHandleRaw().GetInstrumentCountOfType(device);
+        }
+
         public string FileErrorMessage() {
             var accessor = GetHandleRaw();
             string buffer = "";
@@ -353,11 +432,96 @@ namespace librawfilereader
             return activation;
         }
 
+        /// <summary>
+        /// Describe every activation applied to one precursor of a scan. The reaction at `reactionIndex`
+        /// and any following reactions that re-activate the same precursor become the activation steps, while
+        /// stepped and absolute collision energies and ETD reaction parameters are read from the trailer extra
+        /// values when the instrument recorded them.
+        /// </summary>
+        Offset<ActivationDetailsT> StoreActivationDetails(FlatBufferBuilder builder, int scanNumber, int reactionIndex, IScanFilter filter, IRawDataPlus accessor)
+        {
+            var steps = new List<(DissociationMethod, double, bool)>();
+            double? supplementalEnergy = null;
+            for (var i = reactionIndex; i < filter.MassCount; i++)
+            {
+                var supplemental = i > reactionIndex;
+                if (supplemental && !filter.GetIsMultipleActivation(i)) break;
+                DissociationMethod dissociation;
+                if (!DissociationMethodMap.TryGetValue(filter.GetActivation(i), out dissociation))
+                {
+                    dissociation = DissociationMethod.Unknown;
+                }
+                var energy = filter.GetEnergy(i);
+                if (supplemental && supplementalEnergy == null)
+                {
+                    supplementalEnergy = energy;
+                }
+                steps.Add((dissociation, energy, supplemental));
+            }
+
+            var steppedEnergies = new List<double>();
+            if (TrailerMap.TryGetValue(SteppedCollisionEnergyKey, out var headerIdx))
+            {
+                var value = Convert.ToString(accessor.GetTrailerExtraValue(scanNumber, headerIdx), CultureInfo.InvariantCulture);
+                if (!string.IsNullOrWhiteSpace(value))
+                {
+                    foreach (var token in value.Split(',', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries))
+                    {
+                        if (double.TryParse(token, NumberStyles.Float, CultureInfo.InvariantCulture, out var energy))
+                        {
+                            steppedEnergies.Add(energy);
+                        }
+                    }
+                }
+            }
+
+            // Structs in a vector are written in reverse order
+            ActivationDetailsT.StartStepsVector(builder, steps.Count);
+            for (var i = steps.Count - 1; i >= 0; i--)
+            {
+                var (dissociation, energy, supplemental) = steps[i];
+                ActivationStepT.CreateActivationStepT(builder, dissociation, energy, supplemental);
+            }
+            var stepsOffset = builder.EndVector();
+
+            VectorOffset steppedEnergiesOffset = default;
+            if (steppedEnergies.Count > 1)
+            {
+                steppedEnergiesOffset = ActivationDetailsT.CreateSteppedCollisionEnergiesVector(builder, steppedEnergies.ToArray());
+            }
+
+            ActivationDetailsT.StartActivationDetailsT(builder);
+            ActivationDetailsT.AddSteps(builder, stepsOffset);
+            if (steppedEnergies.Count > 1)
+            {
+                ActivationDetailsT.AddSteppedCollisionEnergies(builder, steppedEnergiesOffset);
+            }
+            ActivationDetailsT.AddSupplementalEnergy(builder, supplementalEnergy);
+            if (GetDoubleTrailerExtraFor(accessor, scanNumber, CollisionEnergyEVKeys, out var energyEV))
+            {
+                ActivationDetailsT.AddCollisionEnergyEv(builder, energyEV);
+            }
+            if (GetDoubleTrailerExtraFor(accessor, scanNumber, ETDReactionTimeKeys, out var reactionTime))
+            {
+                ActivationDetailsT.AddEtdReactionTime(builder, reactionTime);
+            }
+            if (GetDoubleTrailerExtraFor(accessor, scanNumber, ETDReagentTargetKeys, out var reagentTarget))
+            {
+                ActivationDetailsT.AddEtdReagentTarget(builder, reagentTarget);
+            }
+            return ActivationDetailsT.EndActivationDetailsT(builder);
+        }
+
         private const string InjectionTimeKey = "Ion Injection Time (ms)";
         private const string ScanEventKey = "Scan Evnet";
         private const string MasterScanKey = "Master Scan";
         private const string MonoisotopicMZKey = "Monoisotopic M/Z";
         private const string ChargeStateKey = "Charge State";
+        private static readonly string[] SPSMassesKeys = ["SPS Masses", "SPS Masses Continued"];
+        private const string SteppedCollisionEnergyKey = "HCD Energy";
+        private static readonly string[] CollisionEnergyEVKeys = ["HCD Energy eV", "HCD Energy V"];
+        private static readonly string[] ETDReactionTimeKeys = ["ETD Reaction Time (ms)", "ETD Reaction Time"];
+        private static readonly string[] ETDReagentTargetKeys = ["ETD Reagent Target", "Reagent Target"];
         private static readonly string[] IsolationLevelKeys = [
             "MS2 Isolation Width",
             "MS3 Isolation Width",
@@ -477,6 +641,18 @@ namespace librawfilereader
             return false;
         }
 
+        bool GetDoubleTrailerExtraFor(IRawDataPlus accessor, int scanNumber, string[] keys, out double value) {
+            foreach (var key in keys)
+            {
+                if (GetDoubleTrailerExtraFor(accessor, scanNumber, key, out value))
+                {
+                    return true;
+                }
+            }
+            value = 0;
+            return false;
+        }
+
         bool GetDoubleTrailerExtraFor(IRawDataPlus accessor, int scanNumber, string key, out double value) {
             object tmp;
             HeaderItem header;
@@ -517,7 +693,7 @@ namespace librawfilereader
             return false;
         }
 
-        (PrecursorProperties?, AcquisitionProperties) ExtractPrecursorAndTrailerMetadata(int scanNumber, short msLevel, IScanFilter filter, IRawDataPlus accessor, ScanStatistics stats)
+        (PrecursorProperties?, AcquisitionProperties) ExtractPrecursorAndTrailerMetadata(int scanNumber, short msLevel, IScanFilter filter, IRawDataPlus accessor, ScanStatistics stats, bool lookupIntensity)
         {
             var trailers = accessor.GetTrailerExtraInformation(scanNumber);
 
@@ -562,7 +738,7 @@ namespace librawfilereader
 
             if (msLevel > 1 && isolationWidth == 0.0)
             {
-                isolationWidth = filter.GetIsolationWidth(msLevel - 2) / 2;
+                isolationWidth = filter.GetIsolationWidth(msLevel - 2);
             }
             if (msLevel > 1)
             {
@@ -580,13 +756,16 @@ namespace librawfilereader
 
                 ActivationProperties activation = ExtractActivation(scanNumber, msLevel, filter);
                 IsolationWindow window = new IsolationWindow(isolationWidth, monoisotopicMZ, isolationOffset);
+                var lookupMZ = PrecursorIntensityUseMonoisotopicMZ ? monoisotopicMZ : filter.GetMass(msLevel - 2);
                 PrecursorProperties props = new PrecursorProperties
                 {
                     PrecursorCharge = precursorCharge,
                     MasterScanNumber = masterScanNumber,
                     MonoisotopicMZ = monoisotopicMZ,
                     IsolationWindow = window,
-                    Activation = activation
+                    Activation = activation,
+                    Intensity = lookupIntensity ? FindPrecursorIntensity(accessor, masterScanNumber, lookupMZ, window) : 0,
+                    ReactionIndex = msLevel - 2
                 };
                 return (props, acquisitionProperties);
             }
@@ -596,6 +775,134 @@ namespace librawfilereader
             }
         }
 
+        /// <summary>
+        /// List every precursor isolated for a scan. Multiplexed scans isolate one precursor per reaction
+        /// and SPS scans co-isolate each of the "SPS Masses" from the parent scan. Otherwise, this is just
+        /// the `primary` precursor.
+        /// </summary>
+        /// <param name="lookupIntensity">Whether to look up each precursor's intensity in the parent scan</param>
+        List<PrecursorProperties> ExtractPrecursors(int scanNumber, short msLevel, IScanFilter filter, IRawDataPlus accessor, PrecursorProperties primary, bool lookupIntensity)
+        {
+            var precursors = new List<PrecursorProperties>();
+            if (filter.Multiplex == TriState.On && filter.MassCount > 1)
+            {
+                for (var i = 0; i < filter.MassCount; i++)
+                {
+                    // Supplemental activations of the same precursor are not distinct precursors
+                    if (filter.GetIsMultipleActivation(i)) continue;
+                    var mz = filter.GetMass(i);
+                    var activation = new ActivationProperties
+                    {
+                        Dissociation = DissociationMethod.Unknown,
+                        Energy = filter.GetEnergy(i)
+                    };
+                    DissociationMethodMap.TryGetValue(filter.GetActivation(i), out activation.Dissociation);
+                    var window = new IsolationWindow(filter.GetIsolationWidth(i), mz, filter.GetIsolationWidthOffset(i));
+                    precursors.Add(new PrecursorProperties
+                    {
+                        MonoisotopicMZ = mz,
+                        IsolationWindow = window,
+                        MasterScanNumber = primary.MasterScanNumber,
+                        Activation = activation,
+                        Intensity = lookupIntensity ? FindPrecursorIntensity(accessor, primary.MasterScanNumber, mz, window) : 0,
+                        ReactionIndex = i
+                    });
+                }
+            }
+            else if (filter.MultiNotch == TriState.On)
+            {
+                var isolationWidth = filter.GetIsolationWidth(msLevel - 2);
+                foreach (var key in SPSMassesKeys)
+                {
+                    if (!TrailerMap.TryGetValue(key, out var headerIdx)) continue;
+                    var value = accessor.GetTrailerExtraValue(scanNumber, headerIdx) as string;
+                    if (string.IsNullOrWhiteSpace(value)) continue;
+                    foreach (var token in value.Split(',', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries))
+                    {
+                        if (!double.TryParse(token, NumberStyles.Float, CultureInfo.InvariantCulture, out var mz) || mz <= 0) continue;
+                        var window = new IsolationWindow(isolationWidth, mz, 0);
+                        precursors.Add(new PrecursorProperties
+                        {
+                            MonoisotopicMZ = mz,
+                            IsolationWindow = window,
+                            MasterScanNumber = primary.MasterScanNumber,
+                            Activation = primary.Activation,
+                            Intensity = lookupIntensity ? FindPrecursorIntensity(accessor, primary.MasterScanNumber, mz, window) : 0,
+                            ReactionIndex = primary.ReactionIndex
+                        });
+                    }
+                }
+            }
+
+            if (precursors.Count == 0)
+            {
+                precursors.Add(primary);
+            }
+            return precursors;
+        }
+
+        /// <summary>
+        /// Find the intensity of the most intense signal in the parent scan within `PrecursorIntensityTolerance`
+        /// of `mz` that also falls inside the isolation window.
+        /// </summary>
+        /// <param name="parentIndex">The 0-based index of the parent scan, or -1 if it is unknown</param>
+        /// <returns>The intensity, or 0 if the lookup is disabled or no signal matched</returns>
+        float FindPrecursorIntensity(IRawDataPlus accessor, int parentIndex, double mz, IsolationWindow window)
+        {
+            if (PrecursorIntensityTolerance <= 0 || parentIndex < 0 || mz <= 0)
+            {
+                return 0;
+            }
+            var width = PrecursorIntensityTolerancePPM ? mz * PrecursorIntensityTolerance / 1e6 : PrecursorIntensityTolerance;
+            var lowMZ = Math.Max(mz - width, window.LowerMZ);
+            var highMZ = Math.Min(mz + width, window.UpperMZ);
+            if (lowMZ > highMZ)
+            {
+                return 0;
+            }
+
+            var parentScan = parentIndex + 1;
+            (int ScanNumber, double[] Positions, double[] Intensities) parent;
+            lock (ParentScanCacheLock)
+            {
+                parent = ParentScanCache;
+            }
+            if (parent.ScanNumber != parentScan)
+            {
+                var segScan = accessor.GetSegmentedScanFromScanNumber(parentScan, null);
+                parent = (parentScan, segScan.Positions, segScan.Intensities);
+                lock (ParentScanCacheLock)
+                {
+                    ParentScanCache = parent;
+                }
+            }
+
+            var start = Array.BinarySearch(parent.Positions, lowMZ);
+            if (start < 0) start = ~start;
+            double intensity = 0;
+            for (var i = start; i < parent.Positions.Length && parent.Positions[i] <= highMZ; i++)
+            {
+                intensity = Math.Max(intensity, parent.Intensities[i]);
+            }
+            return (float)intensity;
+        }
+
+        /// <summary>
+        /// Pack a centroid peak's label flags into the bit set stored in `SpectrumData.flags`
+        /// </summary>
+        static byte PackPeakFlags(PeakOptions options)
+        {
+            byte flags = 0;
+            if (options.HasFlag(PeakOptions.Saturated)) flags |= 1;
+            if (options.HasFlag(PeakOptions.Fragmented)) flags |= 2;
+            if (options.HasFlag(PeakOptions.Merged)) flags |= 4;
+            if (options.HasFlag(PeakOptions.Exception)) flags |= 8;
+            if (options.HasFlag(PeakOptions.Reference)) flags |= 16;
+            if (options.HasFlag(PeakOptions.Modified)) flags |= 32;
+            if (options.HasFlag(PeakOptions.LockPeak)) flags |= 64;
+            return flags;
+        }
+
         Offset<SpectrumData> StoreSpectrumData(int scanNumber, ScanStatistics stats, FlatBufferBuilder bufferBuilder, IRawDataPlus accessor, bool centroidSpectra)
         {
             // We have to write arrays in reverse order because FlatBuffers writes entries back-to-front.
@@ -621,7 +928,19 @@ namespace librawfilereader
                 }
                 var intensityOffset = bufferBuilder.EndVector();
 
-                offset = SpectrumData.CreateSpectrumData(bufferBuilder, mzOffset, intensityOffset);
+                // Leave the flags out when the label stream doesn't have one for every peak
+                VectorOffset flagsOffset = default;
+                if (stream.Flags != null && stream.Flags.Length == centroids.Count)
+                {
+                    var flags = new byte[centroids.Count];
+                    for (var i = 0; i < flags.Length; i++)
+                    {
+                        flags[i] = PackPeakFlags(stream.Flags[i]);
+                    }
+                    flagsOffset = SpectrumData.CreateFlagsVector(bufferBuilder, flags);
+                }
+
+                offset = SpectrumData.CreateSpectrumData(bufferBuilder, mzOffset, intensityOffset, flagsOffset);
             }
             else
             {
@@ -640,7 +959,22 @@ namespace librawfilereader
                     bufferBuilder.AddFloat((float)val);
                 }
                 var intensityOffset = bufferBuilder.EndVector();
-                offset = SpectrumData.CreateSpectrumData(bufferBuilder, mzOffset, intensityOffset);
+
+                // Only profile spectra with more than one segment need their boundaries
+                VectorOffset segmentOffsetsOffset = default;
+                if (!stats.IsCentroidScan && segScan.SegmentCount > 1)
+                {
+                    var segmentOffsets = new uint[segScan.SegmentCount];
+                    uint segmentStart = 0;
+                    for (var i = 0; i < segScan.SegmentCount; i++)
+                    {
+                        segmentOffsets[i] = segmentStart;
+                        segmentStart += (uint)segScan.SegmentSizes[i];
+                    }
+                    segmentOffsetsOffset = SpectrumData.CreateSegmentOffsetsVector(bufferBuilder, segmentOffsets);
+                }
+
+                offset = SpectrumData.CreateSpectrumData(bufferBuilder, mzOffset, intensityOffset, default, segmentOffsetsOffset);
             }
             return offset;
         }
@@ -954,61 +1288,498 @@ namespace librawfilereader
             return builder.DataBuffer;
         }
 
+        static TrailerDataType ToTrailerDataType(GenericDataTypes dataType) {
+            return dataType switch
+            {
+                GenericDataTypes.CHAR => TrailerDataType.Char,
+                GenericDataTypes.Bool => TrailerDataType.TrueFalse,
+                GenericDataTypes.YESNO => TrailerDataType.YesNo,
+                GenericDataTypes.ONOFF => TrailerDataType.OnOff,
+                GenericDataTypes.UCHAR => TrailerDataType.UChar,
+                GenericDataTypes.SHORT => TrailerDataType.Short,
+                GenericDataTypes.USHORT => TrailerDataType.UShort,
+                GenericDataTypes.LONG => TrailerDataType.Long,
+                GenericDataTypes.ULONG => TrailerDataType.ULong,
+                GenericDataTypes.FLOAT => TrailerDataType.Float,
+                GenericDataTypes.DOUBLE => TrailerDataType.Double,
+                GenericDataTypes.CHAR_STRING => TrailerDataType.CharString,
+                GenericDataTypes.WCHAR_STRING => TrailerDataType.WCharString,
+                _ => TrailerDataType.Null,
+            };
+        }
+
+        /// <summary>
+        /// Convert a trailer extra value to the representation matching its declared data type.
+        /// Floating point types are stored in `floatValue`, integer and boolean types in `intValue`
+        /// and string types in `stringValue`. The single byte `Char` and `UChar` types are read as
+        /// `sbyte` and `byte` respectively, so both are integers.
+        /// </summary>
+        /// <returns>Whether the value was present and could be converted</returns>
+        static bool TryConvertTrailerValue(TrailerDataType dataType, object value, out double floatValue, out long intValue, out string stringValue) {
+            floatValue = 0.0;
+            intValue = 0;
+            stringValue = null;
+            if (value == null) {
+                return false;
+            }
+            try {
+                switch (dataType) {
+                    case TrailerDataType.Float:
+                    case TrailerDataType.Double:
+                        {
+                            floatValue = Convert.ToDouble(value);
+                            return true;
+                        }
+                    case TrailerDataType.TrueFalse:
+                    case TrailerDataType.YesNo:
+                    case TrailerDataType.OnOff:
+                        {
+                            intValue = Convert.ToBoolean(value) ? 1 : 0;
+                            return true;
+                        }
+                    case TrailerDataType.Char:
+                    case TrailerDataType.UChar:
+                    case TrailerDataType.Short:
+                    case TrailerDataType.UShort:
+                    case TrailerDataType.Long:
+                    case TrailerDataType.ULong:
+                        {
+                            intValue = Convert.ToInt64(value);
+                            return true;
+                        }
+                    case TrailerDataType.CharString:
+                    case TrailerDataType.WCharString:
+                        {
+                            stringValue = value.ToString();
+                            return true;
+                        }
+                    default:
+                        {
+                            return false;
+                        }
+                }
+            } catch (Exception e) when (e is InvalidCastException || e is FormatException || e is OverflowException) {
+                return false;
+            }
+        }
+
+        /// <summary>
+        /// Store a trailer extra value in the slot of a `TypedTrailerValueT` that matches
+        /// the data type declared by its header. Values that cannot be converted are stored
+        /// with the `Null` data type.
+        /// </summary>
+        static Offset<TypedTrailerValueT> StoreTypedTrailerValue(FlatBufferBuilder builder, HeaderItem header, object value) {
+            var dataType = ToTrailerDataType(header.DataType);
+            if (!TryConvertTrailerValue(dataType, value, out var floatValue, out var intValue, out var stringValue)) {
+                dataType = TrailerDataType.Null;
+            }
+            var labelOffset = builder.CreateString(header.Label.TrimEnd(':'));
+            var stringOffset = stringValue != null ? builder.CreateString(stringValue) : default;
+            return TypedTrailerValueT.CreateTypedTrailerValueT(builder, labelOffset, dataType, floatValue, intValue, stringOffset);
+        }
+
+        /// <summary>
+        /// Describe the label and declared data type of each trailer extra header
+        /// </summary>
+        public ByteBuffer GetTrailerHeaders() {
+            FlatBufferBuilder builder = new FlatBufferBuilder(1024);
+            var headerOffsets = new Offset<TrailerHeaderT>[Headers.Length];
+            for (var i = 0; i < Headers.Length; i++) {
+                var header = Headers[i];
+                var labelOffset = builder.CreateString(header.Label.TrimEnd(':'));
+                headerOffsets[i] = TrailerHeaderT.CreateTrailerHeaderT(builder, labelOffset, ToTrailerDataType(header.DataType), header.StringLengthOrPrecision);
+            }
+            var headersOffset = TrailerHeaderCollectionT.CreateHeadersVector(builder, headerOffsets);
+            var offset = TrailerHeaderCollectionT.CreateTrailerHeaderCollectionT(builder, headersOffset);
+            builder.Finish(offset.Value);
+            return builder.DataBuffer;
+        }
+
+        /// <summary>
+        /// Read the trailer extra values for a scan, keeping the data type declared by each header
+        /// </summary>
+        public ByteBuffer GetTypedTrailersForScan(int scanNumber) {
+            var accessor = GetHandle();
+
+            FlatBufferBuilder builder = new FlatBufferBuilder(1024);
+            var trailerOffsets = new Offset<TypedTrailerValueT>[Headers.Length];
+            for (var i = 0; i < Headers.Length; i++) {
+                var value = accessor.GetTrailerExtraValue(scanNumber, i);
+                trailerOffsets[i] = StoreTypedTrailerValue(builder, Headers[i], value);
+            }
+            var trailersOffset = TypedTrailerValuesT.CreateTrailersVector(builder, trailerOffsets);
+            var offset = TypedTrailerValuesT.CreateTypedTrailerValuesT(builder, trailersOffset);
+            builder.Finish(offset.Value);
+            return builder.DataBuffer;
+        }
+
+        /// <summary>
+        /// Read the values of a single trailer extra header for every scan from `startScan` to `endScan`
+        /// inclusive, along with the retention time of each scan. Values that are missing or cannot be
+        /// converted are stored as `NaN`, 0 or the empty string depending upon the data type.
+        ///
+        /// If there is no trailer with the given label, the returned message has no label.
+        /// </summary>
+        public ByteBuffer GetTrailerColumn(string label, int startScan, int endScan) {
+            var accessor = GetHandle();
+            FlatBufferBuilder builder = new FlatBufferBuilder(1024);
+
+            int headerIdx;
+            if (!TrailerMap.TryGetValue(label, out headerIdx)) {
+                var emptyOffset = TrailerColumnT.CreateTrailerColumnT(builder);
+                builder.Finish(emptyOffset.Value);
+                return builder.DataBuffer;
+            }
+            var header = Headers[headerIdx];
+            var dataType = ToTrailerDataType(header.DataType);
+
+            var n = Math.Max(endScan - startScan + 1, 0);
+            var times = new double[n];
+            var floatValues = new double[n];
+            var intValues = new long[n];
+            var stringValues = new string[n];
+            var valid = new bool[n];
+            for (var i = 0; i < n; i++) {
+                var scanNumber = startScan + i;
+                times[i] = accessor.RetentionTimeFromScanNumber(scanNumber);
+                var value = accessor.GetTrailerExtraValue(scanNumber, headerIdx);
+                if (TryConvertTrailerValue(dataType, value, out var floatValue, out var intValue, out var stringValue)) {
+                    floatValues[i] = floatValue;
+                    intValues[i] = intValue;
+                    stringValues[i] = stringValue;
+                    valid[i] = true;
+                } else {
+                    floatValues[i] = double.NaN;
+                }
+            }
+
+            var labelOffset = builder.CreateString(label);
+            var timesOffset = TrailerColumnT.CreateTimesVector(builder, times);
+            VectorOffset floatValuesOffset = default;
+            VectorOffset intValuesOffset = default;
+            VectorOffset stringValuesOffset = default;
+            switch (dataType) {
+                case TrailerDataType.Float:
+                case TrailerDataType.Double:
+                    {
+                        floatValuesOffset = TrailerColumnT.CreateFloatValuesVector(builder, floatValues);
+                        break;
+                    }
+                case TrailerDataType.CharString:
+                case TrailerDataType.WCharString:
+                    {
+                        var stringOffsets = stringValues.Select(v => builder.CreateString(v ?? "")).ToArray();
+                        stringValuesOffset = TrailerColumnT.CreateStringValuesVector(builder, stringOffsets);
+                        break;
+                    }
+                case TrailerDataType.Null:
+                    {
+                        break;
+                    }
+                default:
+                    {
+                        intValuesOffset = TrailerColumnT.CreateIntValuesVector(builder, intValues);
+                        break;
+                    }
+            }
+            var validOffset = TrailerColumnT.CreateValidVector(builder, valid);
+            var offset = TrailerColumnT.CreateTrailerColumnT(builder, labelOffset, dataType, timesOffset, floatValuesOffset, intValuesOffset, stringValuesOffset, validOffset);
+            builder.Finish(offset.Value);
+            return builder.DataBuffer;
+        }
+
+        /// <summary>
+        /// Read the instrument tune settings for every tune segment in the file, converting each value
+        /// to the data type declared by its tune data header
+        /// </summary>
+        public ByteBuffer GetTuneData() {
+            var accessor = GetHandle();
+            var count = accessor.GetTuneDataCount();
+            var headers = accessor.GetTuneDataHeaderInformation();
+
+            FlatBufferBuilder builder = new FlatBufferBuilder(4096);
+            var segmentOffsets = new Offset<TuneDataSegmentT>[count];
+            for (var segment = 0; segment < count; segment++) {
+                var values = accessor.GetTuneDataValues(segment);
+                var n = Math.Min(headers.Length, values.Length);
+                var valueOffsets = new Offset<TypedTrailerValueT>[n];
+                for (var i = 0; i < n; i++) {
+                    valueOffsets[i] = StoreTypedTrailerValue(builder, headers[i], values[i]);
+                }
+                var valuesOffset = TuneDataSegmentT.CreateValuesVector(builder, valueOffsets);
+                segmentOffsets[segment] = TuneDataSegmentT.CreateTuneDataSegmentT(builder, segment, valuesOffset);
+            }
+
+            var segmentsOffset = TuneDataT.CreateSegmentsVector(builder, segmentOffsets);
+            var offset = TuneDataT.CreateTuneDataT(builder, segmentsOffset);
+            builder.Finish(offset.Value);
+            return builder.DataBuffer;
+        }
+
         public ByteBuffer GetSummaryTrace(TraceType traceType) {
+            return GetSummaryTrace(traceType, null, double.NegativeInfinity, double.PositiveInfinity);
+        }
+
+        /// <summary>
+        /// Build a summary chromatogram like the TIC or base peak chromatogram over the scans matching
+        /// a scan filter within a retention time window.
+        /// </summary>
+        /// <param name="traceType">The kind of chromatogram to build</param>
+        /// <param name="filter">A scan filter string restricting which scans contribute, or `null` for all</param>
+        /// <param name="startTime">The earliest retention time to include, in minutes</param>
+        /// <param name="endTime">The latest retention time to include, in minutes</param>
+        public ByteBuffer GetSummaryTrace(TraceType traceType, string filter, double startTime, double endTime) {
+            ValidateTimeRange(startTime, endTime);
             var accessor = GetHandle();
+            var (startScan, endScan) = ScanRangeForTimeRange(accessor, startTime, endTime);
+            if (endScan < startScan) {
+                return StoreEmptyChromatogramDescription(traceType, startScan, endScan);
+            }
             var ticSettings = new ChromatogramTraceSettings(traceType);
-            var tic = accessor.GetChromatogramDataEx([ticSettings], FirstSpectrum(), LastSpectrum());
+            if (filter != null) ticSettings.Filter = filter;
+            var tic = accessor.GetChromatogramDataEx([ticSettings], startScan, endScan);
             var signals = ChromatogramSignal.FromChromatogramData(tic);
             var signal = signals[0];
 
+            return StoreChromatogramDescription(signal, traceType, startScan, endScan);
+        }
+
+        ByteBuffer StoreChromatogramDescription(ChromatogramSignal signal, TraceType traceType, int startScan, int endScan) {
             var builder = new FlatBufferBuilder(4096);
             Offset<ChromatogramData> dataOffset = StoreChromatogramData(signal, builder);
 
             ChromatogramDescription.StartChromatogramDescription(builder);
             ChromatogramDescription.AddTraceType(builder, (TraceTypeT)(int)traceType);
-            ChromatogramDescription.AddStartIndex(builder, FirstSpectrum());
-            ChromatogramDescription.AddEndIndex(builder, LastSpectrum());
+            ChromatogramDescription.AddStartIndex(builder, startScan);
+            ChromatogramDescription.AddEndIndex(builder, endScan);
             ChromatogramDescription.AddData(builder, dataOffset);
             var description = ChromatogramDescription.EndChromatogramDescription(builder);
             builder.Finish(description.Value);
             return builder.DataBuffer;
         }
 
-        Polarity GetPolarity(IScanFilter filter)
-        {
-            Polarity polarity = Polarity.Positive;
-            if (PolarityType.Positive == filter.Polarity)
-            {
-                polarity = Polarity.Positive;
-            }
-            else if (PolarityType.Negative == filter.Polarity)
-            {
-                polarity = Polarity.Negative;
-            }
-            else
-            {
-                polarity = Polarity.Unknown;
-            }
-            return polarity;
+        ByteBuffer StoreEmptyChromatogramDescription(TraceType traceType, int startScan, int endScan) {
+            var signal = ChromatogramSignal.FromTimeAndIntensity(Array.Empty<double>(), Array.Empty<double>());
+            return StoreChromatogramDescription(signal, traceType, startScan, endScan);
         }
 
-        Offset<AcquisitionT> StoreAcquisition(FlatBufferBuilder builder, AcquisitionProperties acquisitionProperties, IScanFilter filter)
-        {
-            VectorOffset voltagesOffset = default;
-            if (acquisitionProperties.CompensationVoltage.Count > 0)
-            {
-                // Convert List<double> to float[] for FlatBuffer
-                float[] voltageArray = acquisitionProperties.CompensationVoltage.Select(v => (float)v).ToArray();
-                voltagesOffset = AcquisitionT.CreateCompensationVoltagesVector(builder, voltageArray);
+        /// <summary>
+        /// Reject a retention time window that ends before it starts
+        /// </summary>
+        static void ValidateTimeRange(double startTime, double endTime) {
+            if (!(startTime <= endTime)) {
+                throw new ArgumentException($"The retention time window {startTime} to {endTime} ends before it starts");
             }
-            AcquisitionT.StartAcquisitionT(builder);
-            AcquisitionT.AddInjectionTime(builder, (float)acquisitionProperties.InjectionTime);
-            if (acquisitionProperties.CompensationVoltage.Count > 0)
-            {
-                AcquisitionT.AddCompensationVoltages(builder, voltagesOffset);
+        }
+
+        /// <summary>
+        /// Find the first and last scan numbers of the selected device whose retention times fall within
+        /// a time window in minutes, inclusive. If no scans fall within the window, the last scan number
+        /// will be less than the first.
+        /// </summary>
+        (int, int) ScanRangeForTimeRange(IRawDataPlus accessor, double startTime, double endTime) {
+            var header = accessor.RunHeaderEx;
+            if (header.SpectraCount == 0 || endTime < startTime || endTime < header.StartTime || startTime > header.EndTime) {
+                return (header.FirstSpectrum, header.FirstSpectrum - 1);
             }
-            AcquisitionT.AddLowMz(builder, acquisitionProperties.LowMZ);
+
+            // The closest scan to each bound may fall just outside of the window
+            var startScan = accessor.ScanNumberFromRetentionTime(Math.Max(startTime, header.StartTime));
+            if (accessor.RetentionTimeFromScanNumber(startScan) < startTime) startScan += 1;
+
+            var endScan = accessor.ScanNumberFromRetentionTime(Math.Min(endTime, header.EndTime));
+            if (accessor.RetentionTimeFromScanNumber(endScan) > endTime) endScan -= 1;
+            return (startScan, endScan);
+        }
+
+        /// <summary>
+        /// Build a chromatogram from a non-MS device like a UV detector, PDA or analog input channel.
+        /// </summary>
+        /// <param name="device">The kind of device to read from</param>
+        /// <param name="deviceNumber">The 1-based number of the device of that kind</param>
+        /// <param name="traceType">The kind of chromatogram to build, which must be appropriate for the device</param>
+        /// <param name="lowRange">The lower bound of the wavelength range for `TraceType.WavelengthRange`</param>
+        /// <param name="highRange">The upper bound of the wavelength range for `TraceType.WavelengthRange`</param>
+        /// <param name="startTime">The earliest retention time to include, in minutes</param>
+        /// <param name="endTime">The latest retention time to include, in minutes</param>
+        public ByteBuffer GetDeviceTrace(Device device, int deviceNumber, TraceType traceType, double lowRange, double highRange, double startTime, double endTime) {
+            ValidateTimeRange(startTime, endTime);
+            var accessor = GetHandleForDevice(device, deviceNumber);
+            // A device that recorded no scans or no scans in the window has an empty trace
+            var (startScan, endScan) = ScanRangeForTimeRange(accessor, startTime, endTime);
+            if (endScan < startScan) {
+                return StoreEmptyChromatogramDescription(traceType, startScan, endScan);
+            }
+            var settings = new ChromatogramTraceSettings(traceType);
+            if (traceType == TraceType.WavelengthRange) {
+                settings.MassRanges = [ThermoFisher.CommonCore.Data.Business.Range.Create(lowRange, highRange)];
+            }
+            var traces = accessor.GetChromatogramDataEx([settings], startScan, endScan);
+            var signals = ChromatogramSignal.FromChromatogramData(traces);
+            return StoreChromatogramDescription(signals[0], traceType, startScan, endScan);
+        }
+
+        /// <summary>
+        /// Count the number of scans recorded by a device
+        /// </summary>
+        public int DeviceSpectrumCount(Device device, int deviceNumber) {
+            return GetHandleForDevice(device, deviceNumber).RunHeaderEx.SpectraCount;
+        }
+
+        /// <summary>
+        /// Read an absorbance spectrum from a PDA or scanning UV device
+        /// </summary>
+        /// <param name="device">The kind of device to read from</param>
+        /// <param name="deviceNumber">The 1-based number of the device of that kind</param>
+        /// <param name="scanNumber">The scan number on that device to read</param>
+        public ByteBuffer GetWavelengthSpectrum(Device device, int deviceNumber, int scanNumber) {
+            var accessor = GetHandleForDevice(device, deviceNumber);
+            var stats = accessor.GetScanStatsForScanNumber(scanNumber);
+            var scan = accessor.GetSegmentedScanFromScanNumber(scanNumber, stats);
+
+            var builder = new FlatBufferBuilder(4096);
+
+            WavelengthSpectrumT.StartWavelengthVector(builder, scan.Positions.Length);
+            foreach (var w in scan.Positions.Reverse()) {
+                builder.AddDouble(w);
+            }
+            var wavelengthOffset = builder.EndVector();
+
+            WavelengthSpectrumT.StartAbsorbanceVector(builder, scan.Intensities.Length);
+            foreach (var a in scan.Intensities.Reverse()) {
+                builder.AddFloat((float)a);
+            }
+            var absorbanceOffset = builder.EndVector();
+
+            WavelengthSpectrumT.StartWavelengthSpectrumT(builder);
+            WavelengthSpectrumT.AddIndex(builder, scanNumber);
+            WavelengthSpectrumT.AddTime(builder, stats.StartTime);
+            WavelengthSpectrumT.AddLowWavelength(builder, stats.ShortWavelength);
+            WavelengthSpectrumT.AddHighWavelength(builder, stats.LongWavelength);
+            WavelengthSpectrumT.AddWavelength(builder, wavelengthOffset);
+            WavelengthSpectrumT.AddAbsorbance(builder, absorbanceOffset);
+            var offset = WavelengthSpectrumT.EndWavelengthSpectrumT(builder);
+            builder.Finish(offset.Value);
+            return builder.DataBuffer;
+        }
+
+        /// <summary>
+        /// Find the scan number whose retention time is closest to `time`
+        /// </summary>
+        public int ScanNumberForTime(double time) {
+            var accessor = GetHandle();
+            return accessor.ScanNumberFromRetentionTime(time);
+        }
+
+        /// <summary>
+        /// Find the first and last scan numbers whose retention times fall within a time window, inclusive.
+        /// If no scans fall within the window, the last scan number will be less than the first.
+        /// </summary>
+        /// <param name="startTime">The earliest retention time to include, in minutes</param>
+        /// <param name="endTime">The latest retention time to include, in minutes</param>
+        public (int, int) ScansInTimeRange(double startTime, double endTime) {
+            return ScanRangeForTimeRange(GetHandle(), startTime, endTime);
+        }
+
+        /// <summary>
+        /// Build one extracted ion chromatogram per m/z interval in a single pass over the file.
+        /// </summary>
+        /// <param name="lowMzs">The lower bound of each m/z interval</param>
+        /// <param name="highMzs">The upper bound of each m/z interval</param>
+        /// <param name="filter">A scan filter string restricting which scans contribute, or `null` for all</param>
+        /// <param name="startTime">The earliest retention time to include, in minutes</param>
+        /// <param name="endTime">The latest retention time to include, in minutes</param>
+        /// <returns>A `ChromatogramDescription` buffer for each m/z interval, in the same order</returns>
+        public ByteBuffer[] GetMassRangeTraces(double[] lowMzs, double[] highMzs, string filter, double startTime, double endTime) {
+            if (lowMzs.Length != highMzs.Length) {
+                throw new ArgumentException($"Got {lowMzs.Length} lower m/z bounds but {highMzs.Length} upper m/z bounds");
+            }
+            ValidateTimeRange(startTime, endTime);
+            var accessor = GetHandle();
+            var (startScan, endScan) = ScanRangeForTimeRange(accessor, startTime, endTime);
+
+            var settings = new ChromatogramTraceSettings[lowMzs.Length];
+            for (var i = 0; i < lowMzs.Length; i++) {
+                if (!(lowMzs[i] <= highMzs[i])) {
+                    throw new ArgumentException($"The m/z interval {lowMzs[i]} to {highMzs[i]} ends before it starts");
+                }
+                settings[i] = new ChromatogramTraceSettings(TraceType.MassRange) {
+                    MassRanges = [ThermoFisher.CommonCore.Data.Business.Range.Create(lowMzs[i], highMzs[i])],
+                };
+                if (filter != null) settings[i].Filter = filter;
+            }
+
+            var buffers = new ByteBuffer[lowMzs.Length];
+            if (lowMzs.Length == 0) return buffers;
+            if (endScan < startScan) {
+                for (var i = 0; i < buffers.Length; i++) {
+                    buffers[i] = StoreEmptyChromatogramDescription(TraceType.MassRange, startScan, endScan);
+                }
+                return buffers;
+            }
+
+            var traces = accessor.GetChromatogramDataEx(settings, startScan, endScan);
+            var signals = ChromatogramSignal.FromChromatogramData(traces);
+            for (var i = 0; i < signals.Length; i++) {
+                buffers[i] = StoreChromatogramDescription(signals[i], TraceType.MassRange, startScan, endScan);
+            }
+            return buffers;
+        }
+
+        Polarity GetPolarity(IScanFilter filter)
+        {
+            Polarity polarity = Polarity.Positive;
+            if (PolarityType.Positive == filter.Polarity)
+            {
+                polarity = Polarity.Positive;
+            }
+            else if (PolarityType.Negative == filter.Polarity)
+            {
+                polarity = Polarity.Negative;
+            }
+            else
+            {
+                polarity = Polarity.Unknown;
+            }
+            return polarity;
+        }
+
+        Offset<AcquisitionT> StoreAcquisition(FlatBufferBuilder builder, AcquisitionProperties acquisitionProperties, IScanFilter filter)
+        {
+            VectorOffset voltagesOffset = default;
+            if (acquisitionProperties.CompensationVoltage.Count > 0)
+            {
+                // Convert List<double> to float[] for FlatBuffer
+                float[] voltageArray = acquisitionProperties.CompensationVoltage.Select(v => (float)v).ToArray();
+                voltagesOffset = AcquisitionT.CreateCompensationVoltagesVector(builder, voltageArray);
+            }
+
+            // A filter without explicit mass ranges still scanned the full range recorded in the scan statistics
+            if (filter.MassRangeCount > 0)
+            {
+                AcquisitionT.StartScanWindowsVector(builder, filter.MassRangeCount);
+                for (var i = filter.MassRangeCount - 1; i >= 0; i--)
+                {
+                    var range = filter.GetMassRange(i);
+                    MassRangeT.CreateMassRangeT(builder, range.Low, range.High);
+                }
+            }
+            else
+            {
+                AcquisitionT.StartScanWindowsVector(builder, 1);
+                MassRangeT.CreateMassRangeT(builder, acquisitionProperties.LowMZ, acquisitionProperties.HighMZ);
+            }
+            var scanWindowsOffset = builder.EndVector();
+
+            AcquisitionT.StartAcquisitionT(builder);
+            AcquisitionT.AddInjectionTime(builder, (float)acquisitionProperties.InjectionTime);
+            if (acquisitionProperties.CompensationVoltage.Count > 0)
+            {
+                AcquisitionT.AddCompensationVoltages(builder, voltagesOffset);
+            }
+            AcquisitionT.AddLowMz(builder, acquisitionProperties.LowMZ);
             AcquisitionT.AddHighMz(builder, acquisitionProperties.HighMZ);
+            AcquisitionT.AddScanWindows(builder, scanWindowsOffset);
             AcquisitionT.AddMassAnalyzer(builder, acquisitionProperties.Analyzer);
             AcquisitionT.AddScanEvent(builder, acquisitionProperties.ScanEventNumber);
             AcquisitionT.AddIonizationMode(builder, AcquisitionProperties.CastIonizationMode(filter.IonizationMode));
@@ -1019,12 +1790,116 @@ namespace librawfilereader
             return acquisitionOffset;
         }
 
+        /// <summary>
+        /// Store the summary statistics of a scan. `ScanStatistics` does not count spectra itself, so
+        /// `spectrumCount` comes from the run header of the device that acquired the scan.
+        /// </summary>
+        Offset<ScanStatisticsT> StoreScanStatistics(FlatBufferBuilder builder, ScanStatistics stats, int spectrumCount)
+        {
+            var scanTypeOffset = builder.CreateString(stats.ScanType ?? "");
+            return ScanStatisticsT.CreateScanStatisticsT(
+                builder,
+                stats.TIC,
+                stats.BasePeakMass,
+                stats.BasePeakIntensity,
+                stats.LowMass,
+                stats.HighMass,
+                stats.PacketType,
+                stats.PacketCount,
+                stats.SegmentNumber,
+                stats.CycleNumber,
+                stats.ScanEventNumber,
+                stats.NumberOfChannels,
+                stats.Frequency,
+                stats.IsUniformTime,
+                stats.IsCentroidScan,
+                stats.ShortWavelength,
+                stats.LongWavelength,
+                stats.WavelengthStep,
+                stats.AbsorbanceUnitScale,
+                scanTypeOffset,
+                spectrumCount
+            );
+        }
+
+        Offset<ScanFilterT> StoreScanFilter(FlatBufferBuilder builder, IScanFilter filter)
+        {
+            // Structs in a vector are written in reverse order
+            ScanFilterT.StartReactionsVector(builder, filter.MassCount);
+            for (var i = filter.MassCount - 1; i >= 0; i--)
+            {
+                DissociationMethod dissociation;
+                if (!DissociationMethodMap.TryGetValue(filter.GetActivation(i), out dissociation))
+                {
+                    dissociation = DissociationMethod.Unknown;
+                }
+                ScanFilterReactionT.CreateScanFilterReactionT(
+                    builder,
+                    filter.GetMass(i),
+                    filter.GetIsolationWidth(i),
+                    filter.GetEnergy(i),
+                    dissociation,
+                    filter.GetIsMultipleActivation(i)
+                );
+            }
+            var reactionsOffset = builder.EndVector();
+
+            ScanFilterT.StartMassRangesVector(builder, filter.MassRangeCount);
+            for (var i = filter.MassRangeCount - 1; i >= 0; i--)
+            {
+                var range = filter.GetMassRange(i);
+                MassRangeT.CreateMassRangeT(builder, range.Low, range.High);
+            }
+            var massRangesOffset = builder.EndVector();
+
+            VectorOffset voltagesOffset = default;
+            var hasVoltages = filter.CompensationVoltage == TriState.On && filter.CompensationVoltageCount > 0;
+            if (hasVoltages)
+            {
+                var voltages = new double[filter.CompensationVoltageCount];
+                for (var i = 0; i < voltages.Length; i++)
+                {
+                    voltages[i] = filter.CompensationVoltageValue(i);
+                }
+                voltagesOffset = ScanFilterT.CreateCompensationVoltagesVector(builder, voltages);
+            }
+
+            SpectrumMode mode = filter.ScanData switch
+            {
+                ScanDataType.Centroid => SpectrumMode.Centroid,
+                ScanDataType.Profile => SpectrumMode.Profile,
+                _ => SpectrumMode.Unknown,
+            };
+
+            ScanFilterT.StartScanFilterT(builder);
+            ScanFilterT.AddMassAnalyzer(builder, AcquisitionProperties.CastMassAnalyzer(filter.MassAnalyzer));
+            ScanFilterT.AddPolarity(builder, GetPolarity(filter));
+            ScanFilterT.AddScanMode(builder, (ScanMode)(byte)filter.ScanMode);
+            ScanFilterT.AddMsOrder(builder, (MSOrder)filter.MSOrder);
+            ScanFilterT.AddMode(builder, mode);
+            ScanFilterT.AddIonizationMode(builder, AcquisitionProperties.CastIonizationMode(filter.IonizationMode));
+            ScanFilterT.AddReactions(builder, reactionsOffset);
+            ScanFilterT.AddMassRanges(builder, massRangesOffset);
+            if (hasVoltages)
+            {
+                ScanFilterT.AddCompensationVoltages(builder, voltagesOffset);
+            }
+            if (filter.Detector == DetectorType.Valid)
+            {
+                ScanFilterT.AddDetectorValue(builder, filter.DetectorValue);
+            }
+            ScanFilterT.AddSps(builder, filter.MultiNotch == TriState.On);
+            ScanFilterT.AddDependent(builder, filter.Dependent == TriState.On);
+            ScanFilterT.AddSupplementalActivation(builder, filter.SupplementalActivation == TriState.On);
+            return ScanFilterT.EndScanFilterT(builder);
+        }
+
         Offset<PrecursorT> StorePrecursor(FlatBufferBuilder builder, PrecursorProperties precursorProps)
         {
             var precursor = PrecursorT.CreatePrecursorT(
                     builder,
                     precursorProps.MonoisotopicMZ,
-                    (float)0.0,
+                    precursorProps.Intensity,
                     precursorProps.PrecursorCharge,
                     precursorProps.MasterScanNumber,
                     precursorProps.IsolationWindow.LowerMZ,
@@ -1036,11 +1911,52 @@ namespace librawfilereader
             return precursor;
         }
 
+        static SampleType ToSampleType(ThermoFisher.CommonCore.Data.Business.SampleType sampleType) {
+            return sampleType switch
+            {
+                ThermoFisher.CommonCore.Data.Business.SampleType.Blank => SampleType.Blank,
+                ThermoFisher.CommonCore.Data.Business.SampleType.QC => SampleType.QC,
+                ThermoFisher.CommonCore.Data.Business.SampleType.StdClear => SampleType.StdClear,
+                ThermoFisher.CommonCore.Data.Business.SampleType.StdUpdate => SampleType.StdUpdate,
+                ThermoFisher.CommonCore.Data.Business.SampleType.StdBracket => SampleType.StdBracket,
+                ThermoFisher.CommonCore.Data.Business.SampleType.StdBracketStart => SampleType.StdBracketStart,
+                ThermoFisher.CommonCore.Data.Business.SampleType.StdBracketEnd => SampleType.StdBracketEnd,
+                ThermoFisher.CommonCore.Data.Business.SampleType.Program => SampleType.Program,
+                ThermoFisher.CommonCore.Data.Business.SampleType.SolventBlank => SampleType.SolventBlank,
+                ThermoFisher.CommonCore.Data.Business.SampleType.MatrixBlank => SampleType.MatrixBlank,
+                ThermoFisher.CommonCore.Data.Business.SampleType.MatrixSpike => SampleType.MatrixSpike,
+                ThermoFisher.CommonCore.Data.Business.SampleType.MatrixSpikeDuplicate => SampleType.MatrixSpikeDuplicate,
+                _ => SampleType.Unknown,
+            };
+        }
+
+        static long ToUnixTimeMilliseconds(DateTime date) {
+            return new DateTimeOffset(date.ToUniversalTime()).ToUnixTimeMilliseconds();
+        }
+
+        /// <summary>
+        /// Count the spectra at MS levels 1-10, where index `i` holds the number of MS level `i+1` spectra.
+        /// This visits every scan filter the first time it is called on a lazily opened file.
+        /// </summary>
+        public uint[] SpectraPerMSLevel() {
+            var counts = new uint[10];
+            foreach(var (k, v) in MSLevelCounts) {
+                counts[k - 1] = v;
+            }
+            return counts;
+        }
+
+        /// <summary>
+        /// Describe the file. The spectra per MS level are left out when the file was opened lazily and
+        /// they have not been counted yet, so that this stays cheap; see `SpectraPerMSLevel`.
+        /// </summary>
         public ByteBuffer GetFileMetadata() {
             var accessor = GetHandle();
             var builder = new FlatBufferBuilder(1024);
-            var description = accessor.FileHeader.FileDescription;
-            var date = accessor.FileHeader.CreationDate.ToString("o");
+            var fileHeader = accessor.FileHeader;
+            var description = fileHeader.FileDescription;
+            var date = fileHeader.CreationDate.ToString("o");
+            var runHeader = accessor.RunHeaderEx;
 
             SampleInformation sampleInfo = accessor.SampleInformation;
             var sampleID = sampleInfo.SampleId;
@@ -1048,12 +1964,24 @@ namespace librawfilereader
             var sampleComment = sampleInfo.Comment;
             var sampleName = sampleInfo.SampleName;
 
-            var counts = new uint[10];
-            foreach(var (k, v) in MSLevelCounts) {
-                counts[k - 1] = v;
-            }
+            var includeCounts = !LazyIndexing || MSLevelCountsIndex.IsValueCreated;
 
             var dateOffset = builder.CreateString(date);
+            var descriptionOffset = builder.CreateString(description ?? "");
+            var creatorIdOffset = builder.CreateString(fileHeader.WhoCreatedId ?? "");
+            var creatorLogonOffset = builder.CreateString(fileHeader.WhoCreatedLogon ?? "");
+            var modifierIdOffset = builder.CreateString(fileHeader.WhoModifiedId ?? "");
+            var modifierLogonOffset = builder.CreateString(fileHeader.WhoModifiedLogon ?? "");
+
+            var barcodeOffset = builder.CreateString(sampleInfo.Barcode ?? "");
+            var calibrationLevelOffset = builder.CreateString(sampleInfo.CalibrationLevel ?? "");
+            var instrumentMethodFileOffset = builder.CreateString(sampleInfo.InstrumentMethodFile ?? "");
+            var processingMethodFileOffset = builder.CreateString(sampleInfo.ProcessingMethodFile ?? "");
+            var calibrationFileOffset = builder.CreateString(sampleInfo.CalibrationFile ?? "");
+            var rawFilePathOffset = builder.CreateString(System.IO.Path.Combine(sampleInfo.Path ?? "", sampleInfo.RawFileName ?? ""));
+            string[] userText = sampleInfo.UserText ?? Array.Empty<string>();
+            var userTextOffsets = userText.Select(t => builder.CreateString(t ?? "")).ToArray();
+            var userTextOffset = FileDescriptionT.CreateSampleUserTextVector(builder, userTextOffsets);
 
             var sampleIDOffset = builder.CreateString(sampleID);
             var sampleNameOffset = builder.CreateString(sampleName);
@@ -1061,7 +1989,11 @@ namespace librawfilereader
             var sampleCommentOffset = builder.CreateString(sampleComment);
 
             var pathOffset = builder.CreateString(Path);
-            var countsOffset = FileDescriptionT.CreateSpectraPerMsLevelVector(builder, counts);
+            VectorOffset countsOffset = default;
+            if (includeCounts)
+            {
+                countsOffset = FileDescriptionT.CreateSpectraPerMsLevelVector(builder, SpectraPerMSLevel());
+            }
 
             StringOffset[] headerOffsets = new StringOffset[Headers.Length];
             for(var i = 0; i < Headers.Length; i++) {
@@ -1078,8 +2010,47 @@ namespace librawfilereader
             FileDescriptionT.AddSampleName(builder, sampleNameOffset);
             FileDescriptionT.AddSampleVial(builder, sampleVialOffset);
             FileDescriptionT.AddSourceFile(builder, pathOffset);
-            FileDescriptionT.AddSpectraPerMsLevel(builder, countsOffset);
+            if (includeCounts)
+            {
+                FileDescriptionT.AddSpectraPerMsLevel(builder, countsOffset);
+            }
             FileDescriptionT.AddTrailerHeaders(builder, headersOffset);
+            FileDescriptionT.AddCreationTimestamp(builder, ToUnixTimeMilliseconds(fileHeader.CreationDate));
+            FileDescriptionT.AddModificationTimestamp(builder, ToUnixTimeMilliseconds(fileHeader.ModifiedDate));
+            FileDescriptionT.AddDescription(builder, descriptionOffset);
+            FileDescriptionT.AddCreatorId(builder, creatorIdOffset);
+            FileDescriptionT.AddCreatorLogon(builder, creatorLogonOffset);
+            FileDescriptionT.AddModifierId(builder, modifierIdOffset);
+            FileDescriptionT.AddModifierLogon(builder, modifierLogonOffset);
+            FileDescriptionT.AddRevision(builder, fileHeader.Revision);
+            FileDescriptionT.AddTimesCalibrated(builder, fileHeader.NumberOfTimesCalibrated);
+            FileDescriptionT.AddTimesModified(builder, fileHeader.NumberOfTimesModified);
+            FileDescriptionT.AddRunHeader(builder, RunHeaderT.CreateRunHeaderT(
+                builder,
+                runHeader.StartTime,
+                runHeader.EndTime,
+                runHeader.LowMass,
+                runHeader.HighMass,
+                runHeader.ExpectedRuntime,
+                runHeader.MassResolution,
+                runHeader.MaxIntegratedIntensity,
+                runHeader.MaxIntensity
+            ));
+            FileDescriptionT.AddSampleType(builder, ToSampleType(sampleInfo.SampleType));
+            FileDescriptionT.AddSampleBarcode(builder, barcodeOffset);
+            FileDescriptionT.AddSampleBarcodeStatus(builder, (BarcodeStatus)(byte)sampleInfo.BarcodeStatus);
+            FileDescriptionT.AddInjectionVolume(builder, sampleInfo.InjectionVolume);
+            FileDescriptionT.AddDilutionFactor(builder, sampleInfo.DilutionFactor);
+            FileDescriptionT.AddSampleWeight(builder, sampleInfo.SampleWeight);
+            FileDescriptionT.AddSampleVolume(builder, sampleInfo.SampleVolume);
+            FileDescriptionT.AddIstdAmount(builder, sampleInfo.IstdAmount);
+            FileDescriptionT.AddCalibrationLevel(builder, calibrationLevelOffset);
+            FileDescriptionT.AddRowNumber(builder, sampleInfo.RowNumber);
+            FileDescriptionT.AddInstrumentMethodFile(builder, instrumentMethodFileOffset);
+            FileDescriptionT.AddProcessingMethodFile(builder, processingMethodFileOffset);
+            FileDescriptionT.AddCalibrationFile(builder, calibrationFileOffset);
+            FileDescriptionT.AddRawFilePath(builder, rawFilePathOffset);
+            FileDescriptionT.AddSampleUserText(builder, userTextOffset);
             var fileDescOffset = FileDescriptionT.EndFileDescriptionT(builder);
 
             builder.Finish(fileDescOffset.Value);
@@ -1090,7 +2061,7 @@ namespace librawfilereader
             return SpectrumDescriptionFor(scanNumber, IncludeSignal, CentroidSpectra);
         }
 
-        public unsafe uint SpectrumDataIndirection(int scanNumber, bool centroidSpectra, RawVec* mzOut, RawVec* intensityOut)
+        public unsafe uint SpectrumDataIndirection(int scanNumber, bool centroidSpectra, RawVec* mzOut, RawVec* intensityOut, RawVec* flagsOut)
         {
             var accessor = GetHandle();
             var stats = accessor.GetScanStatsForScanNumber(scanNumber);
@@ -1098,6 +2069,8 @@ namespace librawfilereader
             mzOut->Len = 0;
             intensityOut->Data = null;
             intensityOut->Len = 0;
+            flagsOut->Data = null;
+            flagsOut->Len = 0;
             if (centroidSpectra && !stats.IsCentroidScan)
             {
                 var stream = accessor.GetCentroidStream(scanNumber, true);
@@ -1116,6 +2089,16 @@ namespace librawfilereader
                     }
                     *mzOut = mzArr;
                     *intensityOut = intArr;
+                    if (stream.Flags != null && stream.Flags.Length == centroids.Count)
+                    {
+                        var flagsArr = Exports.AllocateRawVec((uint)centroids.Count);
+                        var flagsSpan = flagsArr.AsSpan();
+                        for (var i = 0; i < centroids.Count; i++)
+                        {
+                            flagsSpan[i] = PackPeakFlags(stream.Flags[i]);
+                        }
+                        *flagsOut = flagsArr;
+                    }
                     return (uint)centroids.Count;
                 }
                 else
@@ -1200,6 +2183,35 @@ namespace librawfilereader
         {
             var accessor = GetHandle();
             var stats = accessor.GetScanStatsForScanNumber(scanNumber);
+
+            // Centroid spectra are usually compact, but profile spectra are much bigger
+            var builder = new FlatBufferBuilder(centroidSpectra || stats.IsCentroidScan ? 8192 : 262144);
+            var description = StoreSpectrumDescription(builder, accessor, scanNumber, stats, includeSignal, centroidSpectra);
+            builder.Finish(description.Value);
+            return builder.DataBuffer;
+        }
+
+        /// <summary>
+        /// Write a size-prefixed `SpectrumDescription` message for each scan number into `output`, one after
+        /// the other, sharing a single accessor and `FlatBufferBuilder` between them.
+        /// </summary>
+        public void SpectrumDescriptionsFor(ReadOnlySpan<int> scanNumbers, bool includeSignal, bool centroidSpectra, MemoryStream output)
+        {
+            var accessor = GetHandle();
+            var builder = new FlatBufferBuilder(262144);
+            foreach (var scanNumber in scanNumbers)
+            {
+                var stats = accessor.GetScanStatsForScanNumber(scanNumber);
+                builder.Clear();
+                var description = StoreSpectrumDescription(builder, accessor, scanNumber, stats, includeSignal, centroidSpectra);
+                SpectrumDescription.FinishSizePrefixedSpectrumDescriptionBuffer(builder, description);
+                var buffer = builder.DataBuffer;
+                output.Write(buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position));
+            }
+        }
+
+        Offset<SpectrumDescription> StoreSpectrumDescription(FlatBufferBuilder builder, IRawDataPlus accessor, int scanNumber, ScanStatistics stats, bool includeSignal, bool centroidSpectra)
+        {
             SpectrumMode mode;
             if (centroidSpectra)
             {
@@ -1214,8 +2226,6 @@ namespace librawfilereader
             short level = MSLevelFromFilter(filter);
             Polarity polarity = GetPolarity(filter);
 
-            // Centroid spectra are usually compact, but profile spectra are much bigger
-            var builder = new FlatBufferBuilder(mode == SpectrumMode.Centroid ? 8192 : 262144);
             Offset<SpectrumData> dataOffset = new();
 
             if (includeSignal)
@@ -1227,9 +2237,33 @@ namespace librawfilereader
             var filterString = filter.ToString();
             var filterStringOffset = builder.CreateString(filterString);
 
-            var (precursorPropsOf, acquisitionProperties) = ExtractPrecursorAndTrailerMetadata(scanNumber, level, filter, accessor, stats);
+            // Looking up precursor intensities reads the parent scan's signal, which is skipped along
+            // with this scan's signal to keep metadata-only reads cheap
+            var (precursorPropsOf, acquisitionProperties) = ExtractPrecursorAndTrailerMetadata(scanNumber, level, filter, accessor, stats, includeSignal);
 
             var acquisitionOffset = StoreAcquisition(builder, acquisitionProperties, filter);
+            var scanFilterOffset = StoreScanFilter(builder, filter);
+            var statisticsOffset = StoreScanStatistics(builder, stats, accessor.RunHeaderEx.SpectraCount);
+
+            VectorOffset precursorsOffset = default;
+            VectorOffset activationDetailsOffset = default;
+            if (level > 1)
+            {
+                var precursors = ExtractPrecursors(scanNumber, level, filter, accessor, (PrecursorProperties)precursorPropsOf, includeSignal);
+                var activationDetails = new Offset<ActivationDetailsT>[precursors.Count];
+                for (var i = 0; i < precursors.Count; i++)
+                {
+                    activationDetails[i] = StoreActivationDetails(builder, scanNumber, precursors[i].ReactionIndex, filter, accessor);
+                }
+                activationDetailsOffset = SpectrumDescription.CreateActivationDetailsVector(builder, activationDetails);
+                // Structs in a vector are written in reverse order
+                SpectrumDescription.StartPrecursorsVector(builder, precursors.Count);
+                for (var i = precursors.Count - 1; i >= 0; i--)
+                {
+                    StorePrecursor(builder, precursors[i]);
+                }
+                precursorsOffset = builder.EndVector();
+            }
 
             SpectrumDescription.StartSpectrumDescription(builder);
             if (includeSignal)
@@ -1245,13 +2279,130 @@ namespace librawfilereader
             SpectrumDescription.AddAcquisition(builder, acquisitionOffset);
             SpectrumDescription.AddMsOrder(builder, (MSOrder)filter.MSOrder);
             SpectrumDescription.AddScanMode(builder, modeT);
+            SpectrumDescription.AddControllerNumber(builder, MSControllerNumber);
+            SpectrumDescription.AddScanFilter(builder, scanFilterOffset);
+            SpectrumDescription.AddStatistics(builder, statisticsOffset);
             if (level > 1)
             {
                 var precursor = StorePrecursor(builder, (PrecursorProperties)precursorPropsOf);
                 SpectrumDescription.AddPrecursor(builder, precursor);
+                SpectrumDescription.AddPrecursors(builder, precursorsOffset);
+                SpectrumDescription.AddActivationDetails(builder, activationDetailsOffset);
             }
-            var description = SpectrumDescription.EndSpectrumDescription(builder);
-            builder.Finish(description.Value);
+            return SpectrumDescription.EndSpectrumDescription(builder);
+        }
+
+        /// <summary>
+        /// Find the parent of every spectrum. The master scan trailer is used where it is present, then
+        /// Thermo's scan dependents, and finally the most recent spectrum of a lower MS level.
+        /// </summary>
+        public ByteBuffer GetAcquisitionTree() {
+            var accessor = GetHandle();
+            var first = FirstSpectrum();
+            var last = LastSpectrum();
+
+            var parents = new int[Math.Max(last, 0)];
+            Array.Fill(parents, -1);
+            var lastIndexAtLevel = new int[MSLevelMap.Values.Max() + 1];
+            Array.Fill(lastIndexAtLevel, -1);
+
+            for (var scanNumber = first; scanNumber <= last; scanNumber++) {
+                var index = scanNumber - 1;
+                var level = MSLevelFromFilter(accessor.GetFilterForScanNumber(scanNumber));
+
+                if (level > 1) {
+                    if (GetIntTrailerExtraFor(accessor, scanNumber, MasterScanKey, out var masterScanNumber, -1) && masterScanNumber > 0 && masterScanNumber < scanNumber) {
+                        parents[index] = masterScanNumber - 1;
+                    } else if (parents[index] < 0) {
+                        for (var lower = level - 1; lower > 0; lower--) {
+                            parents[index] = Math.Max(parents[index], lastIndexAtLevel[lower]);
+                        }
+                    }
+                }
+                lastIndexAtLevel[level] = index;
+
+                var dependents = accessor.GetScanDependents(scanNumber, 5);
+                if (dependents?.ScanDependentDetailArray == null) continue;
+                foreach (var dependent in dependents.ScanDependentDetailArray) {
+                    var childScanNumber = dependent.ScanIndex;
+                    if (childScanNumber > scanNumber && childScanNumber <= last && parents[childScanNumber - 1] < 0) {
+                        parents[childScanNumber - 1] = index;
+                    }
+                }
+            }
+
+            var builder = new FlatBufferBuilder(parents.Length * 4 + 64);
+            var parentsOffset = AcquisitionTreeT.CreateParentsVector(builder, parents);
+            var offset = AcquisitionTreeT.CreateAcquisitionTreeT(builder, parentsOffset);
+            builder.Finish(offset.Value);
+            return builder.DataBuffer;
+        }
+
+        /// <summary>
+        /// Group all spectra by their distinct scan filters, in the order Thermo lists the filters
+        /// </summary>
+        public ByteBuffer GetUniqueFilters() {
+            var accessor = GetHandle();
+            var filterStrings = new List<string>();
+            var groups = new Dictionary<string, (IScanFilter, List<uint>)>();
+            foreach (var filter in accessor.GetFilters()) {
+                var key = filter.ToString();
+                if (!groups.ContainsKey(key)) {
+                    filterStrings.Add(key);
+                    groups[key] = (filter, new List<uint>());
+                }
+            }
+
+            var first = FirstSpectrum();
+            var last = LastSpectrum();
+            for (var scanNumber = first; scanNumber <= last; scanNumber++) {
+                var filter = accessor.GetFilterForScanNumber(scanNumber);
+                var key = filter.ToString();
+                if (!groups.ContainsKey(key)) {
+                    filterStrings.Add(key);
+                    groups[key] = (filter, new List<uint>());
+                }
+                groups[key].Item2.Add((uint)(scanNumber - 1));
+            }
+
+            var builder = new FlatBufferBuilder(4096);
+            var groupOffsets = new List<Offset<ScanFilterGroupT>>();
+            foreach (var key in filterStrings) {
+                var (filter, indices) = groups[key];
+                if (indices.Count == 0) continue;
+                var filterStringOffset = builder.CreateString(key);
+                var scanFilterOffset = StoreScanFilter(builder, filter);
+                var indicesOffset = ScanFilterGroupT.CreateIndicesVector(builder, indices.ToArray());
+                groupOffsets.Add(ScanFilterGroupT.CreateScanFilterGroupT(builder, filterStringOffset, scanFilterOffset, indicesOffset));
+            }
+
+            var groupsOffset = ScanFilterGroupCollectionT.CreateGroupsVector(builder, groupOffsets.ToArray());
+            var offset = ScanFilterGroupCollectionT.CreateScanFilterGroupCollectionT(builder, groupsOffset);
+            builder.Finish(offset.Value);
+            return builder.DataBuffer;
+        }
+
+        /// <summary>
+        /// Read the instrument error log, pairing each message with the retention time it was recorded at
+        /// </summary>
+        public ByteBuffer ErrorLog() {
+            var accessor = GetHandle();
+            var count = accessor.RunHeaderEx.ErrorLogCount;
+
+            var times = new double[count];
+            var messages = new string[count];
+            for (var i = 0; i < count; i++) {
+                var entry = accessor.GetErrorLogItem(i);
+                times[i] = entry.RetentionTime;
+                messages[i] = entry.Message ?? "";
+            }
+
+            var builder = new FlatBufferBuilder(1024);
+            var messageOffsets = messages.Select(m => builder.CreateString(m)).ToArray();
+            var messagesOffset = ErrorLogT.CreateMessagesVector(builder, messageOffsets);
+            var timesOffset = ErrorLogT.CreateTimesVector(builder, times);
+            var offset = ErrorLogT.CreateErrorLogT(builder, timesOffset, messagesOffset);
+            builder.Finish(offset.Value);
             return builder.DataBuffer;
         }
 
@@ -1444,7 +2595,7 @@ namespace librawfilereader
             return builder.DataBuffer;
         }
 
-        private void BuildScanTypeMap()
+        private Dictionary<short, uint> BuildScanTypeMap()
         {
             var accessor = GetHandle();
             Dictionary<short, uint> msLevelCounts = new() {
@@ -1493,7 +2644,7 @@ namespace librawfilereader
             }
 
             // PreviousMSLevels = previousMSLevels;
-            MSLevelCounts = msLevelCounts;
+            return msLevelCounts;
         }
 
         private RawFileReaderError Configure()
@@ -1507,12 +2658,19 @@ namespace librawfilereader
             {
                 return RawFileReaderError.InvalidFormat;
             }
+            else if (MSControllerNumber < 1 || MSControllerNumber > accessor.GetInstrumentCountOfType(Device.MS))
+            {
+                return RawFileReaderError.ControllerNotFound;
+            }
             else
             {
-                accessor.SelectInstrument(Device.MS, 1);
+                accessor.SelectInstrument(Device.MS, MSControllerNumber);
+            }
+            if (!LazyIndexing)
+            {
+                _ = InstrumentConfigsIndex.Value;
+                _ = MSLevelCountsIndex.Value;
             }
-            InstrumentConfigsByComponents = FindAllMassAnalyzers();
-            BuildScanTypeMap();
 
             var headers = accessor.GetTrailerExtraHeaderInformation();
             for (var i = 0; i < headers.Length; i++)
@@ -1530,8 +2688,43 @@ namespace librawfilereader
     {
         private static unsafe delegate*<nuint, RawVec*, void> ForeignAllocateMemory;
 
-        private static Dictionary<IntPtr, RawFileReader> OpenHandles = new Dictionary<nint, RawFileReader>();
-        private static IntPtr HandleCounter = 1;
+        // Handles are looked up on every call from Rust, so this must be readable without taking a lock
+        private static ConcurrentDictionary<IntPtr, RawFileReader> OpenHandles = new ConcurrentDictionary<nint, RawFileReader>();
+        private static long HandleCounter = 0;
+
+        // Exceptions must not unwind into Rust, so each export records the exception it caught here
+        // for the caller to collect with `TakeLastError`. Rust calls into .NET synchronously, so
+        // the message is always read on the same thread it was written on.
+        [ThreadStatic]
+        private static string LastErrorMessage;
+        [ThreadStatic]
+        private static RawFileReaderError LastErrorKind;
+
+        private static void RecordException(Exception e)
+        {
+            LastErrorMessage = $"{e.GetType().FullName}: {e.Message}";
+            LastErrorKind = e is HandleNotFoundException ? RawFileReaderError.HandleNotFound : RawFileReaderError.Error;
+        }
+
+        /// <summary>
+        /// Take the message of the last exception an export caught on this thread, clearing it.
+        /// </summary>
+        /// <param name="kindOut">Receives the kind of error, `RawFileReaderError.Error` unless the exception has a dedicated kind</param>
+        /// <returns>A `RawVec` holding the UTF-8 message, or an empty `RawVec` if there was no exception</returns>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_take_last_error")]
+        public static unsafe RawVec TakeLastError(uint* kindOut)
+        {
+            var message = LastErrorMessage;
+            *kindOut = (uint)LastErrorKind;
+            LastErrorMessage = null;
+            LastErrorKind = RawFileReaderError.Ok;
+            if (string.IsNullOrEmpty(message))
+            {
+                return default;
+            }
+            var bytes = Encoding.UTF8.GetBytes(message);
+            return BufferToRustVec(bytes, (nuint)bytes.Length);
+        }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_set_memory_allocator")]
         public static unsafe void SetForeignAllocateMemory(delegate*<nuint, RawVec*, void> allocateMemory) => ForeignAllocateMemory = allocateMemory;
@@ -1586,27 +2779,41 @@ namespace librawfilereader
             return vec;
         }
 
-        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_open")]
-        public static unsafe IntPtr Open(IntPtr textPtr, int textLength)
+        /// <summary>
+        /// Open a RAW file, reading spectra from a specific MS controller and optionally deferring
+        /// the indices that require visiting every scan until they are first needed
+        /// </summary>
+        /// <param name="textPtr">A UTF-8 file path</param>
+        /// <param name="textLength">The length of the file path in bytes</param>
+        /// <param name="controllerNumber">The 1-based number of the MS controller to read from</param>
+        /// <param name="lazyIndexing">Whether to defer building the scan indices</param>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_open_with_options")]
+        public static unsafe IntPtr OpenWithOptions(IntPtr textPtr, int textLength, int controllerNumber, uint lazyIndexing)
         {
-            var text = Marshal.PtrToStringUTF8(textPtr, textLength);
-            var handle = new RawFileReader(text);
-            IntPtr handleToken;
-            lock (OpenHandles)
+            try
             {
-                handleToken = HandleCounter;
-                HandleCounter += 1;
-                OpenHandles[handleToken] = handle;
+                var text = Marshal.PtrToStringUTF8(textPtr, textLength);
+                return RegisterHandle(new RawFileReader(text, controllerNumber, lazyIndexing != 0));
             }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return IntPtr.Zero;
+            }
+        }
+
+        private static IntPtr RegisterHandle(RawFileReader handle)
+        {
+            IntPtr handleToken = (IntPtr)System.Threading.Interlocked.Increment(ref HandleCounter);
+            OpenHandles[handleToken] = handle;
             return handleToken;
         }
 
         private static RawFileReader GetHandleForToken(IntPtr handleToken)
         {
-            RawFileReader handle;
-            lock (OpenHandles)
+            if (!OpenHandles.TryGetValue(handleToken, out var handle))
             {
-                handle = OpenHandles[handleToken];
+                throw new HandleNotFoundException(handleToken);
             }
             return handle;
         }
@@ -1618,13 +2825,7 @@ namespace librawfilereader
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_close")]
         public static unsafe void Close(IntPtr handleToken)
         {
-            lock (OpenHandles)
-            {
-                if (OpenHandles.ContainsKey(handleToken))
-                {
-                    OpenHandles.Remove(handleToken);
-                }
-            }
+            OpenHandles.TryRemove(handleToken, out _);
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_close_all")]
@@ -1636,22 +2837,46 @@ namespace librawfilereader
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_first_spectrum")]
         public static unsafe int FirstSpectrum(IntPtr handleToken)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return reader.FirstSpectrum();
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.FirstSpectrum();
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return -1;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_last_spectrum")]
         public static unsafe int LastSpectrum(IntPtr handleToken)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return reader.LastSpectrum();
-        }
-
-        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_spectrum_count")]
-        public static unsafe int SpectrumCount(IntPtr handleToken)
-        {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return reader.SpectrumCount();
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.LastSpectrum();
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return -1;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_spectrum_count")]
+        public static unsafe int SpectrumCount(IntPtr handleToken)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.SpectrumCount();
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return -1;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_status")]
@@ -1662,51 +2887,105 @@ namespace librawfilereader
                 RawFileReader reader = GetHandleForToken(handleToken);
                 return (uint)reader.Status;
             }
-            catch
+            catch (Exception e)
             {
-                return (uint)RawFileReaderError.HandleNotFound;
+                RecordException(e);
+                return (uint)RawFileReaderError.Error;
             }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_signal_loading")]
         public static unsafe uint GetSignalLoading(IntPtr handleToken)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return (uint)(reader.IncludeSignal ? 1 : 0);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return (uint)(reader.IncludeSignal ? 1 : 0);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return 0;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_set_signal_loading")]
         public static unsafe void SetSignalLoading(IntPtr handleToken, uint value)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            if (value == 0)
+            try
             {
-                reader.IncludeSignal = false;
+                RawFileReader reader = GetHandleForToken(handleToken);
+                if (value == 0)
+                {
+                    reader.IncludeSignal = false;
+                }
+                else
+                {
+                    reader.IncludeSignal = true;
+                }
             }
-            else
+            catch (Exception e)
+            {
+                RecordException(e);
+            }
+        }
+
+        /// <summary>
+        /// Configure how the intensity of a precursor ion is looked up in its parent scan
+        /// </summary>
+        /// <param name="handleToken">The token corresponding to the `RawFileReader` handle</param>
+        /// <param name="tolerance">The m/z matching tolerance, or zero to disable the lookup</param>
+        /// <param name="tolerancePPM">Whether `tolerance` is in parts-per-million rather than Daltons</param>
+        /// <param name="useMonoisotopicMZ">Whether to match the monoisotopic m/z rather than the selected m/z</param>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_set_precursor_intensity_options")]
+        public static unsafe void SetPrecursorIntensityOptions(IntPtr handleToken, double tolerance, uint tolerancePPM, uint useMonoisotopicMZ)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                reader.PrecursorIntensityTolerance = tolerance;
+                reader.PrecursorIntensityTolerancePPM = tolerancePPM != 0;
+                reader.PrecursorIntensityUseMonoisotopicMZ = useMonoisotopicMZ != 0;
+            }
+            catch (Exception e)
             {
-                reader.IncludeSignal = true;
+                RecordException(e);
             }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_centroiding")]
         public static unsafe uint GetCentroidSpectra(IntPtr handleToken)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return (uint)(reader.CentroidSpectra ? 1 : 0);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return (uint)(reader.CentroidSpectra ? 1 : 0);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return 0;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_set_centroiding")]
         public static unsafe void SetCentroidSpectra(IntPtr handleToken, uint value)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            if (value == 0)
+            try
             {
-                reader.CentroidSpectra = false;
+                RawFileReader reader = GetHandleForToken(handleToken);
+                if (value == 0)
+                {
+                    reader.CentroidSpectra = false;
+                }
+                else
+                {
+                    reader.CentroidSpectra = true;
+                }
             }
-            else
+            catch (Exception e)
             {
-                reader.CentroidSpectra = true;
+                RecordException(e);
             }
         }
 
@@ -1719,11 +2998,19 @@ namespace librawfilereader
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_spectrum_description_for")]
         public static unsafe RawVec SpectrumDescriptionFor(IntPtr handleToken, int scanNumber)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.SpectrumDescriptionFor(scanNumber);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.SpectrumDescriptionFor(scanNumber);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         /// <summary>
@@ -1737,11 +3024,48 @@ namespace librawfilereader
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_spectrum_description_for_with_options")]
         public static unsafe RawVec SpectrumDescriptionForWithOptions(IntPtr handleToken, int scanNumber, int includeSignal, int centroidSpectra)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.SpectrumDescriptionFor(scanNumber, includeSignal != 0, centroidSpectra != 0);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.SpectrumDescriptionFor(scanNumber, includeSignal != 0, centroidSpectra != 0);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        /// <summary>
+        /// Get a batch of `SpectrumDescription` FlatBuffer messages for many spectra from a RAW file in one call.
+        /// Each message is prefixed with its size as a little endian `uint`, in the same order as `scanNumbers`.
+        /// </summary>
+        /// <param name="handleToken">The token corresponding to the `RawFileReader` handle</param>
+        /// <param name="scanNumbers">A pointer to the scan numbers of the spectra to retrieve</param>
+        /// <param name="count">The number of scan numbers to read from `scanNumbers`</param>
+        /// <param name="includeSignal">Whether or not to include the MS spectrum signal</param>
+        /// <param name="centroidSpectra">Whether or not to retrieve the centroided spectrum signal</param>
+        /// <returns>A `RawVec` representing Rust-allocated memory that holds the FlatBuffer messages</returns>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_spectrum_descriptions_for")]
+        public static unsafe RawVec SpectrumDescriptionsFor(IntPtr handleToken, int* scanNumbers, int count, int includeSignal, int centroidSpectra)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var output = new MemoryStream();
+                reader.SpectrumDescriptionsFor(new ReadOnlySpan<int>(scanNumbers, count), includeSignal != 0, centroidSpectra != 0, output);
+                var bytes = output.GetBuffer().AsSpan(0, (int)output.Length);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         /// <summary>
@@ -1755,27 +3079,51 @@ namespace librawfilereader
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_spectrum_data_for")]
         public static unsafe RawVec SpectrumDataFor(IntPtr handleToken, int scanNumber, int centroidSpectra)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.SpectrumDataFor(scanNumber, centroidSpectra != 0);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.SpectrumDataFor(scanNumber, centroidSpectra != 0);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_spectrum_data_indirect")]
-        public static unsafe uint GetSpectrumDataIndirect(IntPtr handleToken, int scanNumber, int centroidSpectra, RawVec* mzOut, RawVec* intensityOut)
+        public static unsafe uint GetSpectrumDataIndirect(IntPtr handleToken, int scanNumber, int centroidSpectra, RawVec* mzOut, RawVec* intensityOut, RawVec* flagsOut)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return reader.SpectrumDataIndirection(scanNumber, centroidSpectra == 1, mzOut, intensityOut);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.SpectrumDataIndirection(scanNumber, centroidSpectra == 1, mzOut, intensityOut, flagsOut);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return 0;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_advanced_packet_data_for")]
         public static unsafe RawVec AdvancedPacketDataFor(IntPtr handleToken, int scanNumber, int includeSampledNoise) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.GetAdvancedPacketData(scanNumber, includeSampledNoise != 0);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetAdvancedPacketData(scanNumber, includeSampledNoise != 0);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_advanced_data_indirect")]
@@ -1788,8 +3136,16 @@ namespace librawfilereader
             RawVec* chargeOut,
             RawVec* resolutionOut)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return reader.GetAdvancedPacketDataIndirect(scanNumber, noiseOut, baselineOut, massOut, chargeOut, resolutionOut);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.GetAdvancedPacketDataIndirect(scanNumber, noiseOut, baselineOut, massOut, chargeOut, resolutionOut);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return 0;
+            }
         }
 
         /// <summary>
@@ -1800,86 +3156,520 @@ namespace librawfilereader
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_instrument_model")]
         public static unsafe RawVec InstrumentModel(IntPtr handleToken)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.GetInstrumentInfo();
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetInstrumentInfo();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        /// <summary>
+        /// Count the spectra at MS levels 1-10, writing at most `length` counts to `countsOut`
+        /// </summary>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_spectra_per_ms_level")]
+        public static unsafe void SpectraPerMSLevel(IntPtr handleToken, uint* countsOut, int length)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var counts = reader.SpectraPerMSLevel();
+                for (var i = 0; i < Math.Min(counts.Length, length); i++)
+                {
+                    countsOut[i] = counts[i];
+                }
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_file_description")]
         public static unsafe RawVec FileDescription(IntPtr handleToken) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.GetFileMetadata();
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetFileMetadata();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_instrument_method")]
         public static unsafe RawVec InstrumentMethod(IntPtr handleToken, int method) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.GetInstrumentMethodFor(method);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetInstrumentMethodFor(method);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_instrument_method_count")]
         public static unsafe uint InstrumentMethodCount(IntPtr handleToken) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            return reader.GetInstrumentMethodCount();
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.GetInstrumentMethodCount();
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return 0;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_tic")]
         public static unsafe RawVec GetTIC(IntPtr handleToken) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.GetSummaryTrace(TraceType.TIC);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetSummaryTrace(TraceType.TIC);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_bpc")]
         public static unsafe RawVec GetBPC(IntPtr handleToken)
         {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.GetSummaryTrace(TraceType.BasePeak);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetSummaryTrace(TraceType.BasePeak);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        /// <summary>
+        /// Build a summary chromatogram restricted to scans matching a filter within a retention time window
+        /// </summary>
+        /// <param name="handleToken">The token corresponding to the `RawFileReader` handle</param>
+        /// <param name="traceType">The `TraceType` of the chromatogram, e.g. TIC or base peak</param>
+        /// <param name="filterPtr">A UTF-8 scan filter string, which may be null</param>
+        /// <param name="filterLength">The length of the scan filter string in bytes</param>
+        /// <param name="startTime">The earliest retention time to include, in minutes</param>
+        /// <param name="endTime">The latest retention time to include, in minutes</param>
+        /// <returns>A `RawVec` holding a `ChromatogramDescription` FlatBuffer message</returns>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_summary_trace")]
+        public static unsafe RawVec GetSummaryTrace(
+            IntPtr handleToken,
+            int traceType,
+            IntPtr filterPtr,
+            int filterLength,
+            double startTime,
+            double endTime)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var filter = filterLength > 0 ? Marshal.PtrToStringUTF8(filterPtr, filterLength) : null;
+                var buffer = reader.GetSummaryTrace((TraceType)traceType, filter, startTime, endTime);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_scan_number_for_time")]
+        public static unsafe int ScanNumberForTime(IntPtr handleToken, double time)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.ScanNumberForTime(time);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return -1;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_scans_in_time_range")]
+        public static unsafe void ScansInTimeRange(IntPtr handleToken, double startTime, double endTime, int* startScanOut, int* endScanOut)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var (startScan, endScan) = reader.ScansInTimeRange(startTime, endTime);
+                *startScanOut = startScan;
+                *endScanOut = endScan;
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_device_count")]
+        public static unsafe int DeviceCount(IntPtr handleToken, int device)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.DeviceCount((Device)device);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return -1;
+            }
+        }
+
+        /// <summary>
+        /// Build a chromatogram from a non-MS device
+        /// </summary>
+        /// <param name="handleToken">The token corresponding to the `RawFileReader` handle</param>
+        /// <param name="device">The `Device` kind to read from</param>
+        /// <param name="deviceNumber">The 1-based number of the device of that kind</param>
+        /// <param name="traceType">The `TraceType` of the chromatogram</param>
+        /// <param name="lowRange">The lower bound of the wavelength range, if applicable</param>
+        /// <param name="highRange">The upper bound of the wavelength range, if applicable</param>
+        /// <param name="startTime">The earliest retention time to include, in minutes</param>
+        /// <param name="endTime">The latest retention time to include, in minutes</param>
+        /// <returns>A `RawVec` holding a `ChromatogramDescription` FlatBuffer message</returns>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_device_trace")]
+        public static unsafe RawVec GetDeviceTrace(
+            IntPtr handleToken,
+            int device,
+            int deviceNumber,
+            int traceType,
+            double lowRange,
+            double highRange,
+            double startTime,
+            double endTime)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetDeviceTrace((Device)device, deviceNumber, (TraceType)traceType, lowRange, highRange, startTime, endTime);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_device_spectrum_count")]
+        public static unsafe int DeviceSpectrumCount(IntPtr handleToken, int device, int deviceNumber)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                return reader.DeviceSpectrumCount((Device)device, deviceNumber);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return -1;
+            }
+        }
+
+        /// <summary>
+        /// Get a `WavelengthSpectrumT` FlatBuffer message for a scan on a PDA or scanning UV device
+        /// </summary>
+        /// <param name="handleToken">The token corresponding to the `RawFileReader` handle</param>
+        /// <param name="device">The `Device` kind to read from</param>
+        /// <param name="deviceNumber">The 1-based number of the device of that kind</param>
+        /// <param name="scanNumber">The scan number on that device to read</param>
+        /// <returns>A `RawVec` representing Rust-allocated memory that holds the FlatBuffer message</returns>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_wavelength_spectrum_for")]
+        public static unsafe RawVec WavelengthSpectrumFor(IntPtr handleToken, int device, int deviceNumber, int scanNumber)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetWavelengthSpectrum((Device)device, deviceNumber, scanNumber);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        /// <summary>
+        /// Extract an ion chromatogram for each m/z interval, writing one `ChromatogramDescription` FlatBuffer
+        /// message per interval into `chromatogramsOut`.
+        /// </summary>
+        /// <param name="handleToken">The token corresponding to the `RawFileReader` handle</param>
+        /// <param name="mzRanges">A flat array of `2 * count` (low, high) m/z bounds</param>
+        /// <param name="count">The number of m/z intervals</param>
+        /// <param name="filterPtr">A UTF-8 scan filter string, which may be null</param>
+        /// <param name="filterLength">The length of the scan filter string in bytes</param>
+        /// <param name="startTime">The earliest retention time to include, in minutes</param>
+        /// <param name="endTime">The latest retention time to include, in minutes</param>
+        /// <param name="chromatogramsOut">An array of `count` `RawVec`s to write the messages to</param>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_mass_range_traces")]
+        public static unsafe void GetMassRangeTraces(
+            IntPtr handleToken,
+            double* mzRanges,
+            int count,
+            IntPtr filterPtr,
+            int filterLength,
+            double startTime,
+            double endTime,
+            RawVec* chromatogramsOut)
+        {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                if (count < 0) {
+                    throw new ArgumentOutOfRangeException(nameof(count), count, "The number of m/z intervals cannot be negative");
+                }
+                if (count > 0 && mzRanges == null) {
+                    throw new ArgumentNullException(nameof(mzRanges));
+                }
+                var filter = filterLength > 0 ? Marshal.PtrToStringUTF8(filterPtr, filterLength) : null;
+                var lowMzs = new double[count];
+                var highMzs = new double[count];
+                for (var i = 0; i < count; i++) {
+                    lowMzs[i] = mzRanges[2 * i];
+                    highMzs[i] = mzRanges[2 * i + 1];
+                }
+                var buffers = reader.GetMassRangeTraces(lowMzs, highMzs, filter, startTime, endTime);
+                for (var i = 0; i < buffers.Length; i++) {
+                    var buffer = buffers[i];
+                    var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                    var size = bytes.Length;
+                    chromatogramsOut[i] = MemoryToRawVec(bytes, (nuint)size);
+                }
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_raw_trailer_values_for")]
         public static unsafe RawVec GetRawTrailerValuesFor(IntPtr handleToken, int scanNumber) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.GetRawTrailersForScan(scanNumber);
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetRawTrailersForScan(scanNumber);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_typed_trailer_values_for")]
+        public static unsafe RawVec GetTypedTrailerValuesFor(IntPtr handleToken, int scanNumber) {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetTypedTrailersForScan(scanNumber);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_trailer_headers")]
+        public static unsafe RawVec GetTrailerHeaders(IntPtr handleToken) {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetTrailerHeaders();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        /// <summary>
+        /// Read the values of a single trailer extra header across a range of scans
+        /// </summary>
+        /// <param name="handleToken">The token corresponding to the `RawFileReader` handle</param>
+        /// <param name="labelPtr">The UTF-8 trailer label</param>
+        /// <param name="labelLength">The length of the trailer label in bytes</param>
+        /// <param name="startScan">The first scan number to read</param>
+        /// <param name="endScan">The last scan number to read, inclusive</param>
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_trailer_column")]
+        public static unsafe RawVec GetTrailerColumn(IntPtr handleToken, IntPtr labelPtr, int labelLength, int startScan, int endScan) {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var label = Marshal.PtrToStringUTF8(labelPtr, labelLength);
+                var buffer = reader.GetTrailerColumn(label, startScan, endScan);
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_acquisition_tree")]
+        public static unsafe RawVec GetAcquisitionTree(IntPtr handleToken) {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetAcquisitionTree();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_tune_data")]
+        public static unsafe RawVec GetTuneData(IntPtr handleToken) {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetTuneData();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_file_error_message")]
         public static unsafe RawVec GetErrorMessageFor(IntPtr handleToken) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var message = reader.FileErrorMessage();
-            // Bad things happen if the string is length zero.
-            // If the string is empty, instead operate on a string
-            // of just the nul byte.
-            message = message.Length == 0 ? "\0" : message;
-            var bytes = Encoding.UTF8.GetBytes(message);
-            var bytesSpan = bytes.AsSpan();
-            var size = bytes.Length;
-            return MemoryToRawVec(bytesSpan, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var message = reader.FileErrorMessage();
+                // Bad things happen if the string is length zero.
+                // If the string is empty, instead operate on a string
+                // of just the nul byte.
+                message = message.Length == 0 ? "\0" : message;
+                var bytes = Encoding.UTF8.GetBytes(message);
+                var bytesSpan = bytes.AsSpan();
+                var size = bytes.Length;
+                return MemoryToRawVec(bytesSpan, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_unique_filters")]
+        public static unsafe RawVec GetUniqueFilters(IntPtr handleToken) {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.GetUniqueFilters();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_error_log")]
+        public static unsafe RawVec GetErrorLog(IntPtr handleToken) {
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.ErrorLog();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
 
         [UnmanagedCallersOnly(EntryPoint = "rawfilereader_get_status_logs")]
         public static unsafe RawVec GetStatusLogs(IntPtr handleToken) {
-            RawFileReader reader = GetHandleForToken(handleToken);
-            var buffer = reader.StatusLogs();
-            var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
-            var size = bytes.Length;
-            return MemoryToRawVec(bytes, (nuint)size);
+            try
+            {
+                RawFileReader reader = GetHandleForToken(handleToken);
+                var buffer = reader.StatusLogs();
+                var bytes = buffer.ToSpan(buffer.Position, buffer.Length - buffer.Position);
+                var size = bytes.Length;
+                return MemoryToRawVec(bytes, (nuint)size);
+            }
+            catch (Exception e)
+            {
+                RecordException(e);
+                return default;
+            }
         }
     }
 }
@@ -34,6 +34,8 @@ public struct AcquisitionT : IFlatbufferObject
   public int ScanEvent { get { int o = __p.__offset(14); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)1; } }
   public librawfilereader.IonizationMode IonizationMode { get { int o = __p.__offset(16); return o != 0 ? (librawfilereader.IonizationMode)__p.bb.Get(o + __p.bb_pos) : librawfilereader.IonizationMode.NanoSpray; } }
   public float? Resolution { get { int o = __p.__offset(18); return o != 0 ? __p.bb.GetFloat(o + __p.bb_pos) : (float?)null; } }
+  public librawfilereader.MassRangeT? ScanWindows(int j) { int o = __p.__offset(20); return o != 0 ? (librawfilereader.MassRangeT?)(new librawfilereader.MassRangeT()).__assign(__p.__vector(o) + j * 16, __p.bb) : null; }
+  public int ScanWindowsLength { get { int o = __p.__offset(20); return o != 0 ? __p.__vector_len(o) : 0; } }
 
   public static Offset<librawfilereader.AcquisitionT> CreateAcquisitionT(FlatBufferBuilder builder,
       double low_mz = 0.0,
@@ -43,10 +45,12 @@ public struct AcquisitionT : IFlatbufferObject
       librawfilereader.MassAnalyzer mass_analyzer = librawfilereader.MassAnalyzer.FTMS,
       int scan_event = 1,
       librawfilereader.IonizationMode ionization_mode = librawfilereader.IonizationMode.NanoSpray,
-      float? resolution = null) {
-    builder.StartTable(8);
+      float? resolution = null,
+      VectorOffset scan_windowsOffset = default(VectorOffset)) {
+    builder.StartTable(9);
     AcquisitionT.AddHighMz(builder, high_mz);
     AcquisitionT.AddLowMz(builder, low_mz);
+    AcquisitionT.AddScanWindows(builder, scan_windowsOffset);
     AcquisitionT.AddResolution(builder, resolution);
     AcquisitionT.AddScanEvent(builder, scan_event);
     AcquisitionT.AddCompensationVoltages(builder, compensation_voltagesOffset);
@@ -56,7 +60,7 @@ public struct AcquisitionT : IFlatbufferObject
     return AcquisitionT.EndAcquisitionT(builder);
   }
 
-  public static void StartAcquisitionT(FlatBufferBuilder builder) { builder.StartTable(8); }
+  public static void StartAcquisitionT(FlatBufferBuilder builder) { builder.StartTable(9); }
   public static void AddLowMz(FlatBufferBuilder builder, double lowMz) { builder.AddDouble(0, lowMz, 0.0); }
   public static void AddHighMz(FlatBufferBuilder builder, double highMz) { builder.AddDouble(1, highMz, 0.0); }
   public static void AddInjectionTime(FlatBufferBuilder builder, float injectionTime) { builder.AddFloat(2, injectionTime, 0.0f); }
@@ -70,6 +74,8 @@ public struct AcquisitionT : IFlatbufferObject
   public static void AddScanEvent(FlatBufferBuilder builder, int scanEvent) { builder.AddInt(5, scanEvent, 1); }
   public static void AddIonizationMode(FlatBufferBuilder builder, librawfilereader.IonizationMode ionizationMode) { builder.AddByte(6, (byte)ionizationMode, 5); }
   public static void AddResolution(FlatBufferBuilder builder, float? resolution) { builder.AddFloat(7, resolution); }
+  public static void AddScanWindows(FlatBufferBuilder builder, VectorOffset scanWindowsOffset) { builder.AddOffset(8, scanWindowsOffset.Value, 0); }
+  public static void StartScanWindowsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(16, numElems, 8); }
   public static Offset<librawfilereader.AcquisitionT> EndAcquisitionT(FlatBufferBuilder builder) {
     int o = builder.EndTable();
     return new Offset<librawfilereader.AcquisitionT>(o);
@@ -90,6 +96,7 @@ static public class AcquisitionTVerify
       && verifier.VerifyField(tablePos, 14 /*ScanEvent*/, 4 /*int*/, 4, false)
       && verifier.VerifyField(tablePos, 16 /*IonizationMode*/, 1 /*librawfilereader.IonizationMode*/, 1, false)
       && verifier.VerifyField(tablePos, 18 /*Resolution*/, 4 /*float*/, 4, false)
+      && verifier.VerifyVectorOfData(tablePos, 20 /*ScanWindows*/, 16 /*librawfilereader.MassRangeT*/, false)
       && verifier.VerifyTableEnd(tablePos);
   }
 }
@@ -0,0 +1,62 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct AcquisitionTreeT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static AcquisitionTreeT GetRootAsAcquisitionTreeT(ByteBuffer _bb) { return GetRootAsAcquisitionTreeT(_bb, new AcquisitionTreeT()); }
+  public static AcquisitionTreeT GetRootAsAcquisitionTreeT(ByteBuffer _bb, AcquisitionTreeT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public AcquisitionTreeT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public int Parents(int j) { int o = __p.__offset(4); return o != 0 ? __p.bb.GetInt(__p.__vector(o) + j * 4) : (int)0; }
+  public int ParentsLength { get { int o = __p.__offset(4); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<int> GetParentsBytes() { return __p.__vector_as_span<int>(4, 4); }
+#else
+  public ArraySegment<byte>? GetParentsBytes() { return __p.__vector_as_arraysegment(4); }
+#endif
+  public int[] GetParentsArray() { return __p.__vector_as_array<int>(4); }
+
+  public static Offset<librawfilereader.AcquisitionTreeT> CreateAcquisitionTreeT(FlatBufferBuilder builder,
+      VectorOffset parentsOffset = default(VectorOffset)) {
+    builder.StartTable(1);
+    AcquisitionTreeT.AddParents(builder, parentsOffset);
+    return AcquisitionTreeT.EndAcquisitionTreeT(builder);
+  }
+
+  public static void StartAcquisitionTreeT(FlatBufferBuilder builder) { builder.StartTable(1); }
+  public static void AddParents(FlatBufferBuilder builder, VectorOffset parentsOffset) { builder.AddOffset(0, parentsOffset.Value, 0); }
+  public static VectorOffset CreateParentsVector(FlatBufferBuilder builder, int[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddInt(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateParentsVectorBlock(FlatBufferBuilder builder, int[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateParentsVectorBlock(FlatBufferBuilder builder, ArraySegment<int> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateParentsVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<int>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartParentsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.AcquisitionTreeT> EndAcquisitionTreeT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.AcquisitionTreeT>(o);
+  }
+}
+
+
+static public class AcquisitionTreeTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyVectorOfData(tablePos, 4 /*Parents*/, 4 /*int*/, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,89 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ActivationDetailsT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static ActivationDetailsT GetRootAsActivationDetailsT(ByteBuffer _bb) { return GetRootAsActivationDetailsT(_bb, new ActivationDetailsT()); }
+  public static ActivationDetailsT GetRootAsActivationDetailsT(ByteBuffer _bb, ActivationDetailsT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public ActivationDetailsT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public librawfilereader.ActivationStepT? Steps(int j) { int o = __p.__offset(4); return o != 0 ? (librawfilereader.ActivationStepT?)(new librawfilereader.ActivationStepT()).__assign(__p.__vector(o) + j * 24, __p.bb) : null; }
+  public int StepsLength { get { int o = __p.__offset(4); return o != 0 ? __p.__vector_len(o) : 0; } }
+  public double SteppedCollisionEnergies(int j) { int o = __p.__offset(6); return o != 0 ? __p.bb.GetDouble(__p.__vector(o) + j * 8) : (double)0; }
+  public int SteppedCollisionEnergiesLength { get { int o = __p.__offset(6); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<double> GetSteppedCollisionEnergiesBytes() { return __p.__vector_as_span<double>(6, 8); }
+#else
+  public ArraySegment<byte>? GetSteppedCollisionEnergiesBytes() { return __p.__vector_as_arraysegment(6); }
+#endif
+  public double[] GetSteppedCollisionEnergiesArray() { return __p.__vector_as_array<double>(6); }
+  public double? SupplementalEnergy { get { int o = __p.__offset(8); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double?)null; } }
+  public double? CollisionEnergyEv { get { int o = __p.__offset(10); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double?)null; } }
+  public double? EtdReactionTime { get { int o = __p.__offset(12); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double?)null; } }
+  public double? EtdReagentTarget { get { int o = __p.__offset(14); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double?)null; } }
+
+  public static Offset<librawfilereader.ActivationDetailsT> CreateActivationDetailsT(FlatBufferBuilder builder,
+      VectorOffset stepsOffset = default(VectorOffset),
+      VectorOffset stepped_collision_energiesOffset = default(VectorOffset),
+      double? supplemental_energy = null,
+      double? collision_energy_ev = null,
+      double? etd_reaction_time = null,
+      double? etd_reagent_target = null) {
+    builder.StartTable(6);
+    ActivationDetailsT.AddEtdReagentTarget(builder, etd_reagent_target);
+    ActivationDetailsT.AddEtdReactionTime(builder, etd_reaction_time);
+    ActivationDetailsT.AddCollisionEnergyEv(builder, collision_energy_ev);
+    ActivationDetailsT.AddSupplementalEnergy(builder, supplemental_energy);
+    ActivationDetailsT.AddSteppedCollisionEnergies(builder, stepped_collision_energiesOffset);
+    ActivationDetailsT.AddSteps(builder, stepsOffset);
+    return ActivationDetailsT.EndActivationDetailsT(builder);
+  }
+
+  public static void StartActivationDetailsT(FlatBufferBuilder builder) { builder.StartTable(6); }
+  public static void AddSteps(FlatBufferBuilder builder, VectorOffset stepsOffset) { builder.AddOffset(0, stepsOffset.Value, 0); }
+  public static void StartStepsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(24, numElems, 8); }
+  public static void AddSteppedCollisionEnergies(FlatBufferBuilder builder, VectorOffset steppedCollisionEnergiesOffset) { builder.AddOffset(1, steppedCollisionEnergiesOffset.Value, 0); }
+  public static VectorOffset CreateSteppedCollisionEnergiesVector(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddDouble(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateSteppedCollisionEnergiesVectorBlock(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSteppedCollisionEnergiesVectorBlock(FlatBufferBuilder builder, ArraySegment<double> data) { builder.StartVector(8, data.Count, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSteppedCollisionEnergiesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<double>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartSteppedCollisionEnergiesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(8, numElems, 8); }
+  public static void AddSupplementalEnergy(FlatBufferBuilder builder, double? supplementalEnergy) { builder.AddDouble(2, supplementalEnergy); }
+  public static void AddCollisionEnergyEv(FlatBufferBuilder builder, double? collisionEnergyEv) { builder.AddDouble(3, collisionEnergyEv); }
+  public static void AddEtdReactionTime(FlatBufferBuilder builder, double? etdReactionTime) { builder.AddDouble(4, etdReactionTime); }
+  public static void AddEtdReagentTarget(FlatBufferBuilder builder, double? etdReagentTarget) { builder.AddDouble(5, etdReagentTarget); }
+  public static Offset<librawfilereader.ActivationDetailsT> EndActivationDetailsT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.ActivationDetailsT>(o);
+  }
+}
+
+
+static public class ActivationDetailsTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyVectorOfData(tablePos, 4 /*Steps*/, 24 /*librawfilereader.ActivationStepT*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 6 /*SteppedCollisionEnergies*/, 8 /*double*/, false)
+      && verifier.VerifyField(tablePos, 8 /*SupplementalEnergy*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 10 /*CollisionEnergyEv*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 12 /*EtdReactionTime*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 14 /*EtdReagentTarget*/, 8 /*double*/, 8, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,35 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ActivationStepT : IFlatbufferObject
+{
+  private Struct __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Struct(_i, _bb); }
+  public ActivationStepT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public librawfilereader.DissociationMethod DissociationMethod { get { return (librawfilereader.DissociationMethod)__p.bb.Get(__p.bb_pos + 0); } }
+  public double CollisionEnergy { get { return __p.bb.GetDouble(__p.bb_pos + 8); } }
+  public bool Supplemental { get { return 0!=__p.bb.Get(__p.bb_pos + 16); } }
+
+  public static Offset<librawfilereader.ActivationStepT> CreateActivationStepT(FlatBufferBuilder builder, librawfilereader.DissociationMethod DissociationMethod, double CollisionEnergy, bool Supplemental) {
+    builder.Prep(8, 24);
+    builder.Pad(7);
+    builder.PutBool(Supplemental);
+    builder.PutDouble(CollisionEnergy);
+    builder.Pad(7);
+    builder.PutByte((byte)DissociationMethod);
+    return new Offset<librawfilereader.ActivationStepT>(builder.Offset);
+  }
+}
+
+
+}
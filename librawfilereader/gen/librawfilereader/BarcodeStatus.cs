@@ -0,0 +1,18 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+public enum BarcodeStatus : byte
+{
+  NotRead = 0,
+  Read = 1,
+  Unreadable = 2,
+  Error = 3,
+  Wait = 4,
+};
+
+
+}
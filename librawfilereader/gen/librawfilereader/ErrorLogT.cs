@@ -0,0 +1,73 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ErrorLogT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static ErrorLogT GetRootAsErrorLogT(ByteBuffer _bb) { return GetRootAsErrorLogT(_bb, new ErrorLogT()); }
+  public static ErrorLogT GetRootAsErrorLogT(ByteBuffer _bb, ErrorLogT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public ErrorLogT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public double Times(int j) { int o = __p.__offset(4); return o != 0 ? __p.bb.GetDouble(__p.__vector(o) + j * 8) : (double)0; }
+  public int TimesLength { get { int o = __p.__offset(4); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<double> GetTimesBytes() { return __p.__vector_as_span<double>(4, 8); }
+#else
+  public ArraySegment<byte>? GetTimesBytes() { return __p.__vector_as_arraysegment(4); }
+#endif
+  public double[] GetTimesArray() { return __p.__vector_as_array<double>(4); }
+  public string Messages(int j) { int o = __p.__offset(6); return o != 0 ? __p.__string(__p.__vector(o) + j * 4) : null; }
+  public int MessagesLength { get { int o = __p.__offset(6); return o != 0 ? __p.__vector_len(o) : 0; } }
+
+  public static Offset<librawfilereader.ErrorLogT> CreateErrorLogT(FlatBufferBuilder builder,
+      VectorOffset timesOffset = default(VectorOffset),
+      VectorOffset messagesOffset = default(VectorOffset)) {
+    builder.StartTable(2);
+    ErrorLogT.AddMessages(builder, messagesOffset);
+    ErrorLogT.AddTimes(builder, timesOffset);
+    return ErrorLogT.EndErrorLogT(builder);
+  }
+
+  public static void StartErrorLogT(FlatBufferBuilder builder) { builder.StartTable(2); }
+  public static void AddTimes(FlatBufferBuilder builder, VectorOffset timesOffset) { builder.AddOffset(0, timesOffset.Value, 0); }
+  public static VectorOffset CreateTimesVector(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddDouble(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateTimesVectorBlock(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateTimesVectorBlock(FlatBufferBuilder builder, ArraySegment<double> data) { builder.StartVector(8, data.Count, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateTimesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<double>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartTimesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(8, numElems, 8); }
+  public static void AddMessages(FlatBufferBuilder builder, VectorOffset messagesOffset) { builder.AddOffset(1, messagesOffset.Value, 0); }
+  public static VectorOffset CreateMessagesVector(FlatBufferBuilder builder, StringOffset[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateMessagesVectorBlock(FlatBufferBuilder builder, StringOffset[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateMessagesVectorBlock(FlatBufferBuilder builder, ArraySegment<StringOffset> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateMessagesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<StringOffset>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartMessagesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.ErrorLogT> EndErrorLogT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.ErrorLogT>(o);
+  }
+}
+
+
+static public class ErrorLogTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyVectorOfData(tablePos, 4 /*Times*/, 8 /*double*/, false)
+      && verifier.VerifyVectorOfStrings(tablePos, 6 /*Messages*/, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
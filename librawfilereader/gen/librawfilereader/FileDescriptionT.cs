@@ -71,29 +71,101 @@ public struct FileDescriptionT : IFlatbufferObject
   public ArraySegment<byte>? GetSampleCommentBytes() { return __p.__vector_as_arraysegment(18); }
 #endif
   public byte[] GetSampleCommentArray() { return __p.__vector_as_array<byte>(18); }
+  public long CreationTimestamp { get { int o = __p.__offset(20); return o != 0 ? __p.bb.GetLong(o + __p.bb_pos) : (long)0; } }
+  public long ModificationTimestamp { get { int o = __p.__offset(22); return o != 0 ? __p.bb.GetLong(o + __p.bb_pos) : (long)0; } }
+  public string Description { get { int o = __p.__offset(24); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetDescriptionBytes() { return __p.__vector_as_span<byte>(24, 1); }
+#else
+  public ArraySegment<byte>? GetDescriptionBytes() { return __p.__vector_as_arraysegment(24); }
+#endif
+  public byte[] GetDescriptionArray() { return __p.__vector_as_array<byte>(24); }
+  public string CreatorId { get { int o = __p.__offset(26); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetCreatorIdBytes() { return __p.__vector_as_span<byte>(26, 1); }
+#else
+  public ArraySegment<byte>? GetCreatorIdBytes() { return __p.__vector_as_arraysegment(26); }
+#endif
+  public byte[] GetCreatorIdArray() { return __p.__vector_as_array<byte>(26); }
+  public string CreatorLogon { get { int o = __p.__offset(28); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetCreatorLogonBytes() { return __p.__vector_as_span<byte>(28, 1); }
+#else
+  public ArraySegment<byte>? GetCreatorLogonBytes() { return __p.__vector_as_arraysegment(28); }
+#endif
+  public byte[] GetCreatorLogonArray() { return __p.__vector_as_array<byte>(28); }
+  public string ModifierId { get { int o = __p.__offset(30); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetModifierIdBytes() { return __p.__vector_as_span<byte>(30, 1); }
+#else
+  public ArraySegment<byte>? GetModifierIdBytes() { return __p.__vector_as_arraysegment(30); }
+#endif
+  public byte[] GetModifierIdArray() { return __p.__vector_as_array<byte>(30); }
+  public string ModifierLogon { get { int o = __p.__offset(32); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetModifierLogonBytes() { return __p.__vector_as_span<byte>(32, 1); }
+#else
+  public ArraySegment<byte>? GetModifierLogonBytes() { return __p.__vector_as_arraysegment(32); }
+#endif
+  public byte[] GetModifierLogonArray() { return __p.__vector_as_array<byte>(32); }
+  public int Revision { get { int o = __p.__offset(34); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public int TimesCalibrated { get { int o = __p.__offset(36); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public int TimesModified { get { int o = __p.__offset(38); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public librawfilereader.RunHeaderT? RunHeader { get { int o = __p.__offset(40); return o != 0 ? (librawfilereader.RunHeaderT?)(new librawfilereader.RunHeaderT()).__assign(o + __p.bb_pos, __p.bb) : null; } }
+  public librawfilereader.SampleType SampleType { get { int o = __p.__offset(42); return o != 0 ? (librawfilereader.SampleType)__p.bb.Get(o + __p.bb_pos) : librawfilereader.SampleType.Unknown; } }
+  public string SampleBarcode { get { int o = __p.__offset(44); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetSampleBarcodeBytes() { return __p.__vector_as_span<byte>(44, 1); }
+#else
+  public ArraySegment<byte>? GetSampleBarcodeBytes() { return __p.__vector_as_arraysegment(44); }
+#endif
+  public byte[] GetSampleBarcodeArray() { return __p.__vector_as_array<byte>(44); }
+  public librawfilereader.BarcodeStatus SampleBarcodeStatus { get { int o = __p.__offset(46); return o != 0 ? (librawfilereader.BarcodeStatus)__p.bb.Get(o + __p.bb_pos) : librawfilereader.BarcodeStatus.NotRead; } }
+  public double InjectionVolume { get { int o = __p.__offset(48); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double DilutionFactor { get { int o = __p.__offset(50); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double SampleWeight { get { int o = __p.__offset(52); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double SampleVolume { get { int o = __p.__offset(54); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double IstdAmount { get { int o = __p.__offset(56); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public string CalibrationLevel { get { int o = __p.__offset(58); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetCalibrationLevelBytes() { return __p.__vector_as_span<byte>(58, 1); }
+#else
+  public ArraySegment<byte>? GetCalibrationLevelBytes() { return __p.__vector_as_arraysegment(58); }
+#endif
+  public byte[] GetCalibrationLevelArray() { return __p.__vector_as_array<byte>(58); }
+  public int RowNumber { get { int o = __p.__offset(60); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public string InstrumentMethodFile { get { int o = __p.__offset(62); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetInstrumentMethodFileBytes() { return __p.__vector_as_span<byte>(62, 1); }
+#else
+  public ArraySegment<byte>? GetInstrumentMethodFileBytes() { return __p.__vector_as_arraysegment(62); }
+#endif
+  public byte[] GetInstrumentMethodFileArray() { return __p.__vector_as_array<byte>(62); }
+  public string ProcessingMethodFile { get { int o = __p.__offset(64); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetProcessingMethodFileBytes() { return __p.__vector_as_span<byte>(64, 1); }
+#else
+  public ArraySegment<byte>? GetProcessingMethodFileBytes() { return __p.__vector_as_arraysegment(64); }
+#endif
+  public byte[] GetProcessingMethodFileArray() { return __p.__vector_as_array<byte>(64); }
+  public string CalibrationFile { get { int o = __p.__offset(66); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetCalibrationFileBytes() { return __p.__vector_as_span<byte>(66, 1); }
+#else
+  public ArraySegment<byte>? GetCalibrationFileBytes() { return __p.__vector_as_arraysegment(66); }
+#endif
+  public byte[] GetCalibrationFileArray() { return __p.__vector_as_array<byte>(66); }
+  public string RawFilePath { get { int o = __p.__offset(68); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetRawFilePathBytes() { return __p.__vector_as_span<byte>(68, 1); }
+#else
+  public ArraySegment<byte>? GetRawFilePathBytes() { return __p.__vector_as_arraysegment(68); }
+#endif
+  public byte[] GetRawFilePathArray() { return __p.__vector_as_array<byte>(68); }
+  public string SampleUserText(int j) { int o = __p.__offset(70); return o != 0 ? __p.__string(__p.__vector(o) + j * 4) : null; }
+  public int SampleUserTextLength { get { int o = __p.__offset(70); return o != 0 ? __p.__vector_len(o) : 0; } }
 
-  public static Offset<librawfilereader.FileDescriptionT> CreateFileDescriptionT(FlatBufferBuilder builder,
-      StringOffset creation_dateOffset = default(StringOffset),
-      StringOffset sample_idOffset = default(StringOffset),
-      StringOffset source_fileOffset = default(StringOffset),
-      VectorOffset spectra_per_ms_levelOffset = default(VectorOffset),
-      VectorOffset trailer_headersOffset = default(VectorOffset),
-      StringOffset sample_nameOffset = default(StringOffset),
-      StringOffset sample_vialOffset = default(StringOffset),
-      StringOffset sample_commentOffset = default(StringOffset)) {
-    builder.StartTable(8);
-    FileDescriptionT.AddSampleComment(builder, sample_commentOffset);
-    FileDescriptionT.AddSampleVial(builder, sample_vialOffset);
-    FileDescriptionT.AddSampleName(builder, sample_nameOffset);
-    FileDescriptionT.AddTrailerHeaders(builder, trailer_headersOffset);
-    FileDescriptionT.AddSpectraPerMsLevel(builder, spectra_per_ms_levelOffset);
-    FileDescriptionT.AddSourceFile(builder, source_fileOffset);
-    FileDescriptionT.AddSampleId(builder, sample_idOffset);
-    FileDescriptionT.AddCreationDate(builder, creation_dateOffset);
-    return FileDescriptionT.EndFileDescriptionT(builder);
-  }
-
-  public static void StartFileDescriptionT(FlatBufferBuilder builder) { builder.StartTable(8); }
+  public static void StartFileDescriptionT(FlatBufferBuilder builder) { builder.StartTable(34); }
   public static void AddCreationDate(FlatBufferBuilder builder, StringOffset creationDateOffset) { builder.AddOffset(0, creationDateOffset.Value, 0); }
   public static void AddSampleId(FlatBufferBuilder builder, StringOffset sampleIdOffset) { builder.AddOffset(1, sampleIdOffset.Value, 0); }
   public static void AddSourceFile(FlatBufferBuilder builder, StringOffset sourceFileOffset) { builder.AddOffset(2, sourceFileOffset.Value, 0); }
@@ -112,6 +184,37 @@ public struct FileDescriptionT : IFlatbufferObject
   public static void AddSampleName(FlatBufferBuilder builder, StringOffset sampleNameOffset) { builder.AddOffset(5, sampleNameOffset.Value, 0); }
   public static void AddSampleVial(FlatBufferBuilder builder, StringOffset sampleVialOffset) { builder.AddOffset(6, sampleVialOffset.Value, 0); }
   public static void AddSampleComment(FlatBufferBuilder builder, StringOffset sampleCommentOffset) { builder.AddOffset(7, sampleCommentOffset.Value, 0); }
+  public static void AddCreationTimestamp(FlatBufferBuilder builder, long creationTimestamp) { builder.AddLong(8, creationTimestamp, 0); }
+  public static void AddModificationTimestamp(FlatBufferBuilder builder, long modificationTimestamp) { builder.AddLong(9, modificationTimestamp, 0); }
+  public static void AddDescription(FlatBufferBuilder builder, StringOffset descriptionOffset) { builder.AddOffset(10, descriptionOffset.Value, 0); }
+  public static void AddCreatorId(FlatBufferBuilder builder, StringOffset creatorIdOffset) { builder.AddOffset(11, creatorIdOffset.Value, 0); }
+  public static void AddCreatorLogon(FlatBufferBuilder builder, StringOffset creatorLogonOffset) { builder.AddOffset(12, creatorLogonOffset.Value, 0); }
+  public static void AddModifierId(FlatBufferBuilder builder, StringOffset modifierIdOffset) { builder.AddOffset(13, modifierIdOffset.Value, 0); }
+  public static void AddModifierLogon(FlatBufferBuilder builder, StringOffset modifierLogonOffset) { builder.AddOffset(14, modifierLogonOffset.Value, 0); }
+  public static void AddRevision(FlatBufferBuilder builder, int revision) { builder.AddInt(15, revision, 0); }
+  public static void AddTimesCalibrated(FlatBufferBuilder builder, int timesCalibrated) { builder.AddInt(16, timesCalibrated, 0); }
+  public static void AddTimesModified(FlatBufferBuilder builder, int timesModified) { builder.AddInt(17, timesModified, 0); }
+  public static void AddRunHeader(FlatBufferBuilder builder, Offset<librawfilereader.RunHeaderT> runHeaderOffset) { builder.AddStruct(18, runHeaderOffset.Value, 0); }
+  public static void AddSampleType(FlatBufferBuilder builder, librawfilereader.SampleType sampleType) { builder.AddByte(19, (byte)sampleType, 0); }
+  public static void AddSampleBarcode(FlatBufferBuilder builder, StringOffset sampleBarcodeOffset) { builder.AddOffset(20, sampleBarcodeOffset.Value, 0); }
+  public static void AddSampleBarcodeStatus(FlatBufferBuilder builder, librawfilereader.BarcodeStatus sampleBarcodeStatus) { builder.AddByte(21, (byte)sampleBarcodeStatus, 0); }
+  public static void AddInjectionVolume(FlatBufferBuilder builder, double injectionVolume) { builder.AddDouble(22, injectionVolume, 0.0); }
+  public static void AddDilutionFactor(FlatBufferBuilder builder, double dilutionFactor) { builder.AddDouble(23, dilutionFactor, 0.0); }
+  public static void AddSampleWeight(FlatBufferBuilder builder, double sampleWeight) { builder.AddDouble(24, sampleWeight, 0.0); }
+  public static void AddSampleVolume(FlatBufferBuilder builder, double sampleVolume) { builder.AddDouble(25, sampleVolume, 0.0); }
+  public static void AddIstdAmount(FlatBufferBuilder builder, double istdAmount) { builder.AddDouble(26, istdAmount, 0.0); }
+  public static void AddCalibrationLevel(FlatBufferBuilder builder, StringOffset calibrationLevelOffset) { builder.AddOffset(27, calibrationLevelOffset.Value, 0); }
+  public static void AddRowNumber(FlatBufferBuilder builder, int rowNumber) { builder.AddInt(28, rowNumber, 0); }
+  public static void AddInstrumentMethodFile(FlatBufferBuilder builder, StringOffset instrumentMethodFileOffset) { builder.AddOffset(29, instrumentMethodFileOffset.Value, 0); }
+  public static void AddProcessingMethodFile(FlatBufferBuilder builder, StringOffset processingMethodFileOffset) { builder.AddOffset(30, processingMethodFileOffset.Value, 0); }
+  public static void AddCalibrationFile(FlatBufferBuilder builder, StringOffset calibrationFileOffset) { builder.AddOffset(31, calibrationFileOffset.Value, 0); }
+  public static void AddRawFilePath(FlatBufferBuilder builder, StringOffset rawFilePathOffset) { builder.AddOffset(32, rawFilePathOffset.Value, 0); }
+  public static void AddSampleUserText(FlatBufferBuilder builder, VectorOffset sampleUserTextOffset) { builder.AddOffset(33, sampleUserTextOffset.Value, 0); }
+  public static VectorOffset CreateSampleUserTextVector(FlatBufferBuilder builder, StringOffset[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateSampleUserTextVectorBlock(FlatBufferBuilder builder, StringOffset[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSampleUserTextVectorBlock(FlatBufferBuilder builder, ArraySegment<StringOffset> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSampleUserTextVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<StringOffset>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartSampleUserTextVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
   public static Offset<librawfilereader.FileDescriptionT> EndFileDescriptionT(FlatBufferBuilder builder) {
     int o = builder.EndTable();
     return new Offset<librawfilereader.FileDescriptionT>(o);
@@ -132,6 +235,32 @@ static public class FileDescriptionTVerify
       && verifier.VerifyString(tablePos, 14 /*SampleName*/, false)
       && verifier.VerifyString(tablePos, 16 /*SampleVial*/, false)
       && verifier.VerifyString(tablePos, 18 /*SampleComment*/, false)
+      && verifier.VerifyField(tablePos, 20 /*CreationTimestamp*/, 8 /*long*/, 8, false)
+      && verifier.VerifyField(tablePos, 22 /*ModificationTimestamp*/, 8 /*long*/, 8, false)
+      && verifier.VerifyString(tablePos, 24 /*Description*/, false)
+      && verifier.VerifyString(tablePos, 26 /*CreatorId*/, false)
+      && verifier.VerifyString(tablePos, 28 /*CreatorLogon*/, false)
+      && verifier.VerifyString(tablePos, 30 /*ModifierId*/, false)
+      && verifier.VerifyString(tablePos, 32 /*ModifierLogon*/, false)
+      && verifier.VerifyField(tablePos, 34 /*Revision*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 36 /*TimesCalibrated*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 38 /*TimesModified*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 40 /*RunHeader*/, 64 /*librawfilereader.RunHeaderT*/, 8, false)
+      && verifier.VerifyField(tablePos, 42 /*SampleType*/, 1 /*librawfilereader.SampleType*/, 1, false)
+      && verifier.VerifyString(tablePos, 44 /*SampleBarcode*/, false)
+      && verifier.VerifyField(tablePos, 46 /*SampleBarcodeStatus*/, 1 /*librawfilereader.BarcodeStatus*/, 1, false)
+      && verifier.VerifyField(tablePos, 48 /*InjectionVolume*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 50 /*DilutionFactor*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 52 /*SampleWeight*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 54 /*SampleVolume*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 56 /*IstdAmount*/, 8 /*double*/, 8, false)
+      && verifier.VerifyString(tablePos, 58 /*CalibrationLevel*/, false)
+      && verifier.VerifyField(tablePos, 60 /*RowNumber*/, 4 /*int*/, 4, false)
+      && verifier.VerifyString(tablePos, 62 /*InstrumentMethodFile*/, false)
+      && verifier.VerifyString(tablePos, 64 /*ProcessingMethodFile*/, false)
+      && verifier.VerifyString(tablePos, 66 /*CalibrationFile*/, false)
+      && verifier.VerifyString(tablePos, 68 /*RawFilePath*/, false)
+      && verifier.VerifyVectorOfStrings(tablePos, 70 /*SampleUserText*/, false)
       && verifier.VerifyTableEnd(tablePos);
   }
 }
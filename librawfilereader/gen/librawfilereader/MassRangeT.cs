@@ -0,0 +1,31 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct MassRangeT : IFlatbufferObject
+{
+  private Struct __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Struct(_i, _bb); }
+  public MassRangeT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public double Low { get { return __p.bb.GetDouble(__p.bb_pos + 0); } }
+  public double High { get { return __p.bb.GetDouble(__p.bb_pos + 8); } }
+
+  public static Offset<librawfilereader.MassRangeT> CreateMassRangeT(FlatBufferBuilder builder, double Low, double High) {
+    builder.Prep(8, 16);
+    builder.PutDouble(High);
+    builder.PutDouble(Low);
+    return new Offset<librawfilereader.MassRangeT>(builder.Offset);
+  }
+}
+
+
+}
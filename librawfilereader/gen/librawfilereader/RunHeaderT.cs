@@ -0,0 +1,44 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct RunHeaderT : IFlatbufferObject
+{
+  private Struct __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Struct(_i, _bb); }
+  public RunHeaderT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public double StartTime { get { return __p.bb.GetDouble(__p.bb_pos + 0); } }
+  public double EndTime { get { return __p.bb.GetDouble(__p.bb_pos + 8); } }
+  public double LowMass { get { return __p.bb.GetDouble(__p.bb_pos + 16); } }
+  public double HighMass { get { return __p.bb.GetDouble(__p.bb_pos + 24); } }
+  public double ExpectedRunTime { get { return __p.bb.GetDouble(__p.bb_pos + 32); } }
+  public double MassResolution { get { return __p.bb.GetDouble(__p.bb_pos + 40); } }
+  public double MaxIntegratedIntensity { get { return __p.bb.GetDouble(__p.bb_pos + 48); } }
+  public int MaxIntensity { get { return __p.bb.GetInt(__p.bb_pos + 56); } }
+
+  public static Offset<librawfilereader.RunHeaderT> CreateRunHeaderT(FlatBufferBuilder builder, double StartTime, double EndTime, double LowMass, double HighMass, double ExpectedRunTime, double MassResolution, double MaxIntegratedIntensity, int MaxIntensity) {
+    builder.Prep(8, 64);
+    builder.Pad(4);
+    builder.PutInt(MaxIntensity);
+    builder.PutDouble(MaxIntegratedIntensity);
+    builder.PutDouble(MassResolution);
+    builder.PutDouble(ExpectedRunTime);
+    builder.PutDouble(HighMass);
+    builder.PutDouble(LowMass);
+    builder.PutDouble(EndTime);
+    builder.PutDouble(StartTime);
+    return new Offset<librawfilereader.RunHeaderT>(builder.Offset);
+  }
+}
+
+
+}
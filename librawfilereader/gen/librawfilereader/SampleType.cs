@@ -0,0 +1,26 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+public enum SampleType : byte
+{
+  Unknown = 0,
+  Blank = 1,
+  QC = 2,
+  StdClear = 3,
+  StdUpdate = 4,
+  StdBracket = 5,
+  StdBracketStart = 6,
+  StdBracketEnd = 7,
+  Program = 8,
+  SolventBlank = 9,
+  MatrixBlank = 10,
+  MatrixSpike = 11,
+  MatrixSpikeDuplicate = 12,
+};
+
+
+}
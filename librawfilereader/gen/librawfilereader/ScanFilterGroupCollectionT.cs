@@ -0,0 +1,56 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ScanFilterGroupCollectionT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static ScanFilterGroupCollectionT GetRootAsScanFilterGroupCollectionT(ByteBuffer _bb) { return GetRootAsScanFilterGroupCollectionT(_bb, new ScanFilterGroupCollectionT()); }
+  public static ScanFilterGroupCollectionT GetRootAsScanFilterGroupCollectionT(ByteBuffer _bb, ScanFilterGroupCollectionT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public ScanFilterGroupCollectionT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public librawfilereader.ScanFilterGroupT? Groups(int j) { int o = __p.__offset(4); return o != 0 ? (librawfilereader.ScanFilterGroupT?)(new librawfilereader.ScanFilterGroupT()).__assign(__p.__indirect(__p.__vector(o) + j * 4), __p.bb) : null; }
+  public int GroupsLength { get { int o = __p.__offset(4); return o != 0 ? __p.__vector_len(o) : 0; } }
+
+  public static Offset<librawfilereader.ScanFilterGroupCollectionT> CreateScanFilterGroupCollectionT(FlatBufferBuilder builder,
+      VectorOffset groupsOffset = default(VectorOffset)) {
+    builder.StartTable(1);
+    ScanFilterGroupCollectionT.AddGroups(builder, groupsOffset);
+    return ScanFilterGroupCollectionT.EndScanFilterGroupCollectionT(builder);
+  }
+
+  public static void StartScanFilterGroupCollectionT(FlatBufferBuilder builder) { builder.StartTable(1); }
+  public static void AddGroups(FlatBufferBuilder builder, VectorOffset groupsOffset) { builder.AddOffset(0, groupsOffset.Value, 0); }
+  public static VectorOffset CreateGroupsVector(FlatBufferBuilder builder, Offset<librawfilereader.ScanFilterGroupT>[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateGroupsVectorBlock(FlatBufferBuilder builder, Offset<librawfilereader.ScanFilterGroupT>[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateGroupsVectorBlock(FlatBufferBuilder builder, ArraySegment<Offset<librawfilereader.ScanFilterGroupT>> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateGroupsVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<Offset<librawfilereader.ScanFilterGroupT>>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartGroupsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.ScanFilterGroupCollectionT> EndScanFilterGroupCollectionT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.ScanFilterGroupCollectionT>(o);
+  }
+}
+
+
+static public class ScanFilterGroupCollectionTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyVectorOfTables(tablePos, 4 /*Groups*/, librawfilereader.ScanFilterGroupTVerify.Verify, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,78 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ScanFilterGroupT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static ScanFilterGroupT GetRootAsScanFilterGroupT(ByteBuffer _bb) { return GetRootAsScanFilterGroupT(_bb, new ScanFilterGroupT()); }
+  public static ScanFilterGroupT GetRootAsScanFilterGroupT(ByteBuffer _bb, ScanFilterGroupT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public ScanFilterGroupT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public string FilterString { get { int o = __p.__offset(4); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetFilterStringBytes() { return __p.__vector_as_span<byte>(4, 1); }
+#else
+  public ArraySegment<byte>? GetFilterStringBytes() { return __p.__vector_as_arraysegment(4); }
+#endif
+  public byte[] GetFilterStringArray() { return __p.__vector_as_array<byte>(4); }
+  public librawfilereader.ScanFilterT? ScanFilter { get { int o = __p.__offset(6); return o != 0 ? (librawfilereader.ScanFilterT?)(new librawfilereader.ScanFilterT()).__assign(__p.__indirect(o + __p.bb_pos), __p.bb) : null; } }
+  public uint Indices(int j) { int o = __p.__offset(8); return o != 0 ? __p.bb.GetUint(__p.__vector(o) + j * 4) : (uint)0; }
+  public int IndicesLength { get { int o = __p.__offset(8); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<uint> GetIndicesBytes() { return __p.__vector_as_span<uint>(8, 4); }
+#else
+  public ArraySegment<byte>? GetIndicesBytes() { return __p.__vector_as_arraysegment(8); }
+#endif
+  public uint[] GetIndicesArray() { return __p.__vector_as_array<uint>(8); }
+
+  public static Offset<librawfilereader.ScanFilterGroupT> CreateScanFilterGroupT(FlatBufferBuilder builder,
+      StringOffset filter_stringOffset = default(StringOffset),
+      Offset<librawfilereader.ScanFilterT> scan_filterOffset = default(Offset<librawfilereader.ScanFilterT>),
+      VectorOffset indicesOffset = default(VectorOffset)) {
+    builder.StartTable(3);
+    ScanFilterGroupT.AddIndices(builder, indicesOffset);
+    ScanFilterGroupT.AddScanFilter(builder, scan_filterOffset);
+    ScanFilterGroupT.AddFilterString(builder, filter_stringOffset);
+    return ScanFilterGroupT.EndScanFilterGroupT(builder);
+  }
+
+  public static void StartScanFilterGroupT(FlatBufferBuilder builder) { builder.StartTable(3); }
+  public static void AddFilterString(FlatBufferBuilder builder, StringOffset filterStringOffset) { builder.AddOffset(0, filterStringOffset.Value, 0); }
+  public static void AddScanFilter(FlatBufferBuilder builder, Offset<librawfilereader.ScanFilterT> scanFilterOffset) { builder.AddOffset(1, scanFilterOffset.Value, 0); }
+  public static void AddIndices(FlatBufferBuilder builder, VectorOffset indicesOffset) { builder.AddOffset(2, indicesOffset.Value, 0); }
+  public static VectorOffset CreateIndicesVector(FlatBufferBuilder builder, uint[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddUint(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateIndicesVectorBlock(FlatBufferBuilder builder, uint[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateIndicesVectorBlock(FlatBufferBuilder builder, ArraySegment<uint> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateIndicesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<uint>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartIndicesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.ScanFilterGroupT> EndScanFilterGroupT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.ScanFilterGroupT>(o);
+  }
+}
+
+
+static public class ScanFilterGroupTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyString(tablePos, 4 /*FilterString*/, false)
+      && verifier.VerifyTable(tablePos, 6 /*ScanFilter*/, librawfilereader.ScanFilterTVerify.Verify, false)
+      && verifier.VerifyVectorOfData(tablePos, 8 /*Indices*/, 4 /*uint*/, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,38 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ScanFilterReactionT : IFlatbufferObject
+{
+  private Struct __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Struct(_i, _bb); }
+  public ScanFilterReactionT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public double PrecursorMz { get { return __p.bb.GetDouble(__p.bb_pos + 0); } }
+  public double IsolationWidth { get { return __p.bb.GetDouble(__p.bb_pos + 8); } }
+  public double CollisionEnergy { get { return __p.bb.GetDouble(__p.bb_pos + 16); } }
+  public librawfilereader.DissociationMethod DissociationMethod { get { return (librawfilereader.DissociationMethod)__p.bb.Get(__p.bb_pos + 24); } }
+  public bool MultipleActivation { get { return 0!=__p.bb.Get(__p.bb_pos + 25); } }
+
+  public static Offset<librawfilereader.ScanFilterReactionT> CreateScanFilterReactionT(FlatBufferBuilder builder, double PrecursorMz, double IsolationWidth, double CollisionEnergy, librawfilereader.DissociationMethod DissociationMethod, bool MultipleActivation) {
+    builder.Prep(8, 32);
+    builder.Pad(6);
+    builder.PutBool(MultipleActivation);
+    builder.PutByte((byte)DissociationMethod);
+    builder.PutDouble(CollisionEnergy);
+    builder.PutDouble(IsolationWidth);
+    builder.PutDouble(PrecursorMz);
+    return new Offset<librawfilereader.ScanFilterReactionT>(builder.Offset);
+  }
+}
+
+
+}
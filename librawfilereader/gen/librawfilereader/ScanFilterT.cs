@@ -0,0 +1,126 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ScanFilterT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static ScanFilterT GetRootAsScanFilterT(ByteBuffer _bb) { return GetRootAsScanFilterT(_bb, new ScanFilterT()); }
+  public static ScanFilterT GetRootAsScanFilterT(ByteBuffer _bb, ScanFilterT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public ScanFilterT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public librawfilereader.MassAnalyzer MassAnalyzer { get { int o = __p.__offset(4); return o != 0 ? (librawfilereader.MassAnalyzer)__p.bb.Get(o + __p.bb_pos) : librawfilereader.MassAnalyzer.Unknown; } }
+  public librawfilereader.Polarity Polarity { get { int o = __p.__offset(6); return o != 0 ? (librawfilereader.Polarity)__p.bb.GetSbyte(o + __p.bb_pos) : librawfilereader.Polarity.Unknown; } }
+  public librawfilereader.ScanMode ScanMode { get { int o = __p.__offset(8); return o != 0 ? (librawfilereader.ScanMode)__p.bb.Get(o + __p.bb_pos) : librawfilereader.ScanMode.Full; } }
+  public librawfilereader.MSOrder MsOrder { get { int o = __p.__offset(10); return o != 0 ? (librawfilereader.MSOrder)__p.bb.GetShort(o + __p.bb_pos) : librawfilereader.MSOrder.Unknown; } }
+  public librawfilereader.SpectrumMode Mode { get { int o = __p.__offset(12); return o != 0 ? (librawfilereader.SpectrumMode)__p.bb.GetSbyte(o + __p.bb_pos) : librawfilereader.SpectrumMode.Unknown; } }
+  public librawfilereader.IonizationMode IonizationMode { get { int o = __p.__offset(14); return o != 0 ? (librawfilereader.IonizationMode)__p.bb.Get(o + __p.bb_pos) : librawfilereader.IonizationMode.Any; } }
+  public librawfilereader.ScanFilterReactionT? Reactions(int j) { int o = __p.__offset(16); return o != 0 ? (librawfilereader.ScanFilterReactionT?)(new librawfilereader.ScanFilterReactionT()).__assign(__p.__vector(o) + j * 32, __p.bb) : null; }
+  public int ReactionsLength { get { int o = __p.__offset(16); return o != 0 ? __p.__vector_len(o) : 0; } }
+  public librawfilereader.MassRangeT? MassRanges(int j) { int o = __p.__offset(18); return o != 0 ? (librawfilereader.MassRangeT?)(new librawfilereader.MassRangeT()).__assign(__p.__vector(o) + j * 16, __p.bb) : null; }
+  public int MassRangesLength { get { int o = __p.__offset(18); return o != 0 ? __p.__vector_len(o) : 0; } }
+  public double CompensationVoltages(int j) { int o = __p.__offset(20); return o != 0 ? __p.bb.GetDouble(__p.__vector(o) + j * 8) : (double)0; }
+  public int CompensationVoltagesLength { get { int o = __p.__offset(20); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<double> GetCompensationVoltagesBytes() { return __p.__vector_as_span<double>(20, 8); }
+#else
+  public ArraySegment<byte>? GetCompensationVoltagesBytes() { return __p.__vector_as_arraysegment(20); }
+#endif
+  public double[] GetCompensationVoltagesArray() { return __p.__vector_as_array<double>(20); }
+  public double? DetectorValue { get { int o = __p.__offset(22); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double?)null; } }
+  public bool Sps { get { int o = __p.__offset(24); return o != 0 ? 0!=__p.bb.Get(o + __p.bb_pos) : false; } }
+  public bool Dependent { get { int o = __p.__offset(26); return o != 0 ? 0!=__p.bb.Get(o + __p.bb_pos) : false; } }
+  public bool SupplementalActivation { get { int o = __p.__offset(28); return o != 0 ? 0!=__p.bb.Get(o + __p.bb_pos) : false; } }
+
+  public static Offset<librawfilereader.ScanFilterT> CreateScanFilterT(FlatBufferBuilder builder,
+      librawfilereader.MassAnalyzer mass_analyzer = librawfilereader.MassAnalyzer.Unknown,
+      librawfilereader.Polarity polarity = librawfilereader.Polarity.Unknown,
+      librawfilereader.ScanMode scan_mode = librawfilereader.ScanMode.Full,
+      librawfilereader.MSOrder ms_order = librawfilereader.MSOrder.Unknown,
+      librawfilereader.SpectrumMode mode = librawfilereader.SpectrumMode.Unknown,
+      librawfilereader.IonizationMode ionization_mode = librawfilereader.IonizationMode.Any,
+      VectorOffset reactionsOffset = default(VectorOffset),
+      VectorOffset mass_rangesOffset = default(VectorOffset),
+      VectorOffset compensation_voltagesOffset = default(VectorOffset),
+      double? detector_value = null,
+      bool sps = false,
+      bool dependent = false,
+      bool supplemental_activation = false) {
+    builder.StartTable(13);
+    ScanFilterT.AddDetectorValue(builder, detector_value);
+    ScanFilterT.AddCompensationVoltages(builder, compensation_voltagesOffset);
+    ScanFilterT.AddMassRanges(builder, mass_rangesOffset);
+    ScanFilterT.AddReactions(builder, reactionsOffset);
+    ScanFilterT.AddMsOrder(builder, ms_order);
+    ScanFilterT.AddSupplementalActivation(builder, supplemental_activation);
+    ScanFilterT.AddDependent(builder, dependent);
+    ScanFilterT.AddSps(builder, sps);
+    ScanFilterT.AddIonizationMode(builder, ionization_mode);
+    ScanFilterT.AddMode(builder, mode);
+    ScanFilterT.AddScanMode(builder, scan_mode);
+    ScanFilterT.AddPolarity(builder, polarity);
+    ScanFilterT.AddMassAnalyzer(builder, mass_analyzer);
+    return ScanFilterT.EndScanFilterT(builder);
+  }
+
+  public static void StartScanFilterT(FlatBufferBuilder builder) { builder.StartTable(13); }
+  public static void AddMassAnalyzer(FlatBufferBuilder builder, librawfilereader.MassAnalyzer massAnalyzer) { builder.AddByte(0, (byte)massAnalyzer, 0); }
+  public static void AddPolarity(FlatBufferBuilder builder, librawfilereader.Polarity polarity) { builder.AddSbyte(1, (sbyte)polarity, 0); }
+  public static void AddScanMode(FlatBufferBuilder builder, librawfilereader.ScanMode scanMode) { builder.AddByte(2, (byte)scanMode, 0); }
+  public static void AddMsOrder(FlatBufferBuilder builder, librawfilereader.MSOrder msOrder) { builder.AddShort(3, (short)msOrder, 999); }
+  public static void AddMode(FlatBufferBuilder builder, librawfilereader.SpectrumMode mode) { builder.AddSbyte(4, (sbyte)mode, 0); }
+  public static void AddIonizationMode(FlatBufferBuilder builder, librawfilereader.IonizationMode ionizationMode) { builder.AddByte(5, (byte)ionizationMode, 10); }
+  public static void AddReactions(FlatBufferBuilder builder, VectorOffset reactionsOffset) { builder.AddOffset(6, reactionsOffset.Value, 0); }
+  public static void StartReactionsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(32, numElems, 8); }
+  public static void AddMassRanges(FlatBufferBuilder builder, VectorOffset massRangesOffset) { builder.AddOffset(7, massRangesOffset.Value, 0); }
+  public static void StartMassRangesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(16, numElems, 8); }
+  public static void AddCompensationVoltages(FlatBufferBuilder builder, VectorOffset compensationVoltagesOffset) { builder.AddOffset(8, compensationVoltagesOffset.Value, 0); }
+  public static VectorOffset CreateCompensationVoltagesVector(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddDouble(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateCompensationVoltagesVectorBlock(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateCompensationVoltagesVectorBlock(FlatBufferBuilder builder, ArraySegment<double> data) { builder.StartVector(8, data.Count, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateCompensationVoltagesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<double>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartCompensationVoltagesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(8, numElems, 8); }
+  public static void AddDetectorValue(FlatBufferBuilder builder, double? detectorValue) { builder.AddDouble(9, detectorValue); }
+  public static void AddSps(FlatBufferBuilder builder, bool sps) { builder.AddBool(10, sps, false); }
+  public static void AddDependent(FlatBufferBuilder builder, bool dependent) { builder.AddBool(11, dependent, false); }
+  public static void AddSupplementalActivation(FlatBufferBuilder builder, bool supplementalActivation) { builder.AddBool(12, supplementalActivation, false); }
+  public static Offset<librawfilereader.ScanFilterT> EndScanFilterT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.ScanFilterT>(o);
+  }
+}
+
+
+static public class ScanFilterTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyField(tablePos, 4 /*MassAnalyzer*/, 1 /*librawfilereader.MassAnalyzer*/, 1, false)
+      && verifier.VerifyField(tablePos, 6 /*Polarity*/, 1 /*librawfilereader.Polarity*/, 1, false)
+      && verifier.VerifyField(tablePos, 8 /*ScanMode*/, 1 /*librawfilereader.ScanMode*/, 1, false)
+      && verifier.VerifyField(tablePos, 10 /*MsOrder*/, 2 /*librawfilereader.MSOrder*/, 2, false)
+      && verifier.VerifyField(tablePos, 12 /*Mode*/, 1 /*librawfilereader.SpectrumMode*/, 1, false)
+      && verifier.VerifyField(tablePos, 14 /*IonizationMode*/, 1 /*librawfilereader.IonizationMode*/, 1, false)
+      && verifier.VerifyVectorOfData(tablePos, 16 /*Reactions*/, 32 /*librawfilereader.ScanFilterReactionT*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 18 /*MassRanges*/, 16 /*librawfilereader.MassRangeT*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 20 /*CompensationVoltages*/, 8 /*double*/, false)
+      && verifier.VerifyField(tablePos, 22 /*DetectorValue*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 24 /*Sps*/, 1 /*bool*/, 1, false)
+      && verifier.VerifyField(tablePos, 26 /*Dependent*/, 1 /*bool*/, 1, false)
+      && verifier.VerifyField(tablePos, 28 /*SupplementalActivation*/, 1 /*bool*/, 1, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,151 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct ScanStatisticsT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static ScanStatisticsT GetRootAsScanStatisticsT(ByteBuffer _bb) { return GetRootAsScanStatisticsT(_bb, new ScanStatisticsT()); }
+  public static ScanStatisticsT GetRootAsScanStatisticsT(ByteBuffer _bb, ScanStatisticsT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public ScanStatisticsT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public double Tic { get { int o = __p.__offset(4); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double BasePeakMz { get { int o = __p.__offset(6); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double BasePeakIntensity { get { int o = __p.__offset(8); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double LowMass { get { int o = __p.__offset(10); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double HighMass { get { int o = __p.__offset(12); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public int PacketType { get { int o = __p.__offset(14); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public int PacketCount { get { int o = __p.__offset(16); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public int SegmentNumber { get { int o = __p.__offset(18); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public int CycleNumber { get { int o = __p.__offset(20); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public int ScanEventNumber { get { int o = __p.__offset(22); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public int NumberOfChannels { get { int o = __p.__offset(24); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public double Frequency { get { int o = __p.__offset(26); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public bool IsUniformTime { get { int o = __p.__offset(28); return o != 0 ? 0!=__p.bb.Get(o + __p.bb_pos) : false; } }
+  public bool IsCentroid { get { int o = __p.__offset(30); return o != 0 ? 0!=__p.bb.Get(o + __p.bb_pos) : false; } }
+  public double ShortWavelength { get { int o = __p.__offset(32); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double LongWavelength { get { int o = __p.__offset(34); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double WavelengthStep { get { int o = __p.__offset(36); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double AbsorbanceUnitScale { get { int o = __p.__offset(38); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public string ScanType { get { int o = __p.__offset(40); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetScanTypeBytes() { return __p.__vector_as_span<byte>(40, 1); }
+#else
+  public ArraySegment<byte>? GetScanTypeBytes() { return __p.__vector_as_arraysegment(40); }
+#endif
+  public byte[] GetScanTypeArray() { return __p.__vector_as_array<byte>(40); }
+  public int SpectrumCount { get { int o = __p.__offset(42); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+
+  public static Offset<librawfilereader.ScanStatisticsT> CreateScanStatisticsT(FlatBufferBuilder builder,
+      double tic = 0.0,
+      double base_peak_mz = 0.0,
+      double base_peak_intensity = 0.0,
+      double low_mass = 0.0,
+      double high_mass = 0.0,
+      int packet_type = 0,
+      int packet_count = 0,
+      int segment_number = 0,
+      int cycle_number = 0,
+      int scan_event_number = 0,
+      int number_of_channels = 0,
+      double frequency = 0.0,
+      bool is_uniform_time = false,
+      bool is_centroid = false,
+      double short_wavelength = 0.0,
+      double long_wavelength = 0.0,
+      double wavelength_step = 0.0,
+      double absorbance_unit_scale = 0.0,
+      StringOffset scan_typeOffset = default(StringOffset),
+      int spectrum_count = 0) {
+    builder.StartTable(20);
+    ScanStatisticsT.AddAbsorbanceUnitScale(builder, absorbance_unit_scale);
+    ScanStatisticsT.AddWavelengthStep(builder, wavelength_step);
+    ScanStatisticsT.AddLongWavelength(builder, long_wavelength);
+    ScanStatisticsT.AddShortWavelength(builder, short_wavelength);
+    ScanStatisticsT.AddFrequency(builder, frequency);
+    ScanStatisticsT.AddHighMass(builder, high_mass);
+    ScanStatisticsT.AddLowMass(builder, low_mass);
+    ScanStatisticsT.AddBasePeakIntensity(builder, base_peak_intensity);
+    ScanStatisticsT.AddBasePeakMz(builder, base_peak_mz);
+    ScanStatisticsT.AddTic(builder, tic);
+    ScanStatisticsT.AddSpectrumCount(builder, spectrum_count);
+    ScanStatisticsT.AddScanType(builder, scan_typeOffset);
+    ScanStatisticsT.AddNumberOfChannels(builder, number_of_channels);
+    ScanStatisticsT.AddScanEventNumber(builder, scan_event_number);
+    ScanStatisticsT.AddCycleNumber(builder, cycle_number);
+    ScanStatisticsT.AddSegmentNumber(builder, segment_number);
+    ScanStatisticsT.AddPacketCount(builder, packet_count);
+    ScanStatisticsT.AddPacketType(builder, packet_type);
+    ScanStatisticsT.AddIsCentroid(builder, is_centroid);
+    ScanStatisticsT.AddIsUniformTime(builder, is_uniform_time);
+    return ScanStatisticsT.EndScanStatisticsT(builder);
+  }
+
+  public static void StartScanStatisticsT(FlatBufferBuilder builder) { builder.StartTable(20); }
+  public static void AddTic(FlatBufferBuilder builder, double tic) { builder.AddDouble(0, tic, 0.0); }
+  public static void AddBasePeakMz(FlatBufferBuilder builder, double basePeakMz) { builder.AddDouble(1, basePeakMz, 0.0); }
+  public static void AddBasePeakIntensity(FlatBufferBuilder builder, double basePeakIntensity) { builder.AddDouble(2, basePeakIntensity, 0.0); }
+  public static void AddLowMass(FlatBufferBuilder builder, double lowMass) { builder.AddDouble(3, lowMass, 0.0); }
+  public static void AddHighMass(FlatBufferBuilder builder, double highMass) { builder.AddDouble(4, highMass, 0.0); }
+  public static void AddPacketType(FlatBufferBuilder builder, int packetType) { builder.AddInt(5, packetType, 0); }
+  public static void AddPacketCount(FlatBufferBuilder builder, int packetCount) { builder.AddInt(6, packetCount, 0); }
+  public static void AddSegmentNumber(FlatBufferBuilder builder, int segmentNumber) { builder.AddInt(7, segmentNumber, 0); }
+  public static void AddCycleNumber(FlatBufferBuilder builder, int cycleNumber) { builder.AddInt(8, cycleNumber, 0); }
+  public static void AddScanEventNumber(FlatBufferBuilder builder, int scanEventNumber) { builder.AddInt(9, scanEventNumber, 0); }
+  public static void AddNumberOfChannels(FlatBufferBuilder builder, int numberOfChannels) { builder.AddInt(10, numberOfChannels, 0); }
+  public static void AddFrequency(FlatBufferBuilder builder, double frequency) { builder.AddDouble(11, frequency, 0.0); }
+  public static void AddIsUniformTime(FlatBufferBuilder builder, bool isUniformTime) { builder.AddBool(12, isUniformTime, false); }
+  public static void AddIsCentroid(FlatBufferBuilder builder, bool isCentroid) { builder.AddBool(13, isCentroid, false); }
+  public static void AddShortWavelength(FlatBufferBuilder builder, double shortWavelength) { builder.AddDouble(14, shortWavelength, 0.0); }
+  public static void AddLongWavelength(FlatBufferBuilder builder, double longWavelength) { builder.AddDouble(15, longWavelength, 0.0); }
+  public static void AddWavelengthStep(FlatBufferBuilder builder, double wavelengthStep) { builder.AddDouble(16, wavelengthStep, 0.0); }
+  public static void AddAbsorbanceUnitScale(FlatBufferBuilder builder, double absorbanceUnitScale) { builder.AddDouble(17, absorbanceUnitScale, 0.0); }
+  public static void AddScanType(FlatBufferBuilder builder, StringOffset scanTypeOffset) { builder.AddOffset(18, scanTypeOffset.Value, 0); }
+  public static void AddSpectrumCount(FlatBufferBuilder builder, int spectrumCount) { builder.AddInt(19, spectrumCount, 0); }
+  public static Offset<librawfilereader.ScanStatisticsT> EndScanStatisticsT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.ScanStatisticsT>(o);
+  }
+}
+
+
+static public class ScanStatisticsTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyField(tablePos, 4 /*Tic*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 6 /*BasePeakMz*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 8 /*BasePeakIntensity*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 10 /*LowMass*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 12 /*HighMass*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 14 /*PacketType*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 16 /*PacketCount*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 18 /*SegmentNumber*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 20 /*CycleNumber*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 22 /*ScanEventNumber*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 24 /*NumberOfChannels*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 26 /*Frequency*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 28 /*IsUniformTime*/, 1 /*bool*/, 1, false)
+      && verifier.VerifyField(tablePos, 30 /*IsCentroid*/, 1 /*bool*/, 1, false)
+      && verifier.VerifyField(tablePos, 32 /*ShortWavelength*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 34 /*LongWavelength*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 36 /*WavelengthStep*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 38 /*AbsorbanceUnitScale*/, 8 /*double*/, 8, false)
+      && verifier.VerifyString(tablePos, 40 /*ScanType*/, false)
+      && verifier.VerifyField(tablePos, 42 /*SpectrumCount*/, 4 /*int*/, 4, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -35,17 +35,37 @@ public struct SpectrumData : IFlatbufferObject
   public ArraySegment<byte>? GetIntensityBytes() { return __p.__vector_as_arraysegment(6); }
 #endif
   public float[] GetIntensityArray() { return __p.__vector_as_array<float>(6); }
+  public byte Flags(int j) { int o = __p.__offset(8); return o != 0 ? __p.bb.Get(__p.__vector(o) + j * 1) : (byte)0; }
+  public int FlagsLength { get { int o = __p.__offset(8); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetFlagsBytes() { return __p.__vector_as_span<byte>(8, 1); }
+#else
+  public ArraySegment<byte>? GetFlagsBytes() { return __p.__vector_as_arraysegment(8); }
+#endif
+  public byte[] GetFlagsArray() { return __p.__vector_as_array<byte>(8); }
+  public uint SegmentOffsets(int j) { int o = __p.__offset(10); return o != 0 ? __p.bb.GetUint(__p.__vector(o) + j * 4) : (uint)0; }
+  public int SegmentOffsetsLength { get { int o = __p.__offset(10); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<uint> GetSegmentOffsetsBytes() { return __p.__vector_as_span<uint>(10, 4); }
+#else
+  public ArraySegment<byte>? GetSegmentOffsetsBytes() { return __p.__vector_as_arraysegment(10); }
+#endif
+  public uint[] GetSegmentOffsetsArray() { return __p.__vector_as_array<uint>(10); }
 
   public static Offset<librawfilereader.SpectrumData> CreateSpectrumData(FlatBufferBuilder builder,
       VectorOffset mzOffset = default(VectorOffset),
-      VectorOffset intensityOffset = default(VectorOffset)) {
-    builder.StartTable(2);
+      VectorOffset intensityOffset = default(VectorOffset),
+      VectorOffset flagsOffset = default(VectorOffset),
+      VectorOffset segment_offsetsOffset = default(VectorOffset)) {
+    builder.StartTable(4);
+    SpectrumData.AddSegmentOffsets(builder, segment_offsetsOffset);
+    SpectrumData.AddFlags(builder, flagsOffset);
     SpectrumData.AddIntensity(builder, intensityOffset);
     SpectrumData.AddMz(builder, mzOffset);
     return SpectrumData.EndSpectrumData(builder);
   }
 
-  public static void StartSpectrumData(FlatBufferBuilder builder) { builder.StartTable(2); }
+  public static void StartSpectrumData(FlatBufferBuilder builder) { builder.StartTable(4); }
   public static void AddMz(FlatBufferBuilder builder, VectorOffset mzOffset) { builder.AddOffset(0, mzOffset.Value, 0); }
   public static VectorOffset CreateMzVector(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddDouble(data[i]); return builder.EndVector(); }
   public static VectorOffset CreateMzVectorBlock(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
@@ -58,6 +78,18 @@ public struct SpectrumData : IFlatbufferObject
   public static VectorOffset CreateIntensityVectorBlock(FlatBufferBuilder builder, ArraySegment<float> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
   public static VectorOffset CreateIntensityVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<float>(dataPtr, sizeInBytes); return builder.EndVector(); }
   public static void StartIntensityVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static void AddFlags(FlatBufferBuilder builder, VectorOffset flagsOffset) { builder.AddOffset(2, flagsOffset.Value, 0); }
+  public static VectorOffset CreateFlagsVector(FlatBufferBuilder builder, byte[] data) { builder.StartVector(1, data.Length, 1); for (int i = data.Length - 1; i >= 0; i--) builder.AddByte(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateFlagsVectorBlock(FlatBufferBuilder builder, byte[] data) { builder.StartVector(1, data.Length, 1); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateFlagsVectorBlock(FlatBufferBuilder builder, ArraySegment<byte> data) { builder.StartVector(1, data.Count, 1); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateFlagsVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<byte>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartFlagsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(1, numElems, 1); }
+  public static void AddSegmentOffsets(FlatBufferBuilder builder, VectorOffset segmentOffsetsOffset) { builder.AddOffset(3, segmentOffsetsOffset.Value, 0); }
+  public static VectorOffset CreateSegmentOffsetsVector(FlatBufferBuilder builder, uint[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddUint(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateSegmentOffsetsVectorBlock(FlatBufferBuilder builder, uint[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSegmentOffsetsVectorBlock(FlatBufferBuilder builder, ArraySegment<uint> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSegmentOffsetsVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<uint>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartSegmentOffsetsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
   public static Offset<librawfilereader.SpectrumData> EndSpectrumData(FlatBufferBuilder builder) {
     int o = builder.EndTable();
     return new Offset<librawfilereader.SpectrumData>(o);
@@ -72,6 +104,8 @@ static public class SpectrumDataVerify
     return verifier.VerifyTableStart(tablePos)
       && verifier.VerifyVectorOfData(tablePos, 4 /*Mz*/, 8 /*double*/, false)
       && verifier.VerifyVectorOfData(tablePos, 6 /*Intensity*/, 4 /*float*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 8 /*Flags*/, 1 /*byte*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 10 /*SegmentOffsets*/, 4 /*uint*/, false)
       && verifier.VerifyTableEnd(tablePos);
   }
 }
@@ -37,8 +37,15 @@ public struct SpectrumDescription : IFlatbufferObject
   public librawfilereader.AcquisitionT? Acquisition { get { int o = __p.__offset(20); return o != 0 ? (librawfilereader.AcquisitionT?)(new librawfilereader.AcquisitionT()).__assign(__p.__indirect(o + __p.bb_pos), __p.bb) : null; } }
   public librawfilereader.MSOrder MsOrder { get { int o = __p.__offset(22); return o != 0 ? (librawfilereader.MSOrder)__p.bb.GetShort(o + __p.bb_pos) : librawfilereader.MSOrder.Unknown; } }
   public librawfilereader.ScanMode ScanMode { get { int o = __p.__offset(24); return o != 0 ? (librawfilereader.ScanMode)__p.bb.Get(o + __p.bb_pos) : librawfilereader.ScanMode.Full; } }
+  public int ControllerNumber { get { int o = __p.__offset(26); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)1; } }
+  public librawfilereader.ScanFilterT? ScanFilter { get { int o = __p.__offset(28); return o != 0 ? (librawfilereader.ScanFilterT?)(new librawfilereader.ScanFilterT()).__assign(__p.__indirect(o + __p.bb_pos), __p.bb) : null; } }
+  public librawfilereader.ScanStatisticsT? Statistics { get { int o = __p.__offset(30); return o != 0 ? (librawfilereader.ScanStatisticsT?)(new librawfilereader.ScanStatisticsT()).__assign(__p.__indirect(o + __p.bb_pos), __p.bb) : null; } }
+  public librawfilereader.PrecursorT? Precursors(int j) { int o = __p.__offset(32); return o != 0 ? (librawfilereader.PrecursorT?)(new librawfilereader.PrecursorT()).__assign(__p.__vector(o) + j * 64, __p.bb) : null; }
+  public int PrecursorsLength { get { int o = __p.__offset(32); return o != 0 ? __p.__vector_len(o) : 0; } }
+  public librawfilereader.ActivationDetailsT? ActivationDetails(int j) { int o = __p.__offset(34); return o != 0 ? (librawfilereader.ActivationDetailsT?)(new librawfilereader.ActivationDetailsT()).__assign(__p.__indirect(__p.__vector(o) + j * 4), __p.bb) : null; }
+  public int ActivationDetailsLength { get { int o = __p.__offset(34); return o != 0 ? __p.__vector_len(o) : 0; } }
 
-  public static void StartSpectrumDescription(FlatBufferBuilder builder) { builder.StartTable(11); }
+  public static void StartSpectrumDescription(FlatBufferBuilder builder) { builder.StartTable(16); }
   public static void AddIndex(FlatBufferBuilder builder, int index) { builder.AddInt(0, index, 0); }
   public static void AddMsLevel(FlatBufferBuilder builder, byte msLevel) { builder.AddByte(1, msLevel, 2); }
   public static void AddTime(FlatBufferBuilder builder, double time) { builder.AddDouble(2, time, 0.0); }
@@ -50,6 +57,17 @@ public struct SpectrumDescription : IFlatbufferObject
   public static void AddAcquisition(FlatBufferBuilder builder, Offset<librawfilereader.AcquisitionT> acquisitionOffset) { builder.AddOffset(8, acquisitionOffset.Value, 0); }
   public static void AddMsOrder(FlatBufferBuilder builder, librawfilereader.MSOrder msOrder) { builder.AddShort(9, (short)msOrder, 999); }
   public static void AddScanMode(FlatBufferBuilder builder, librawfilereader.ScanMode scanMode) { builder.AddByte(10, (byte)scanMode, 0); }
+  public static void AddControllerNumber(FlatBufferBuilder builder, int controllerNumber) { builder.AddInt(11, controllerNumber, 1); }
+  public static void AddScanFilter(FlatBufferBuilder builder, Offset<librawfilereader.ScanFilterT> scanFilterOffset) { builder.AddOffset(12, scanFilterOffset.Value, 0); }
+  public static void AddStatistics(FlatBufferBuilder builder, Offset<librawfilereader.ScanStatisticsT> statisticsOffset) { builder.AddOffset(13, statisticsOffset.Value, 0); }
+  public static void AddPrecursors(FlatBufferBuilder builder, VectorOffset precursorsOffset) { builder.AddOffset(14, precursorsOffset.Value, 0); }
+  public static void StartPrecursorsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(64, numElems, 8); }
+  public static void AddActivationDetails(FlatBufferBuilder builder, VectorOffset activationDetailsOffset) { builder.AddOffset(15, activationDetailsOffset.Value, 0); }
+  public static VectorOffset CreateActivationDetailsVector(FlatBufferBuilder builder, Offset<librawfilereader.ActivationDetailsT>[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateActivationDetailsVectorBlock(FlatBufferBuilder builder, Offset<librawfilereader.ActivationDetailsT>[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateActivationDetailsVectorBlock(FlatBufferBuilder builder, ArraySegment<Offset<librawfilereader.ActivationDetailsT>> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateActivationDetailsVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<Offset<librawfilereader.ActivationDetailsT>>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartActivationDetailsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
   public static Offset<librawfilereader.SpectrumDescription> EndSpectrumDescription(FlatBufferBuilder builder) {
     int o = builder.EndTable();
     return new Offset<librawfilereader.SpectrumDescription>(o);
@@ -75,6 +93,11 @@ static public class SpectrumDescriptionVerify
       && verifier.VerifyTable(tablePos, 20 /*Acquisition*/, librawfilereader.AcquisitionTVerify.Verify, false)
       && verifier.VerifyField(tablePos, 22 /*MsOrder*/, 2 /*librawfilereader.MSOrder*/, 2, false)
       && verifier.VerifyField(tablePos, 24 /*ScanMode*/, 1 /*librawfilereader.ScanMode*/, 1, false)
+      && verifier.VerifyField(tablePos, 26 /*ControllerNumber*/, 4 /*int*/, 4, false)
+      && verifier.VerifyTable(tablePos, 28 /*ScanFilter*/, librawfilereader.ScanFilterTVerify.Verify, false)
+      && verifier.VerifyTable(tablePos, 30 /*Statistics*/, librawfilereader.ScanStatisticsTVerify.Verify, false)
+      && verifier.VerifyVectorOfData(tablePos, 32 /*Precursors*/, 64 /*librawfilereader.PrecursorT*/, false)
+      && verifier.VerifyVectorOfTables(tablePos, 34 /*ActivationDetails*/, librawfilereader.ActivationDetailsTVerify.Verify, false)
       && verifier.VerifyTableEnd(tablePos);
   }
 }
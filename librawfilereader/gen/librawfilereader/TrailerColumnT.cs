@@ -0,0 +1,140 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct TrailerColumnT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static TrailerColumnT GetRootAsTrailerColumnT(ByteBuffer _bb) { return GetRootAsTrailerColumnT(_bb, new TrailerColumnT()); }
+  public static TrailerColumnT GetRootAsTrailerColumnT(ByteBuffer _bb, TrailerColumnT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public TrailerColumnT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public string Label { get { int o = __p.__offset(4); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetLabelBytes() { return __p.__vector_as_span<byte>(4, 1); }
+#else
+  public ArraySegment<byte>? GetLabelBytes() { return __p.__vector_as_arraysegment(4); }
+#endif
+  public byte[] GetLabelArray() { return __p.__vector_as_array<byte>(4); }
+  public librawfilereader.TrailerDataType DataType { get { int o = __p.__offset(6); return o != 0 ? (librawfilereader.TrailerDataType)__p.bb.Get(o + __p.bb_pos) : librawfilereader.TrailerDataType.Null; } }
+  public double Times(int j) { int o = __p.__offset(8); return o != 0 ? __p.bb.GetDouble(__p.__vector(o) + j * 8) : (double)0; }
+  public int TimesLength { get { int o = __p.__offset(8); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<double> GetTimesBytes() { return __p.__vector_as_span<double>(8, 8); }
+#else
+  public ArraySegment<byte>? GetTimesBytes() { return __p.__vector_as_arraysegment(8); }
+#endif
+  public double[] GetTimesArray() { return __p.__vector_as_array<double>(8); }
+  public double FloatValues(int j) { int o = __p.__offset(10); return o != 0 ? __p.bb.GetDouble(__p.__vector(o) + j * 8) : (double)0; }
+  public int FloatValuesLength { get { int o = __p.__offset(10); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<double> GetFloatValuesBytes() { return __p.__vector_as_span<double>(10, 8); }
+#else
+  public ArraySegment<byte>? GetFloatValuesBytes() { return __p.__vector_as_arraysegment(10); }
+#endif
+  public double[] GetFloatValuesArray() { return __p.__vector_as_array<double>(10); }
+  public long IntValues(int j) { int o = __p.__offset(12); return o != 0 ? __p.bb.GetLong(__p.__vector(o) + j * 8) : (long)0; }
+  public int IntValuesLength { get { int o = __p.__offset(12); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<long> GetIntValuesBytes() { return __p.__vector_as_span<long>(12, 8); }
+#else
+  public ArraySegment<byte>? GetIntValuesBytes() { return __p.__vector_as_arraysegment(12); }
+#endif
+  public long[] GetIntValuesArray() { return __p.__vector_as_array<long>(12); }
+  public string StringValues(int j) { int o = __p.__offset(14); return o != 0 ? __p.__string(__p.__vector(o) + j * 4) : null; }
+  public int StringValuesLength { get { int o = __p.__offset(14); return o != 0 ? __p.__vector_len(o) : 0; } }
+  public bool Valid(int j) { int o = __p.__offset(16); return o != 0 ? 0!=__p.bb.Get(__p.__vector(o) + j * 1) : false; }
+  public int ValidLength { get { int o = __p.__offset(16); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<bool> GetValidBytes() { return __p.__vector_as_span<bool>(16, 1); }
+#else
+  public ArraySegment<byte>? GetValidBytes() { return __p.__vector_as_arraysegment(16); }
+#endif
+  public bool[] GetValidArray() { return __p.__vector_as_array<bool>(16); }
+
+  public static Offset<librawfilereader.TrailerColumnT> CreateTrailerColumnT(FlatBufferBuilder builder,
+      StringOffset labelOffset = default(StringOffset),
+      librawfilereader.TrailerDataType data_type = librawfilereader.TrailerDataType.Null,
+      VectorOffset timesOffset = default(VectorOffset),
+      VectorOffset float_valuesOffset = default(VectorOffset),
+      VectorOffset int_valuesOffset = default(VectorOffset),
+      VectorOffset string_valuesOffset = default(VectorOffset),
+      VectorOffset validOffset = default(VectorOffset)) {
+    builder.StartTable(7);
+    TrailerColumnT.AddValid(builder, validOffset);
+    TrailerColumnT.AddStringValues(builder, string_valuesOffset);
+    TrailerColumnT.AddIntValues(builder, int_valuesOffset);
+    TrailerColumnT.AddFloatValues(builder, float_valuesOffset);
+    TrailerColumnT.AddTimes(builder, timesOffset);
+    TrailerColumnT.AddLabel(builder, labelOffset);
+    TrailerColumnT.AddDataType(builder, data_type);
+    return TrailerColumnT.EndTrailerColumnT(builder);
+  }
+
+  public static void StartTrailerColumnT(FlatBufferBuilder builder) { builder.StartTable(7); }
+  public static void AddLabel(FlatBufferBuilder builder, StringOffset labelOffset) { builder.AddOffset(0, labelOffset.Value, 0); }
+  public static void AddDataType(FlatBufferBuilder builder, librawfilereader.TrailerDataType dataType) { builder.AddByte(1, (byte)dataType, 0); }
+  public static void AddTimes(FlatBufferBuilder builder, VectorOffset timesOffset) { builder.AddOffset(2, timesOffset.Value, 0); }
+  public static VectorOffset CreateTimesVector(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddDouble(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateTimesVectorBlock(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateTimesVectorBlock(FlatBufferBuilder builder, ArraySegment<double> data) { builder.StartVector(8, data.Count, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateTimesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<double>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartTimesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(8, numElems, 8); }
+  public static void AddFloatValues(FlatBufferBuilder builder, VectorOffset floatValuesOffset) { builder.AddOffset(3, floatValuesOffset.Value, 0); }
+  public static VectorOffset CreateFloatValuesVector(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddDouble(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateFloatValuesVectorBlock(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateFloatValuesVectorBlock(FlatBufferBuilder builder, ArraySegment<double> data) { builder.StartVector(8, data.Count, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateFloatValuesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<double>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartFloatValuesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(8, numElems, 8); }
+  public static void AddIntValues(FlatBufferBuilder builder, VectorOffset intValuesOffset) { builder.AddOffset(4, intValuesOffset.Value, 0); }
+  public static VectorOffset CreateIntValuesVector(FlatBufferBuilder builder, long[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddLong(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateIntValuesVectorBlock(FlatBufferBuilder builder, long[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateIntValuesVectorBlock(FlatBufferBuilder builder, ArraySegment<long> data) { builder.StartVector(8, data.Count, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateIntValuesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<long>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartIntValuesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(8, numElems, 8); }
+  public static void AddStringValues(FlatBufferBuilder builder, VectorOffset stringValuesOffset) { builder.AddOffset(5, stringValuesOffset.Value, 0); }
+  public static VectorOffset CreateStringValuesVector(FlatBufferBuilder builder, StringOffset[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateStringValuesVectorBlock(FlatBufferBuilder builder, StringOffset[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateStringValuesVectorBlock(FlatBufferBuilder builder, ArraySegment<StringOffset> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateStringValuesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<StringOffset>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartStringValuesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static void AddValid(FlatBufferBuilder builder, VectorOffset validOffset) { builder.AddOffset(6, validOffset.Value, 0); }
+  public static VectorOffset CreateValidVector(FlatBufferBuilder builder, bool[] data) { builder.StartVector(1, data.Length, 1); for (int i = data.Length - 1; i >= 0; i--) builder.AddBool(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateValidVectorBlock(FlatBufferBuilder builder, bool[] data) { builder.StartVector(1, data.Length, 1); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateValidVectorBlock(FlatBufferBuilder builder, ArraySegment<bool> data) { builder.StartVector(1, data.Count, 1); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateValidVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<bool>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartValidVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(1, numElems, 1); }
+  public static Offset<librawfilereader.TrailerColumnT> EndTrailerColumnT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.TrailerColumnT>(o);
+  }
+}
+
+
+static public class TrailerColumnTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyString(tablePos, 4 /*Label*/, false)
+      && verifier.VerifyField(tablePos, 6 /*DataType*/, 1 /*librawfilereader.TrailerDataType*/, 1, false)
+      && verifier.VerifyVectorOfData(tablePos, 8 /*Times*/, 8 /*double*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 10 /*FloatValues*/, 8 /*double*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 12 /*IntValues*/, 8 /*long*/, false)
+      && verifier.VerifyVectorOfStrings(tablePos, 14 /*StringValues*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 16 /*Valid*/, 1 /*bool*/, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,27 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+public enum TrailerDataType : byte
+{
+  Null = 0,
+  Char = 1,
+  TrueFalse = 2,
+  YesNo = 3,
+  OnOff = 4,
+  UChar = 5,
+  Short = 6,
+  UShort = 7,
+  Long = 8,
+  ULong = 9,
+  Float = 10,
+  Double = 11,
+  CharString = 12,
+  WCharString = 13,
+};
+
+
+}
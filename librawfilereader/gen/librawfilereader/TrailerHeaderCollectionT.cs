@@ -0,0 +1,56 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct TrailerHeaderCollectionT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static TrailerHeaderCollectionT GetRootAsTrailerHeaderCollectionT(ByteBuffer _bb) { return GetRootAsTrailerHeaderCollectionT(_bb, new TrailerHeaderCollectionT()); }
+  public static TrailerHeaderCollectionT GetRootAsTrailerHeaderCollectionT(ByteBuffer _bb, TrailerHeaderCollectionT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public TrailerHeaderCollectionT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public librawfilereader.TrailerHeaderT? Headers(int j) { int o = __p.__offset(4); return o != 0 ? (librawfilereader.TrailerHeaderT?)(new librawfilereader.TrailerHeaderT()).__assign(__p.__indirect(__p.__vector(o) + j * 4), __p.bb) : null; }
+  public int HeadersLength { get { int o = __p.__offset(4); return o != 0 ? __p.__vector_len(o) : 0; } }
+
+  public static Offset<librawfilereader.TrailerHeaderCollectionT> CreateTrailerHeaderCollectionT(FlatBufferBuilder builder,
+      VectorOffset headersOffset = default(VectorOffset)) {
+    builder.StartTable(1);
+    TrailerHeaderCollectionT.AddHeaders(builder, headersOffset);
+    return TrailerHeaderCollectionT.EndTrailerHeaderCollectionT(builder);
+  }
+
+  public static void StartTrailerHeaderCollectionT(FlatBufferBuilder builder) { builder.StartTable(1); }
+  public static void AddHeaders(FlatBufferBuilder builder, VectorOffset headersOffset) { builder.AddOffset(0, headersOffset.Value, 0); }
+  public static VectorOffset CreateHeadersVector(FlatBufferBuilder builder, Offset<librawfilereader.TrailerHeaderT>[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateHeadersVectorBlock(FlatBufferBuilder builder, Offset<librawfilereader.TrailerHeaderT>[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateHeadersVectorBlock(FlatBufferBuilder builder, ArraySegment<Offset<librawfilereader.TrailerHeaderT>> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateHeadersVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<Offset<librawfilereader.TrailerHeaderT>>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartHeadersVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.TrailerHeaderCollectionT> EndTrailerHeaderCollectionT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.TrailerHeaderCollectionT>(o);
+  }
+}
+
+
+static public class TrailerHeaderCollectionTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyVectorOfTables(tablePos, 4 /*Headers*/, librawfilereader.TrailerHeaderTVerify.Verify, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,66 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct TrailerHeaderT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static TrailerHeaderT GetRootAsTrailerHeaderT(ByteBuffer _bb) { return GetRootAsTrailerHeaderT(_bb, new TrailerHeaderT()); }
+  public static TrailerHeaderT GetRootAsTrailerHeaderT(ByteBuffer _bb, TrailerHeaderT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public TrailerHeaderT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public string Label { get { int o = __p.__offset(4); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetLabelBytes() { return __p.__vector_as_span<byte>(4, 1); }
+#else
+  public ArraySegment<byte>? GetLabelBytes() { return __p.__vector_as_arraysegment(4); }
+#endif
+  public byte[] GetLabelArray() { return __p.__vector_as_array<byte>(4); }
+  public librawfilereader.TrailerDataType DataType { get { int o = __p.__offset(6); return o != 0 ? (librawfilereader.TrailerDataType)__p.bb.Get(o + __p.bb_pos) : librawfilereader.TrailerDataType.Null; } }
+  public int LengthOrPrecision { get { int o = __p.__offset(8); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+
+  public static Offset<librawfilereader.TrailerHeaderT> CreateTrailerHeaderT(FlatBufferBuilder builder,
+      StringOffset labelOffset = default(StringOffset),
+      librawfilereader.TrailerDataType data_type = librawfilereader.TrailerDataType.Null,
+      int length_or_precision = 0) {
+    builder.StartTable(3);
+    TrailerHeaderT.AddLengthOrPrecision(builder, length_or_precision);
+    TrailerHeaderT.AddLabel(builder, labelOffset);
+    TrailerHeaderT.AddDataType(builder, data_type);
+    return TrailerHeaderT.EndTrailerHeaderT(builder);
+  }
+
+  public static void StartTrailerHeaderT(FlatBufferBuilder builder) { builder.StartTable(3); }
+  public static void AddLabel(FlatBufferBuilder builder, StringOffset labelOffset) { builder.AddOffset(0, labelOffset.Value, 0); }
+  public static void AddDataType(FlatBufferBuilder builder, librawfilereader.TrailerDataType dataType) { builder.AddByte(1, (byte)dataType, 0); }
+  public static void AddLengthOrPrecision(FlatBufferBuilder builder, int lengthOrPrecision) { builder.AddInt(2, lengthOrPrecision, 0); }
+  public static Offset<librawfilereader.TrailerHeaderT> EndTrailerHeaderT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.TrailerHeaderT>(o);
+  }
+}
+
+
+static public class TrailerHeaderTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyString(tablePos, 4 /*Label*/, false)
+      && verifier.VerifyField(tablePos, 6 /*DataType*/, 1 /*librawfilereader.TrailerDataType*/, 1, false)
+      && verifier.VerifyField(tablePos, 8 /*LengthOrPrecision*/, 4 /*int*/, 4, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,61 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct TuneDataSegmentT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static TuneDataSegmentT GetRootAsTuneDataSegmentT(ByteBuffer _bb) { return GetRootAsTuneDataSegmentT(_bb, new TuneDataSegmentT()); }
+  public static TuneDataSegmentT GetRootAsTuneDataSegmentT(ByteBuffer _bb, TuneDataSegmentT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public TuneDataSegmentT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public int Index { get { int o = __p.__offset(4); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public librawfilereader.TypedTrailerValueT? Values(int j) { int o = __p.__offset(6); return o != 0 ? (librawfilereader.TypedTrailerValueT?)(new librawfilereader.TypedTrailerValueT()).__assign(__p.__indirect(__p.__vector(o) + j * 4), __p.bb) : null; }
+  public int ValuesLength { get { int o = __p.__offset(6); return o != 0 ? __p.__vector_len(o) : 0; } }
+
+  public static Offset<librawfilereader.TuneDataSegmentT> CreateTuneDataSegmentT(FlatBufferBuilder builder,
+      int index = 0,
+      VectorOffset valuesOffset = default(VectorOffset)) {
+    builder.StartTable(2);
+    TuneDataSegmentT.AddValues(builder, valuesOffset);
+    TuneDataSegmentT.AddIndex(builder, index);
+    return TuneDataSegmentT.EndTuneDataSegmentT(builder);
+  }
+
+  public static void StartTuneDataSegmentT(FlatBufferBuilder builder) { builder.StartTable(2); }
+  public static void AddIndex(FlatBufferBuilder builder, int index) { builder.AddInt(0, index, 0); }
+  public static void AddValues(FlatBufferBuilder builder, VectorOffset valuesOffset) { builder.AddOffset(1, valuesOffset.Value, 0); }
+  public static VectorOffset CreateValuesVector(FlatBufferBuilder builder, Offset<librawfilereader.TypedTrailerValueT>[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateValuesVectorBlock(FlatBufferBuilder builder, Offset<librawfilereader.TypedTrailerValueT>[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateValuesVectorBlock(FlatBufferBuilder builder, ArraySegment<Offset<librawfilereader.TypedTrailerValueT>> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateValuesVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<Offset<librawfilereader.TypedTrailerValueT>>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartValuesVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.TuneDataSegmentT> EndTuneDataSegmentT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.TuneDataSegmentT>(o);
+  }
+}
+
+
+static public class TuneDataSegmentTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyField(tablePos, 4 /*Index*/, 4 /*int*/, 4, false)
+      && verifier.VerifyVectorOfTables(tablePos, 6 /*Values*/, librawfilereader.TypedTrailerValueTVerify.Verify, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
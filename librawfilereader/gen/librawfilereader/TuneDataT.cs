@@ -0,0 +1,56 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct TuneDataT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static TuneDataT GetRootAsTuneDataT(ByteBuffer _bb) { return GetRootAsTuneDataT(_bb, new TuneDataT()); }
+  public static TuneDataT GetRootAsTuneDataT(ByteBuffer _bb, TuneDataT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public TuneDataT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public librawfilereader.TuneDataSegmentT? Segments(int j) { int o = __p.__offset(4); return o != 0 ? (librawfilereader.TuneDataSegmentT?)(new librawfilereader.TuneDataSegmentT()).__assign(__p.__indirect(__p.__vector(o) + j * 4), __p.bb) : null; }
+  public int SegmentsLength { get { int o = __p.__offset(4); return o != 0 ? __p.__vector_len(o) : 0; } }
+
+  public static Offset<librawfilereader.TuneDataT> CreateTuneDataT(FlatBufferBuilder builder,
+      VectorOffset segmentsOffset = default(VectorOffset)) {
+    builder.StartTable(1);
+    TuneDataT.AddSegments(builder, segmentsOffset);
+    return TuneDataT.EndTuneDataT(builder);
+  }
+
+  public static void StartTuneDataT(FlatBufferBuilder builder) { builder.StartTable(1); }
+  public static void AddSegments(FlatBufferBuilder builder, VectorOffset segmentsOffset) { builder.AddOffset(0, segmentsOffset.Value, 0); }
+  public static VectorOffset CreateSegmentsVector(FlatBufferBuilder builder, Offset<librawfilereader.TuneDataSegmentT>[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateSegmentsVectorBlock(FlatBufferBuilder builder, Offset<librawfilereader.TuneDataSegmentT>[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSegmentsVectorBlock(FlatBufferBuilder builder, ArraySegment<Offset<librawfilereader.TuneDataSegmentT>> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateSegmentsVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<Offset<librawfilereader.TuneDataSegmentT>>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartSegmentsVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.TuneDataT> EndTuneDataT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.TuneDataT>(o);
+  }
+}
+
+
+static public class TuneDataTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyVectorOfTables(tablePos, 4 /*Segments*/, librawfilereader.TuneDataSegmentTVerify.Verify, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
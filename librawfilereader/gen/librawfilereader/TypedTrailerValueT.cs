@@ -0,0 +1,82 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct TypedTrailerValueT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static TypedTrailerValueT GetRootAsTypedTrailerValueT(ByteBuffer _bb) { return GetRootAsTypedTrailerValueT(_bb, new TypedTrailerValueT()); }
+  public static TypedTrailerValueT GetRootAsTypedTrailerValueT(ByteBuffer _bb, TypedTrailerValueT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public TypedTrailerValueT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public string Label { get { int o = __p.__offset(4); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetLabelBytes() { return __p.__vector_as_span<byte>(4, 1); }
+#else
+  public ArraySegment<byte>? GetLabelBytes() { return __p.__vector_as_arraysegment(4); }
+#endif
+  public byte[] GetLabelArray() { return __p.__vector_as_array<byte>(4); }
+  public librawfilereader.TrailerDataType DataType { get { int o = __p.__offset(6); return o != 0 ? (librawfilereader.TrailerDataType)__p.bb.Get(o + __p.bb_pos) : librawfilereader.TrailerDataType.Null; } }
+  public double FloatValue { get { int o = __p.__offset(8); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public long IntValue { get { int o = __p.__offset(10); return o != 0 ? __p.bb.GetLong(o + __p.bb_pos) : (long)0; } }
+  public string StringValue { get { int o = __p.__offset(12); return o != 0 ? __p.__string(o + __p.bb_pos) : null; } }
+#if ENABLE_SPAN_T
+  public Span<byte> GetStringValueBytes() { return __p.__vector_as_span<byte>(12, 1); }
+#else
+  public ArraySegment<byte>? GetStringValueBytes() { return __p.__vector_as_arraysegment(12); }
+#endif
+  public byte[] GetStringValueArray() { return __p.__vector_as_array<byte>(12); }
+
+  public static Offset<librawfilereader.TypedTrailerValueT> CreateTypedTrailerValueT(FlatBufferBuilder builder,
+      StringOffset labelOffset = default(StringOffset),
+      librawfilereader.TrailerDataType data_type = librawfilereader.TrailerDataType.Null,
+      double float_value = 0.0,
+      long int_value = 0,
+      StringOffset string_valueOffset = default(StringOffset)) {
+    builder.StartTable(5);
+    TypedTrailerValueT.AddIntValue(builder, int_value);
+    TypedTrailerValueT.AddFloatValue(builder, float_value);
+    TypedTrailerValueT.AddStringValue(builder, string_valueOffset);
+    TypedTrailerValueT.AddLabel(builder, labelOffset);
+    TypedTrailerValueT.AddDataType(builder, data_type);
+    return TypedTrailerValueT.EndTypedTrailerValueT(builder);
+  }
+
+  public static void StartTypedTrailerValueT(FlatBufferBuilder builder) { builder.StartTable(5); }
+  public static void AddLabel(FlatBufferBuilder builder, StringOffset labelOffset) { builder.AddOffset(0, labelOffset.Value, 0); }
+  public static void AddDataType(FlatBufferBuilder builder, librawfilereader.TrailerDataType dataType) { builder.AddByte(1, (byte)dataType, 0); }
+  public static void AddFloatValue(FlatBufferBuilder builder, double floatValue) { builder.AddDouble(2, floatValue, 0.0); }
+  public static void AddIntValue(FlatBufferBuilder builder, long intValue) { builder.AddLong(3, intValue, 0); }
+  public static void AddStringValue(FlatBufferBuilder builder, StringOffset stringValueOffset) { builder.AddOffset(4, stringValueOffset.Value, 0); }
+  public static Offset<librawfilereader.TypedTrailerValueT> EndTypedTrailerValueT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.TypedTrailerValueT>(o);
+  }
+}
+
+
+static public class TypedTrailerValueTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyString(tablePos, 4 /*Label*/, false)
+      && verifier.VerifyField(tablePos, 6 /*DataType*/, 1 /*librawfilereader.TrailerDataType*/, 1, false)
+      && verifier.VerifyField(tablePos, 8 /*FloatValue*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 10 /*IntValue*/, 8 /*long*/, 8, false)
+      && verifier.VerifyString(tablePos, 12 /*StringValue*/, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -0,0 +1,56 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct TypedTrailerValuesT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static TypedTrailerValuesT GetRootAsTypedTrailerValuesT(ByteBuffer _bb) { return GetRootAsTypedTrailerValuesT(_bb, new TypedTrailerValuesT()); }
+  public static TypedTrailerValuesT GetRootAsTypedTrailerValuesT(ByteBuffer _bb, TypedTrailerValuesT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public TypedTrailerValuesT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public librawfilereader.TypedTrailerValueT? Trailers(int j) { int o = __p.__offset(4); return o != 0 ? (librawfilereader.TypedTrailerValueT?)(new librawfilereader.TypedTrailerValueT()).__assign(__p.__indirect(__p.__vector(o) + j * 4), __p.bb) : null; }
+  public int TrailersLength { get { int o = __p.__offset(4); return o != 0 ? __p.__vector_len(o) : 0; } }
+
+  public static Offset<librawfilereader.TypedTrailerValuesT> CreateTypedTrailerValuesT(FlatBufferBuilder builder,
+      VectorOffset trailersOffset = default(VectorOffset)) {
+    builder.StartTable(1);
+    TypedTrailerValuesT.AddTrailers(builder, trailersOffset);
+    return TypedTrailerValuesT.EndTypedTrailerValuesT(builder);
+  }
+
+  public static void StartTypedTrailerValuesT(FlatBufferBuilder builder) { builder.StartTable(1); }
+  public static void AddTrailers(FlatBufferBuilder builder, VectorOffset trailersOffset) { builder.AddOffset(0, trailersOffset.Value, 0); }
+  public static VectorOffset CreateTrailersVector(FlatBufferBuilder builder, Offset<librawfilereader.TypedTrailerValueT>[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddOffset(data[i].Value); return builder.EndVector(); }
+  public static VectorOffset CreateTrailersVectorBlock(FlatBufferBuilder builder, Offset<librawfilereader.TypedTrailerValueT>[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateTrailersVectorBlock(FlatBufferBuilder builder, ArraySegment<Offset<librawfilereader.TypedTrailerValueT>> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateTrailersVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<Offset<librawfilereader.TypedTrailerValueT>>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartTrailersVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.TypedTrailerValuesT> EndTypedTrailerValuesT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.TypedTrailerValuesT>(o);
+  }
+}
+
+
+static public class TypedTrailerValuesTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyVectorOfTables(tablePos, 4 /*Trailers*/, librawfilereader.TypedTrailerValueTVerify.Verify, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
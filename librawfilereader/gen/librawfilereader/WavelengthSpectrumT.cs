@@ -0,0 +1,99 @@
+// <auto-generated>
+//  automatically generated by the FlatBuffers compiler, do not modify
+// </auto-generated>
+
+namespace librawfilereader
+{
+
+using global::System;
+using global::System.Collections.Generic;
+using global::Google.FlatBuffers;
+
+public struct WavelengthSpectrumT : IFlatbufferObject
+{
+  private Table __p;
+  public ByteBuffer ByteBuffer { get { return __p.bb; } }
+  public static void ValidateVersion() { FlatBufferConstants.FLATBUFFERS_25_2_10(); }
+  public static WavelengthSpectrumT GetRootAsWavelengthSpectrumT(ByteBuffer _bb) { return GetRootAsWavelengthSpectrumT(_bb, new WavelengthSpectrumT()); }
+  public static WavelengthSpectrumT GetRootAsWavelengthSpectrumT(ByteBuffer _bb, WavelengthSpectrumT obj) { return (obj.__assign(_bb.GetInt(_bb.Position) + _bb.Position, _bb)); }
+  public void __init(int _i, ByteBuffer _bb) { __p = new Table(_i, _bb); }
+  public WavelengthSpectrumT __assign(int _i, ByteBuffer _bb) { __init(_i, _bb); return this; }
+
+  public int Index { get { int o = __p.__offset(4); return o != 0 ? __p.bb.GetInt(o + __p.bb_pos) : (int)0; } }
+  public double Time { get { int o = __p.__offset(6); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double LowWavelength { get { int o = __p.__offset(8); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double HighWavelength { get { int o = __p.__offset(10); return o != 0 ? __p.bb.GetDouble(o + __p.bb_pos) : (double)0.0; } }
+  public double Wavelength(int j) { int o = __p.__offset(12); return o != 0 ? __p.bb.GetDouble(__p.__vector(o) + j * 8) : (double)0; }
+  public int WavelengthLength { get { int o = __p.__offset(12); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<double> GetWavelengthBytes() { return __p.__vector_as_span<double>(12, 8); }
+#else
+  public ArraySegment<byte>? GetWavelengthBytes() { return __p.__vector_as_arraysegment(12); }
+#endif
+  public double[] GetWavelengthArray() { return __p.__vector_as_array<double>(12); }
+  public float Absorbance(int j) { int o = __p.__offset(14); return o != 0 ? __p.bb.GetFloat(__p.__vector(o) + j * 4) : (float)0; }
+  public int AbsorbanceLength { get { int o = __p.__offset(14); return o != 0 ? __p.__vector_len(o) : 0; } }
+#if ENABLE_SPAN_T
+  public Span<float> GetAbsorbanceBytes() { return __p.__vector_as_span<float>(14, 4); }
+#else
+  public ArraySegment<byte>? GetAbsorbanceBytes() { return __p.__vector_as_arraysegment(14); }
+#endif
+  public float[] GetAbsorbanceArray() { return __p.__vector_as_array<float>(14); }
+
+  public static Offset<librawfilereader.WavelengthSpectrumT> CreateWavelengthSpectrumT(FlatBufferBuilder builder,
+      int index = 0,
+      double time = 0.0,
+      double low_wavelength = 0.0,
+      double high_wavelength = 0.0,
+      VectorOffset wavelengthOffset = default(VectorOffset),
+      VectorOffset absorbanceOffset = default(VectorOffset)) {
+    builder.StartTable(6);
+    WavelengthSpectrumT.AddHighWavelength(builder, high_wavelength);
+    WavelengthSpectrumT.AddLowWavelength(builder, low_wavelength);
+    WavelengthSpectrumT.AddTime(builder, time);
+    WavelengthSpectrumT.AddAbsorbance(builder, absorbanceOffset);
+    WavelengthSpectrumT.AddWavelength(builder, wavelengthOffset);
+    WavelengthSpectrumT.AddIndex(builder, index);
+    return WavelengthSpectrumT.EndWavelengthSpectrumT(builder);
+  }
+
+  public static void StartWavelengthSpectrumT(FlatBufferBuilder builder) { builder.StartTable(6); }
+  public static void AddIndex(FlatBufferBuilder builder, int index) { builder.AddInt(0, index, 0); }
+  public static void AddTime(FlatBufferBuilder builder, double time) { builder.AddDouble(1, time, 0.0); }
+  public static void AddLowWavelength(FlatBufferBuilder builder, double lowWavelength) { builder.AddDouble(2, lowWavelength, 0.0); }
+  public static void AddHighWavelength(FlatBufferBuilder builder, double highWavelength) { builder.AddDouble(3, highWavelength, 0.0); }
+  public static void AddWavelength(FlatBufferBuilder builder, VectorOffset wavelengthOffset) { builder.AddOffset(4, wavelengthOffset.Value, 0); }
+  public static VectorOffset CreateWavelengthVector(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); for (int i = data.Length - 1; i >= 0; i--) builder.AddDouble(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateWavelengthVectorBlock(FlatBufferBuilder builder, double[] data) { builder.StartVector(8, data.Length, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateWavelengthVectorBlock(FlatBufferBuilder builder, ArraySegment<double> data) { builder.StartVector(8, data.Count, 8); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateWavelengthVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<double>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartWavelengthVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(8, numElems, 8); }
+  public static void AddAbsorbance(FlatBufferBuilder builder, VectorOffset absorbanceOffset) { builder.AddOffset(5, absorbanceOffset.Value, 0); }
+  public static VectorOffset CreateAbsorbanceVector(FlatBufferBuilder builder, float[] data) { builder.StartVector(4, data.Length, 4); for (int i = data.Length - 1; i >= 0; i--) builder.AddFloat(data[i]); return builder.EndVector(); }
+  public static VectorOffset CreateAbsorbanceVectorBlock(FlatBufferBuilder builder, float[] data) { builder.StartVector(4, data.Length, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateAbsorbanceVectorBlock(FlatBufferBuilder builder, ArraySegment<float> data) { builder.StartVector(4, data.Count, 4); builder.Add(data); return builder.EndVector(); }
+  public static VectorOffset CreateAbsorbanceVectorBlock(FlatBufferBuilder builder, IntPtr dataPtr, int sizeInBytes) { builder.StartVector(1, sizeInBytes, 1); builder.Add<float>(dataPtr, sizeInBytes); return builder.EndVector(); }
+  public static void StartAbsorbanceVector(FlatBufferBuilder builder, int numElems) { builder.StartVector(4, numElems, 4); }
+  public static Offset<librawfilereader.WavelengthSpectrumT> EndWavelengthSpectrumT(FlatBufferBuilder builder) {
+    int o = builder.EndTable();
+    return new Offset<librawfilereader.WavelengthSpectrumT>(o);
+  }
+}
+
+
+static public class WavelengthSpectrumTVerify
+{
+  static public bool Verify(Google.FlatBuffers.Verifier verifier, uint tablePos)
+  {
+    return verifier.VerifyTableStart(tablePos)
+      && verifier.VerifyField(tablePos, 4 /*Index*/, 4 /*int*/, 4, false)
+      && verifier.VerifyField(tablePos, 6 /*Time*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 8 /*LowWavelength*/, 8 /*double*/, 8, false)
+      && verifier.VerifyField(tablePos, 10 /*HighWavelength*/, 8 /*double*/, 8, false)
+      && verifier.VerifyVectorOfData(tablePos, 12 /*Wavelength*/, 8 /*double*/, false)
+      && verifier.VerifyVectorOfData(tablePos, 14 /*Absorbance*/, 4 /*float*/, false)
+      && verifier.VerifyTableEnd(tablePos);
+  }
+}
+
+}
@@ -12,9 +12,8 @@ fn main() -> io::Result<()> {
 
     let count: usize = (0..handle.len())
         .into_par_iter()
-        .flat_map(|i| handle.get(i))
-        .map(|s| s.data().unwrap().mz().len())
-        .sum();
+        .map(|i| -> io::Result<usize> { Ok(handle.get(i)?.data().unwrap().mz().len()) })
+        .sum::<io::Result<usize>>()?;
 
     let end = time::Instant::now();
     let elapsed = (end - start).as_secs_f64();
@@ -8,7 +8,11 @@ fn main() -> io::Result<()> {
     let mut writer = io::BufWriter::new(fs::File::create("tests/data/small.mgf")?);
 
     handle.set_centroid_spectra(true);
-    for spectrum in handle.iter().filter(|s| s.ms_level() == 2) {
+    for spectrum in handle.iter() {
+        let spectrum = spectrum?;
+        if spectrum.ms_level() != 2 {
+            continue;
+        }
 
         let prec = spectrum.precursor().unwrap();
         let prec_mz = prec.mz();
@@ -12,7 +12,7 @@ pub fn main() -> io::Result<()> {
         path
     )?;
 
-    let instrument = handle.instrument_model();
+    let instrument = handle.instrument_model()?;
     instrument.model().map(|s| {
         println!("Instrument Model: {}", s);
     });
@@ -21,7 +21,7 @@ pub fn main() -> io::Result<()> {
         println!("Conf {i}: {c}")
     });
 
-    let file_descr = handle.file_description();
+    let file_descr = handle.file_description()?;
     if let Some(headers) = file_descr.trailer_headers() {
         println!("Trailer Names");
         headers.iter().for_each(|h| {
@@ -33,23 +33,30 @@ pub fn main() -> io::Result<()> {
     if target < 0 {
         handle.set_signal_loading(false);
         println!("Counting MSn spectra");
-        let ms2_count = handle.iter().filter(|b| b.ms_level() > 1).count();
+        let mut ms2_count = 0;
+        for b in handle.iter() {
+            if b?.ms_level() > 1 {
+                ms2_count += 1;
+            }
+        }
         println!("Found {ms2_count} MSn spectra");
         handle.set_signal_loading(true);
         handle.set_centroid_spectra(true);
-        let data_points: usize = handle.iter().map(|b| {
+        let mut data_points: usize = 0;
+        for b in handle.iter() {
+            let b = b?;
             let view = b.view();
             let data_view = view.data().unwrap();
-            data_view.mz().unwrap().len()
-        }).sum();
+            data_points += data_view.mz().unwrap().len();
+        }
         println!("Found {data_points} points");
     } else {
-        handle.describe(target as usize);
-        let dta = handle.get_extended_spectrum_data(target as usize, true).unwrap();
+        handle.describe(target as usize)?;
+        let dta = handle.get_extended_spectrum_data(target as usize, true)?;
         let noise = dta.noise();
         let charge = dta.charge();
         handle.set_centroid_spectra(true);
-        let spec = handle.get(target as usize).unwrap();
+        let spec = handle.get(target as usize)?;
         println!("{} peaks, {} noise points", spec.data().unwrap().len(), noise.as_ref().map(|x| x.len()).unwrap_or_default());
         // spec.data().unwrap().into_iter().for_each(|(mz, int)| {
         //     println!("{mz}\t{int}")
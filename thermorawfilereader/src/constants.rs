@@ -1,4 +1,5 @@
 use crate::r#gen::schema_generated::librawfilereader::TraceTypeT;
+use std::ops::{BitAnd, BitOr};
 
 /// This enum mirrors the different types of traces covered in Thermo's RawFileReader library.
 ///
@@ -270,4 +271,107 @@ impl From<u8> for MassAnalyzer {
             _ => Self::Unknown
         }
     }
-}
\ No newline at end of file
+}
+
+/// This enum mirrors the different kinds of devices that can record data in a RAW file
+/// in Thermo's RawFileReader library
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum DeviceKind {
+    None = -1,
+    MS = 0,
+    MSAnalog = 1,
+    Analog = 2,
+    PDA = 3,
+    UV = 4,
+    Other = 5,
+}
+
+impl From<i32> for DeviceKind {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => Self::MS,
+            1 => Self::MSAnalog,
+            2 => Self::Analog,
+            3 => Self::PDA,
+            4 => Self::UV,
+            5 => Self::Other,
+            _ => Self::None
+        }
+    }
+}
+
+/// The label flags Thermo's RawFileReader attaches to each centroid peak, packed into a bit set.
+///
+/// Multiple flags may be set on the same peak.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct PeakFlags(pub u8);
+
+// SAFETY: `PeakFlags` is a transparent wrapper around a `u8`, every bit pattern of which is valid
+unsafe impl bytemuck::Zeroable for PeakFlags {}
+unsafe impl bytemuck::Pod for PeakFlags {}
+
+impl PeakFlags {
+    pub const NONE: Self = Self(0);
+    pub const SATURATED: Self = Self(1);
+    pub const FRAGMENTED: Self = Self(2);
+    pub const MERGED: Self = Self(4);
+    pub const EXCEPTION: Self = Self(8);
+    pub const REFERENCE: Self = Self(16);
+    pub const MODIFIED: Self = Self(32);
+    pub const LOCK_PEAK: Self = Self(64);
+
+    /// Whether all of the bits in `other` are set
+    pub const fn contains(&self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The detector was saturated at this peak, so its intensity is unreliable
+    pub const fn is_saturated(&self) -> bool {
+        self.contains(Self::SATURATED)
+    }
+
+    pub const fn is_fragmented(&self) -> bool {
+        self.contains(Self::FRAGMENTED)
+    }
+
+    pub const fn is_merged(&self) -> bool {
+        self.contains(Self::MERGED)
+    }
+
+    /// The peak is a calibration exception or lock mass reference peak
+    pub const fn is_reference(&self) -> bool {
+        self.contains(Self::EXCEPTION) || self.contains(Self::REFERENCE) || self.contains(Self::LOCK_PEAK)
+    }
+
+    pub const fn is_modified(&self) -> bool {
+        self.contains(Self::MODIFIED)
+    }
+}
+
+impl From<u8> for PeakFlags {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl BitOr for PeakFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for PeakFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
@@ -1107,53 +1107,418 @@ impl<'a> flatbuffers::Verifiable for TraceTypeT {
 }
 
 impl flatbuffers::SimpleToVerifyInSlice for TraceTypeT {}
-// struct InstrumentConfigurationT, aligned to 1
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_SAMPLE_TYPE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_SAMPLE_TYPE: u8 = 12;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SAMPLE_TYPE: [SampleType; 13] = [
+  SampleType::Unknown,
+  SampleType::Blank,
+  SampleType::QC,
+  SampleType::StdClear,
+  SampleType::StdUpdate,
+  SampleType::StdBracket,
+  SampleType::StdBracketStart,
+  SampleType::StdBracketEnd,
+  SampleType::Program,
+  SampleType::SolventBlank,
+  SampleType::MatrixBlank,
+  SampleType::MatrixSpike,
+  SampleType::MatrixSpikeDuplicate,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SampleType(pub u8);
+#[allow(non_upper_case_globals)]
+impl SampleType {
+  pub const Unknown: Self = Self(0);
+  pub const Blank: Self = Self(1);
+  pub const QC: Self = Self(2);
+  pub const StdClear: Self = Self(3);
+  pub const StdUpdate: Self = Self(4);
+  pub const StdBracket: Self = Self(5);
+  pub const StdBracketStart: Self = Self(6);
+  pub const StdBracketEnd: Self = Self(7);
+  pub const Program: Self = Self(8);
+  pub const SolventBlank: Self = Self(9);
+  pub const MatrixBlank: Self = Self(10);
+  pub const MatrixSpike: Self = Self(11);
+  pub const MatrixSpikeDuplicate: Self = Self(12);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 12;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Unknown,
+    Self::Blank,
+    Self::QC,
+    Self::StdClear,
+    Self::StdUpdate,
+    Self::StdBracket,
+    Self::StdBracketStart,
+    Self::StdBracketEnd,
+    Self::Program,
+    Self::SolventBlank,
+    Self::MatrixBlank,
+    Self::MatrixSpike,
+    Self::MatrixSpikeDuplicate,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Unknown => Some("Unknown"),
+      Self::Blank => Some("Blank"),
+      Self::QC => Some("QC"),
+      Self::StdClear => Some("StdClear"),
+      Self::StdUpdate => Some("StdUpdate"),
+      Self::StdBracket => Some("StdBracket"),
+      Self::StdBracketStart => Some("StdBracketStart"),
+      Self::StdBracketEnd => Some("StdBracketEnd"),
+      Self::Program => Some("Program"),
+      Self::SolventBlank => Some("SolventBlank"),
+      Self::MatrixBlank => Some("MatrixBlank"),
+      Self::MatrixSpike => Some("MatrixSpike"),
+      Self::MatrixSpikeDuplicate => Some("MatrixSpikeDuplicate"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for SampleType {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for SampleType {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for SampleType {
+    type Output = SampleType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for SampleType {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for SampleType {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for SampleType {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_BARCODE_STATUS: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_BARCODE_STATUS: u8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_BARCODE_STATUS: [BarcodeStatus; 5] = [
+  BarcodeStatus::NotRead,
+  BarcodeStatus::Read,
+  BarcodeStatus::Unreadable,
+  BarcodeStatus::Error,
+  BarcodeStatus::Wait,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct BarcodeStatus(pub u8);
+#[allow(non_upper_case_globals)]
+impl BarcodeStatus {
+  pub const NotRead: Self = Self(0);
+  pub const Read: Self = Self(1);
+  pub const Unreadable: Self = Self(2);
+  pub const Error: Self = Self(3);
+  pub const Wait: Self = Self(4);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NotRead,
+    Self::Read,
+    Self::Unreadable,
+    Self::Error,
+    Self::Wait,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NotRead => Some("NotRead"),
+      Self::Read => Some("Read"),
+      Self::Unreadable => Some("Unreadable"),
+      Self::Error => Some("Error"),
+      Self::Wait => Some("Wait"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for BarcodeStatus {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for BarcodeStatus {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for BarcodeStatus {
+    type Output = BarcodeStatus;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for BarcodeStatus {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for BarcodeStatus {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for BarcodeStatus {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TRAILER_DATA_TYPE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TRAILER_DATA_TYPE: u8 = 13;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TRAILER_DATA_TYPE: [TrailerDataType; 14] = [
+  TrailerDataType::Null,
+  TrailerDataType::Char,
+  TrailerDataType::TrueFalse,
+  TrailerDataType::YesNo,
+  TrailerDataType::OnOff,
+  TrailerDataType::UChar,
+  TrailerDataType::Short,
+  TrailerDataType::UShort,
+  TrailerDataType::Long,
+  TrailerDataType::ULong,
+  TrailerDataType::Float,
+  TrailerDataType::Double,
+  TrailerDataType::CharString,
+  TrailerDataType::WCharString,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TrailerDataType(pub u8);
+#[allow(non_upper_case_globals)]
+impl TrailerDataType {
+  pub const Null: Self = Self(0);
+  pub const Char: Self = Self(1);
+  pub const TrueFalse: Self = Self(2);
+  pub const YesNo: Self = Self(3);
+  pub const OnOff: Self = Self(4);
+  pub const UChar: Self = Self(5);
+  pub const Short: Self = Self(6);
+  pub const UShort: Self = Self(7);
+  pub const Long: Self = Self(8);
+  pub const ULong: Self = Self(9);
+  pub const Float: Self = Self(10);
+  pub const Double: Self = Self(11);
+  pub const CharString: Self = Self(12);
+  pub const WCharString: Self = Self(13);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 13;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Null,
+    Self::Char,
+    Self::TrueFalse,
+    Self::YesNo,
+    Self::OnOff,
+    Self::UChar,
+    Self::Short,
+    Self::UShort,
+    Self::Long,
+    Self::ULong,
+    Self::Float,
+    Self::Double,
+    Self::CharString,
+    Self::WCharString,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Null => Some("Null"),
+      Self::Char => Some("Char"),
+      Self::TrueFalse => Some("TrueFalse"),
+      Self::YesNo => Some("YesNo"),
+      Self::OnOff => Some("OnOff"),
+      Self::UChar => Some("UChar"),
+      Self::Short => Some("Short"),
+      Self::UShort => Some("UShort"),
+      Self::Long => Some("Long"),
+      Self::ULong => Some("ULong"),
+      Self::Float => Some("Float"),
+      Self::Double => Some("Double"),
+      Self::CharString => Some("CharString"),
+      Self::WCharString => Some("WCharString"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for TrailerDataType {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for TrailerDataType {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for TrailerDataType {
+    type Output = TrailerDataType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for TrailerDataType {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for TrailerDataType {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TrailerDataType {}
+// struct RunHeaderT, aligned to 8
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct InstrumentConfigurationT(pub [u8; 2]);
-impl Default for InstrumentConfigurationT {
+pub struct RunHeaderT(pub [u8; 64]);
+impl Default for RunHeaderT {
   fn default() -> Self {
-    Self([0; 2])
+    Self([0; 64])
   }
 }
-impl core::fmt::Debug for InstrumentConfigurationT {
+impl core::fmt::Debug for RunHeaderT {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("InstrumentConfigurationT")
-      .field("mass_analyzer", &self.mass_analyzer())
-      .field("ionization_mode", &self.ionization_mode())
+    f.debug_struct("RunHeaderT")
+      .field("start_time", &self.start_time())
+      .field("end_time", &self.end_time())
+      .field("low_mass", &self.low_mass())
+      .field("high_mass", &self.high_mass())
+      .field("expected_run_time", &self.expected_run_time())
+      .field("mass_resolution", &self.mass_resolution())
+      .field("max_integrated_intensity", &self.max_integrated_intensity())
+      .field("max_intensity", &self.max_intensity())
       .finish()
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for InstrumentConfigurationT {}
-impl<'a> flatbuffers::Follow<'a> for InstrumentConfigurationT {
-  type Inner = &'a InstrumentConfigurationT;
+impl flatbuffers::SimpleToVerifyInSlice for RunHeaderT {}
+impl<'a> flatbuffers::Follow<'a> for RunHeaderT {
+  type Inner = &'a RunHeaderT;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a InstrumentConfigurationT>::follow(buf, loc)
+    <&'a RunHeaderT>::follow(buf, loc)
   }
 }
-impl<'a> flatbuffers::Follow<'a> for &'a InstrumentConfigurationT {
-  type Inner = &'a InstrumentConfigurationT;
+impl<'a> flatbuffers::Follow<'a> for &'a RunHeaderT {
+  type Inner = &'a RunHeaderT;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<InstrumentConfigurationT>(buf, loc)
+    flatbuffers::follow_cast_ref::<RunHeaderT>(buf, loc)
   }
 }
-impl<'b> flatbuffers::Push for InstrumentConfigurationT {
-    type Output = InstrumentConfigurationT;
+impl<'b> flatbuffers::Push for RunHeaderT {
+    type Output = RunHeaderT;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const InstrumentConfigurationT as *const u8, <Self as flatbuffers::Push>::size());
+        let src = ::core::slice::from_raw_parts(self as *const RunHeaderT as *const u8, <Self as flatbuffers::Push>::size());
         dst.copy_from_slice(src);
     }
     #[inline]
     fn alignment() -> flatbuffers::PushAlignment {
-        flatbuffers::PushAlignment::new(1)
+        flatbuffers::PushAlignment::new(8)
     }
 }
 
-impl<'a> flatbuffers::Verifiable for InstrumentConfigurationT {
+impl<'a> flatbuffers::Verifiable for RunHeaderT {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -1163,20 +1528,32 @@ impl<'a> flatbuffers::Verifiable for InstrumentConfigurationT {
   }
 }
 
-impl<'a> InstrumentConfigurationT {
+impl<'a> RunHeaderT {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
-    mass_analyzer: MassAnalyzer,
-    ionization_mode: IonizationMode,
+    start_time: f64,
+    end_time: f64,
+    low_mass: f64,
+    high_mass: f64,
+    expected_run_time: f64,
+    mass_resolution: f64,
+    max_integrated_intensity: f64,
+    max_intensity: i32,
   ) -> Self {
-    let mut s = Self([0; 2]);
-    s.set_mass_analyzer(mass_analyzer);
-    s.set_ionization_mode(ionization_mode);
+    let mut s = Self([0; 64]);
+    s.set_start_time(start_time);
+    s.set_end_time(end_time);
+    s.set_low_mass(low_mass);
+    s.set_high_mass(high_mass);
+    s.set_expected_run_time(expected_run_time);
+    s.set_mass_resolution(mass_resolution);
+    s.set_max_integrated_intensity(max_integrated_intensity);
+    s.set_max_intensity(max_intensity);
     s
   }
 
-  pub fn mass_analyzer(&self) -> MassAnalyzer {
-    let mut mem = core::mem::MaybeUninit::<<MassAnalyzer as EndianScalar>::Scalar>::uninit();
+  pub fn start_time(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
@@ -1184,13 +1561,13 @@ impl<'a> InstrumentConfigurationT {
       core::ptr::copy_nonoverlapping(
         self.0[0..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<MassAnalyzer as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_mass_analyzer(&mut self, x: MassAnalyzer) {
+  pub fn set_start_time(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1199,27 +1576,27 @@ impl<'a> InstrumentConfigurationT {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
         self.0[0..].as_mut_ptr(),
-        core::mem::size_of::<<MassAnalyzer as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn ionization_mode(&self) -> IonizationMode {
-    let mut mem = core::mem::MaybeUninit::<<IonizationMode as EndianScalar>::Scalar>::uninit();
+  pub fn end_time(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[1..].as_ptr(),
+        self.0[8..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<IonizationMode as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_ionization_mode(&mut self, x: IonizationMode) {
+  pub fn set_end_time(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1227,93 +1604,78 @@ impl<'a> InstrumentConfigurationT {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[1..].as_mut_ptr(),
-        core::mem::size_of::<<IonizationMode as EndianScalar>::Scalar>(),
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-}
-
-// struct IsolationWindowT, aligned to 8
-#[repr(transparent)]
-#[derive(Clone, Copy, PartialEq)]
-pub struct IsolationWindowT(pub [u8; 24]);
-impl Default for IsolationWindowT {
-  fn default() -> Self {
-    Self([0; 24])
-  }
-}
-impl core::fmt::Debug for IsolationWindowT {
-  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("IsolationWindowT")
-      .field("lower", &self.lower())
-      .field("target", &self.target())
-      .field("upper", &self.upper())
-      .finish()
+  pub fn low_mass(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
   }
-}
 
-impl flatbuffers::SimpleToVerifyInSlice for IsolationWindowT {}
-impl<'a> flatbuffers::Follow<'a> for IsolationWindowT {
-  type Inner = &'a IsolationWindowT;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a IsolationWindowT>::follow(buf, loc)
-  }
-}
-impl<'a> flatbuffers::Follow<'a> for &'a IsolationWindowT {
-  type Inner = &'a IsolationWindowT;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<IsolationWindowT>(buf, loc)
-  }
-}
-impl<'b> flatbuffers::Push for IsolationWindowT {
-    type Output = IsolationWindowT;
-    #[inline]
-    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const IsolationWindowT as *const u8, <Self as flatbuffers::Push>::size());
-        dst.copy_from_slice(src);
-    }
-    #[inline]
-    fn alignment() -> flatbuffers::PushAlignment {
-        flatbuffers::PushAlignment::new(8)
+  pub fn set_low_mass(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
     }
-}
+  }
 
-impl<'a> flatbuffers::Verifiable for IsolationWindowT {
-  #[inline]
-  fn run_verifier(
-    v: &mut flatbuffers::Verifier, pos: usize
-  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-    use self::flatbuffers::Verifiable;
-    v.in_buffer::<Self>(pos)
+  pub fn high_mass(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[24..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
   }
-}
 
-impl<'a> IsolationWindowT {
-  #[allow(clippy::too_many_arguments)]
-  pub fn new(
-    lower: f64,
-    target: f64,
-    upper: f64,
-  ) -> Self {
-    let mut s = Self([0; 24]);
-    s.set_lower(lower);
-    s.set_target(target);
-    s.set_upper(upper);
-    s
+  pub fn set_high_mass(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[24..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
   }
 
-  pub fn lower(&self) -> f64 {
+  pub fn expected_run_time(&self) -> f64 {
     let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[0..].as_ptr(),
+        self.0[32..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
@@ -1321,7 +1683,7 @@ impl<'a> IsolationWindowT {
     })
   }
 
-  pub fn set_lower(&mut self, x: f64) {
+  pub fn set_expected_run_time(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1329,20 +1691,20 @@ impl<'a> IsolationWindowT {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[0..].as_mut_ptr(),
+        self.0[32..].as_mut_ptr(),
         core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn target(&self) -> f64 {
+  pub fn mass_resolution(&self) -> f64 {
     let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[8..].as_ptr(),
+        self.0[40..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
@@ -1350,7 +1712,7 @@ impl<'a> IsolationWindowT {
     })
   }
 
-  pub fn set_target(&mut self, x: f64) {
+  pub fn set_mass_resolution(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1358,20 +1720,20 @@ impl<'a> IsolationWindowT {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[8..].as_mut_ptr(),
+        self.0[40..].as_mut_ptr(),
         core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn upper(&self) -> f64 {
+  pub fn max_integrated_intensity(&self) -> f64 {
     let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[16..].as_ptr(),
+        self.0[48..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
         core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
@@ -1379,7 +1741,7 @@ impl<'a> IsolationWindowT {
     })
   }
 
-  pub fn set_upper(&mut self, x: f64) {
+  pub fn set_max_integrated_intensity(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1387,61 +1749,90 @@ impl<'a> IsolationWindowT {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[16..].as_mut_ptr(),
+        self.0[48..].as_mut_ptr(),
         core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
     }
   }
 
+  pub fn max_intensity(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[56..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_max_intensity(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[56..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
 }
 
-// struct ActivationT, aligned to 8
+// struct InstrumentConfigurationT, aligned to 1
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct ActivationT(pub [u8; 16]);
-impl Default for ActivationT {
+pub struct InstrumentConfigurationT(pub [u8; 2]);
+impl Default for InstrumentConfigurationT {
   fn default() -> Self {
-    Self([0; 16])
+    Self([0; 2])
   }
 }
-impl core::fmt::Debug for ActivationT {
+impl core::fmt::Debug for InstrumentConfigurationT {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("ActivationT")
-      .field("dissociation_method", &self.dissociation_method())
-      .field("collision_energy", &self.collision_energy())
+    f.debug_struct("InstrumentConfigurationT")
+      .field("mass_analyzer", &self.mass_analyzer())
+      .field("ionization_mode", &self.ionization_mode())
       .finish()
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for ActivationT {}
-impl<'a> flatbuffers::Follow<'a> for ActivationT {
-  type Inner = &'a ActivationT;
+impl flatbuffers::SimpleToVerifyInSlice for InstrumentConfigurationT {}
+impl<'a> flatbuffers::Follow<'a> for InstrumentConfigurationT {
+  type Inner = &'a InstrumentConfigurationT;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a ActivationT>::follow(buf, loc)
+    <&'a InstrumentConfigurationT>::follow(buf, loc)
   }
 }
-impl<'a> flatbuffers::Follow<'a> for &'a ActivationT {
-  type Inner = &'a ActivationT;
+impl<'a> flatbuffers::Follow<'a> for &'a InstrumentConfigurationT {
+  type Inner = &'a InstrumentConfigurationT;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<ActivationT>(buf, loc)
+    flatbuffers::follow_cast_ref::<InstrumentConfigurationT>(buf, loc)
   }
 }
-impl<'b> flatbuffers::Push for ActivationT {
-    type Output = ActivationT;
+impl<'b> flatbuffers::Push for InstrumentConfigurationT {
+    type Output = InstrumentConfigurationT;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const ActivationT as *const u8, <Self as flatbuffers::Push>::size());
+        let src = ::core::slice::from_raw_parts(self as *const InstrumentConfigurationT as *const u8, <Self as flatbuffers::Push>::size());
         dst.copy_from_slice(src);
     }
     #[inline]
     fn alignment() -> flatbuffers::PushAlignment {
-        flatbuffers::PushAlignment::new(8)
+        flatbuffers::PushAlignment::new(1)
     }
 }
 
-impl<'a> flatbuffers::Verifiable for ActivationT {
+impl<'a> flatbuffers::Verifiable for InstrumentConfigurationT {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -1451,20 +1842,20 @@ impl<'a> flatbuffers::Verifiable for ActivationT {
   }
 }
 
-impl<'a> ActivationT {
+impl<'a> InstrumentConfigurationT {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
-    dissociation_method: DissociationMethod,
-    collision_energy: f64,
+    mass_analyzer: MassAnalyzer,
+    ionization_mode: IonizationMode,
   ) -> Self {
-    let mut s = Self([0; 16]);
-    s.set_dissociation_method(dissociation_method);
-    s.set_collision_energy(collision_energy);
+    let mut s = Self([0; 2]);
+    s.set_mass_analyzer(mass_analyzer);
+    s.set_ionization_mode(ionization_mode);
     s
   }
 
-  pub fn dissociation_method(&self) -> DissociationMethod {
-    let mut mem = core::mem::MaybeUninit::<<DissociationMethod as EndianScalar>::Scalar>::uninit();
+  pub fn mass_analyzer(&self) -> MassAnalyzer {
+    let mut mem = core::mem::MaybeUninit::<<MassAnalyzer as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
@@ -1472,13 +1863,13 @@ impl<'a> ActivationT {
       core::ptr::copy_nonoverlapping(
         self.0[0..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<MassAnalyzer as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_dissociation_method(&mut self, x: DissociationMethod) {
+  pub fn set_mass_analyzer(&mut self, x: MassAnalyzer) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1487,27 +1878,27 @@ impl<'a> ActivationT {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
         self.0[0..].as_mut_ptr(),
-        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<MassAnalyzer as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn collision_energy(&self) -> f64 {
-    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+  pub fn ionization_mode(&self) -> IonizationMode {
+    let mut mem = core::mem::MaybeUninit::<<IonizationMode as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[8..].as_ptr(),
+        self.0[1..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<IonizationMode as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_collision_energy(&mut self, x: f64) {
+  pub fn set_ionization_mode(&mut self, x: IonizationMode) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1515,56 +1906,53 @@ impl<'a> ActivationT {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[8..].as_mut_ptr(),
-        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+        self.0[1..].as_mut_ptr(),
+        core::mem::size_of::<<IonizationMode as EndianScalar>::Scalar>(),
       );
     }
   }
 
 }
 
-// struct PrecursorT, aligned to 8
+// struct IsolationWindowT, aligned to 8
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct PrecursorT(pub [u8; 64]);
-impl Default for PrecursorT {
+pub struct IsolationWindowT(pub [u8; 24]);
+impl Default for IsolationWindowT {
   fn default() -> Self {
-    Self([0; 64])
+    Self([0; 24])
   }
 }
-impl core::fmt::Debug for PrecursorT {
+impl core::fmt::Debug for IsolationWindowT {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    f.debug_struct("PrecursorT")
-      .field("mz", &self.mz())
-      .field("intensity", &self.intensity())
-      .field("charge", &self.charge())
-      .field("parent_index", &self.parent_index())
-      .field("isolation_window", &self.isolation_window())
-      .field("activation", &self.activation())
+    f.debug_struct("IsolationWindowT")
+      .field("lower", &self.lower())
+      .field("target", &self.target())
+      .field("upper", &self.upper())
       .finish()
   }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for PrecursorT {}
-impl<'a> flatbuffers::Follow<'a> for PrecursorT {
-  type Inner = &'a PrecursorT;
+impl flatbuffers::SimpleToVerifyInSlice for IsolationWindowT {}
+impl<'a> flatbuffers::Follow<'a> for IsolationWindowT {
+  type Inner = &'a IsolationWindowT;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    <&'a PrecursorT>::follow(buf, loc)
+    <&'a IsolationWindowT>::follow(buf, loc)
   }
 }
-impl<'a> flatbuffers::Follow<'a> for &'a PrecursorT {
-  type Inner = &'a PrecursorT;
+impl<'a> flatbuffers::Follow<'a> for &'a IsolationWindowT {
+  type Inner = &'a IsolationWindowT;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    flatbuffers::follow_cast_ref::<PrecursorT>(buf, loc)
+    flatbuffers::follow_cast_ref::<IsolationWindowT>(buf, loc)
   }
 }
-impl<'b> flatbuffers::Push for PrecursorT {
-    type Output = PrecursorT;
+impl<'b> flatbuffers::Push for IsolationWindowT {
+    type Output = IsolationWindowT;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        let src = ::core::slice::from_raw_parts(self as *const PrecursorT as *const u8, <Self as flatbuffers::Push>::size());
+        let src = ::core::slice::from_raw_parts(self as *const IsolationWindowT as *const u8, <Self as flatbuffers::Push>::size());
         dst.copy_from_slice(src);
     }
     #[inline]
@@ -1573,7 +1961,7 @@ impl<'b> flatbuffers::Push for PrecursorT {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for PrecursorT {
+impl<'a> flatbuffers::Verifiable for IsolationWindowT {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -1583,27 +1971,21 @@ impl<'a> flatbuffers::Verifiable for PrecursorT {
   }
 }
 
-impl<'a> PrecursorT {
+impl<'a> IsolationWindowT {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
-    mz: f64,
-    intensity: f32,
-    charge: i32,
-    parent_index: i32,
-    isolation_window: &IsolationWindowT,
-    activation: &ActivationT,
+    lower: f64,
+    target: f64,
+    upper: f64,
   ) -> Self {
-    let mut s = Self([0; 64]);
-    s.set_mz(mz);
-    s.set_intensity(intensity);
-    s.set_charge(charge);
-    s.set_parent_index(parent_index);
-    s.set_isolation_window(isolation_window);
-    s.set_activation(activation);
+    let mut s = Self([0; 24]);
+    s.set_lower(lower);
+    s.set_target(target);
+    s.set_upper(upper);
     s
   }
 
-  pub fn mz(&self) -> f64 {
+  pub fn lower(&self) -> f64 {
     let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
@@ -1618,7 +2000,7 @@ impl<'a> PrecursorT {
     })
   }
 
-  pub fn set_mz(&mut self, x: f64) {
+  pub fn set_lower(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1632,8 +2014,8 @@ impl<'a> PrecursorT {
     }
   }
 
-  pub fn intensity(&self) -> f32 {
-    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+  pub fn target(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
@@ -1641,13 +2023,13 @@ impl<'a> PrecursorT {
       core::ptr::copy_nonoverlapping(
         self.0[8..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_intensity(&mut self, x: f32) {
+  pub fn set_target(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1656,27 +2038,27 @@ impl<'a> PrecursorT {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
         self.0[8..].as_mut_ptr(),
-        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn charge(&self) -> i32 {
-    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+  pub fn upper(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[12..].as_ptr(),
+        self.0[16..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_charge(&mut self, x: i32) {
+  pub fn set_upper(&mut self, x: f64) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1684,28 +2066,98 @@ impl<'a> PrecursorT {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[12..].as_mut_ptr(),
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn parent_index(&self) -> i32 {
-    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+}
+
+// struct ActivationT, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ActivationT(pub [u8; 16]);
+impl Default for ActivationT {
+  fn default() -> Self {
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for ActivationT {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("ActivationT")
+      .field("dissociation_method", &self.dissociation_method())
+      .field("collision_energy", &self.collision_energy())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ActivationT {}
+impl<'a> flatbuffers::Follow<'a> for ActivationT {
+  type Inner = &'a ActivationT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a ActivationT>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a ActivationT {
+  type Inner = &'a ActivationT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<ActivationT>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for ActivationT {
+    type Output = ActivationT;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const ActivationT as *const u8, <Self as flatbuffers::Push>::size());
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ActivationT {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> ActivationT {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    dissociation_method: DissociationMethod,
+    collision_energy: f64,
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_dissociation_method(dissociation_method);
+    s.set_collision_energy(collision_energy);
+    s
+  }
+
+  pub fn dissociation_method(&self) -> DissociationMethod {
+    let mut mem = core::mem::MaybeUninit::<<DissociationMethod as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
     // Which contains a valid value in this slot
     EndianScalar::from_little_endian(unsafe {
       core::ptr::copy_nonoverlapping(
-        self.0[16..].as_ptr(),
+        self.0[0..].as_ptr(),
         mem.as_mut_ptr() as *mut u8,
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
       );
       mem.assume_init()
     })
   }
 
-  pub fn set_parent_index(&mut self, x: i32) {
+  pub fn set_dissociation_method(&mut self, x: DissociationMethod) {
     let x_le = x.to_little_endian();
     // Safety:
     // Created from a valid Table for this object
@@ -1713,2010 +2165,5484 @@ impl<'a> PrecursorT {
     unsafe {
       core::ptr::copy_nonoverlapping(
         &x_le as *const _ as *const u8,
-        self.0[16..].as_mut_ptr(),
-        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
       );
     }
   }
 
-  pub fn isolation_window(&self) -> &IsolationWindowT {
+  pub fn collision_energy(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
-    // Which contains a valid struct in this slot
-    unsafe { &*(self.0[24..].as_ptr() as *const IsolationWindowT) }
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
   }
 
-  #[allow(clippy::identity_op)]
-  pub fn set_isolation_window(&mut self, x: &IsolationWindowT) {
-    self.0[24..24 + 24].copy_from_slice(&x.0)
+  pub fn set_collision_energy(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
   }
 
-  pub fn activation(&self) -> &ActivationT {
+}
+
+// struct ActivationStepT, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ActivationStepT(pub [u8; 24]);
+impl Default for ActivationStepT {
+  fn default() -> Self {
+    Self([0; 24])
+  }
+}
+impl core::fmt::Debug for ActivationStepT {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("ActivationStepT")
+      .field("dissociation_method", &self.dissociation_method())
+      .field("collision_energy", &self.collision_energy())
+      .field("supplemental", &self.supplemental())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ActivationStepT {}
+impl<'a> flatbuffers::Follow<'a> for ActivationStepT {
+  type Inner = &'a ActivationStepT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a ActivationStepT>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a ActivationStepT {
+  type Inner = &'a ActivationStepT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<ActivationStepT>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for ActivationStepT {
+    type Output = ActivationStepT;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const ActivationStepT as *const u8, <Self as flatbuffers::Push>::size());
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ActivationStepT {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> ActivationStepT {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    dissociation_method: DissociationMethod,
+    collision_energy: f64,
+    supplemental: bool,
+  ) -> Self {
+    let mut s = Self([0; 24]);
+    s.set_dissociation_method(dissociation_method);
+    s.set_collision_energy(collision_energy);
+    s.set_supplemental(supplemental);
+    s
+  }
+
+  pub fn dissociation_method(&self) -> DissociationMethod {
+    let mut mem = core::mem::MaybeUninit::<<DissociationMethod as EndianScalar>::Scalar>::uninit();
     // Safety:
     // Created from a valid Table for this object
-    // Which contains a valid struct in this slot
-    unsafe { &*(self.0[48..].as_ptr() as *const ActivationT) }
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_dissociation_method(&mut self, x: DissociationMethod) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn collision_energy(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_collision_energy(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn supplemental(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_supplemental(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct MassRangeT, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct MassRangeT(pub [u8; 16]);
+impl Default for MassRangeT {
+  fn default() -> Self {
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for MassRangeT {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("MassRangeT")
+      .field("low", &self.low())
+      .field("high", &self.high())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for MassRangeT {}
+impl<'a> flatbuffers::Follow<'a> for MassRangeT {
+  type Inner = &'a MassRangeT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a MassRangeT>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a MassRangeT {
+  type Inner = &'a MassRangeT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<MassRangeT>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for MassRangeT {
+    type Output = MassRangeT;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const MassRangeT as *const u8, <Self as flatbuffers::Push>::size());
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for MassRangeT {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> MassRangeT {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    low: f64,
+    high: f64,
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_low(low);
+    s.set_high(high);
+    s
+  }
+
+  pub fn low(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_low(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn high(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_high(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct ScanFilterReactionT, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ScanFilterReactionT(pub [u8; 32]);
+impl Default for ScanFilterReactionT {
+  fn default() -> Self {
+    Self([0; 32])
+  }
+}
+impl core::fmt::Debug for ScanFilterReactionT {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("ScanFilterReactionT")
+      .field("precursor_mz", &self.precursor_mz())
+      .field("isolation_width", &self.isolation_width())
+      .field("collision_energy", &self.collision_energy())
+      .field("dissociation_method", &self.dissociation_method())
+      .field("multiple_activation", &self.multiple_activation())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ScanFilterReactionT {}
+impl<'a> flatbuffers::Follow<'a> for ScanFilterReactionT {
+  type Inner = &'a ScanFilterReactionT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a ScanFilterReactionT>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a ScanFilterReactionT {
+  type Inner = &'a ScanFilterReactionT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<ScanFilterReactionT>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for ScanFilterReactionT {
+    type Output = ScanFilterReactionT;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const ScanFilterReactionT as *const u8, <Self as flatbuffers::Push>::size());
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ScanFilterReactionT {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> ScanFilterReactionT {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    precursor_mz: f64,
+    isolation_width: f64,
+    collision_energy: f64,
+    dissociation_method: DissociationMethod,
+    multiple_activation: bool,
+  ) -> Self {
+    let mut s = Self([0; 32]);
+    s.set_precursor_mz(precursor_mz);
+    s.set_isolation_width(isolation_width);
+    s.set_collision_energy(collision_energy);
+    s.set_dissociation_method(dissociation_method);
+    s.set_multiple_activation(multiple_activation);
+    s
+  }
+
+  pub fn precursor_mz(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_precursor_mz(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn isolation_width(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_isolation_width(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn collision_energy(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_collision_energy(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn dissociation_method(&self) -> DissociationMethod {
+    let mut mem = core::mem::MaybeUninit::<<DissociationMethod as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[24..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_dissociation_method(&mut self, x: DissociationMethod) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[24..].as_mut_ptr(),
+        core::mem::size_of::<<DissociationMethod as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn multiple_activation(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[25..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_multiple_activation(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[25..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct PrecursorT, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PrecursorT(pub [u8; 64]);
+impl Default for PrecursorT {
+  fn default() -> Self {
+    Self([0; 64])
+  }
+}
+impl core::fmt::Debug for PrecursorT {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("PrecursorT")
+      .field("mz", &self.mz())
+      .field("intensity", &self.intensity())
+      .field("charge", &self.charge())
+      .field("parent_index", &self.parent_index())
+      .field("isolation_window", &self.isolation_window())
+      .field("activation", &self.activation())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PrecursorT {}
+impl<'a> flatbuffers::Follow<'a> for PrecursorT {
+  type Inner = &'a PrecursorT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a PrecursorT>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a PrecursorT {
+  type Inner = &'a PrecursorT;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<PrecursorT>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for PrecursorT {
+    type Output = PrecursorT;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const PrecursorT as *const u8, <Self as flatbuffers::Push>::size());
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for PrecursorT {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> PrecursorT {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    mz: f64,
+    intensity: f32,
+    charge: i32,
+    parent_index: i32,
+    isolation_window: &IsolationWindowT,
+    activation: &ActivationT,
+  ) -> Self {
+    let mut s = Self([0; 64]);
+    s.set_mz(mz);
+    s.set_intensity(intensity);
+    s.set_charge(charge);
+    s.set_parent_index(parent_index);
+    s.set_isolation_window(isolation_window);
+    s.set_activation(activation);
+    s
+  }
+
+  pub fn mz(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_mz(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn intensity(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_intensity(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn charge(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[12..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_charge(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[12..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn parent_index(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_parent_index(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn isolation_window(&self) -> &IsolationWindowT {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid struct in this slot
+    unsafe { &*(self.0[24..].as_ptr() as *const IsolationWindowT) }
+  }
+
+  #[allow(clippy::identity_op)]
+  pub fn set_isolation_window(&mut self, x: &IsolationWindowT) {
+    self.0[24..24 + 24].copy_from_slice(&x.0)
+  }
+
+  pub fn activation(&self) -> &ActivationT {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid struct in this slot
+    unsafe { &*(self.0[48..].as_ptr() as *const ActivationT) }
+  }
+
+  #[allow(clippy::identity_op)]
+  pub fn set_activation(&mut self, x: &ActivationT) {
+    self.0[48..48 + 16].copy_from_slice(&x.0)
+  }
+
+}
+
+pub enum FileDescriptionTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FileDescriptionT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FileDescriptionT<'a> {
+  type Inner = FileDescriptionT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> FileDescriptionT<'a> {
+  pub const VT_CREATION_DATE: flatbuffers::VOffsetT = 4;
+  pub const VT_SAMPLE_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_SOURCE_FILE: flatbuffers::VOffsetT = 8;
+  pub const VT_SPECTRA_PER_MS_LEVEL: flatbuffers::VOffsetT = 10;
+  pub const VT_TRAILER_HEADERS: flatbuffers::VOffsetT = 12;
+  pub const VT_SAMPLE_NAME: flatbuffers::VOffsetT = 14;
+  pub const VT_SAMPLE_VIAL: flatbuffers::VOffsetT = 16;
+  pub const VT_SAMPLE_COMMENT: flatbuffers::VOffsetT = 18;
+  pub const VT_CREATION_TIMESTAMP: flatbuffers::VOffsetT = 20;
+  pub const VT_MODIFICATION_TIMESTAMP: flatbuffers::VOffsetT = 22;
+  pub const VT_DESCRIPTION: flatbuffers::VOffsetT = 24;
+  pub const VT_CREATOR_ID: flatbuffers::VOffsetT = 26;
+  pub const VT_CREATOR_LOGON: flatbuffers::VOffsetT = 28;
+  pub const VT_MODIFIER_ID: flatbuffers::VOffsetT = 30;
+  pub const VT_MODIFIER_LOGON: flatbuffers::VOffsetT = 32;
+  pub const VT_REVISION: flatbuffers::VOffsetT = 34;
+  pub const VT_TIMES_CALIBRATED: flatbuffers::VOffsetT = 36;
+  pub const VT_TIMES_MODIFIED: flatbuffers::VOffsetT = 38;
+  pub const VT_RUN_HEADER: flatbuffers::VOffsetT = 40;
+  pub const VT_SAMPLE_TYPE: flatbuffers::VOffsetT = 42;
+  pub const VT_SAMPLE_BARCODE: flatbuffers::VOffsetT = 44;
+  pub const VT_SAMPLE_BARCODE_STATUS: flatbuffers::VOffsetT = 46;
+  pub const VT_INJECTION_VOLUME: flatbuffers::VOffsetT = 48;
+  pub const VT_DILUTION_FACTOR: flatbuffers::VOffsetT = 50;
+  pub const VT_SAMPLE_WEIGHT: flatbuffers::VOffsetT = 52;
+  pub const VT_SAMPLE_VOLUME: flatbuffers::VOffsetT = 54;
+  pub const VT_ISTD_AMOUNT: flatbuffers::VOffsetT = 56;
+  pub const VT_CALIBRATION_LEVEL: flatbuffers::VOffsetT = 58;
+  pub const VT_ROW_NUMBER: flatbuffers::VOffsetT = 60;
+  pub const VT_INSTRUMENT_METHOD_FILE: flatbuffers::VOffsetT = 62;
+  pub const VT_PROCESSING_METHOD_FILE: flatbuffers::VOffsetT = 64;
+  pub const VT_CALIBRATION_FILE: flatbuffers::VOffsetT = 66;
+  pub const VT_RAW_FILE_PATH: flatbuffers::VOffsetT = 68;
+  pub const VT_SAMPLE_USER_TEXT: flatbuffers::VOffsetT = 70;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FileDescriptionT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args FileDescriptionTArgs<'args>
+  ) -> flatbuffers::WIPOffset<FileDescriptionT<'bldr>> {
+    let mut builder = FileDescriptionTBuilder::new(_fbb);
+    builder.add_istd_amount(args.istd_amount);
+    builder.add_sample_volume(args.sample_volume);
+    builder.add_sample_weight(args.sample_weight);
+    builder.add_dilution_factor(args.dilution_factor);
+    builder.add_injection_volume(args.injection_volume);
+    builder.add_modification_timestamp(args.modification_timestamp);
+    builder.add_creation_timestamp(args.creation_timestamp);
+    if let Some(x) = args.sample_user_text { builder.add_sample_user_text(x); }
+    if let Some(x) = args.raw_file_path { builder.add_raw_file_path(x); }
+    if let Some(x) = args.calibration_file { builder.add_calibration_file(x); }
+    if let Some(x) = args.processing_method_file { builder.add_processing_method_file(x); }
+    if let Some(x) = args.instrument_method_file { builder.add_instrument_method_file(x); }
+    builder.add_row_number(args.row_number);
+    if let Some(x) = args.calibration_level { builder.add_calibration_level(x); }
+    if let Some(x) = args.sample_barcode { builder.add_sample_barcode(x); }
+    if let Some(x) = args.run_header { builder.add_run_header(x); }
+    builder.add_times_modified(args.times_modified);
+    builder.add_times_calibrated(args.times_calibrated);
+    builder.add_revision(args.revision);
+    if let Some(x) = args.modifier_logon { builder.add_modifier_logon(x); }
+    if let Some(x) = args.modifier_id { builder.add_modifier_id(x); }
+    if let Some(x) = args.creator_logon { builder.add_creator_logon(x); }
+    if let Some(x) = args.creator_id { builder.add_creator_id(x); }
+    if let Some(x) = args.description { builder.add_description(x); }
+    if let Some(x) = args.sample_comment { builder.add_sample_comment(x); }
+    if let Some(x) = args.sample_vial { builder.add_sample_vial(x); }
+    if let Some(x) = args.sample_name { builder.add_sample_name(x); }
+    if let Some(x) = args.trailer_headers { builder.add_trailer_headers(x); }
+    if let Some(x) = args.spectra_per_ms_level { builder.add_spectra_per_ms_level(x); }
+    if let Some(x) = args.source_file { builder.add_source_file(x); }
+    if let Some(x) = args.sample_id { builder.add_sample_id(x); }
+    if let Some(x) = args.creation_date { builder.add_creation_date(x); }
+    builder.add_sample_barcode_status(args.sample_barcode_status);
+    builder.add_sample_type(args.sample_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn creation_date(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_CREATION_DATE, None)}
+  }
+  #[inline]
+  pub fn sample_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_ID, None)}
+  }
+  #[inline]
+  pub fn source_file(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SOURCE_FILE, None)}
+  }
+  #[inline]
+  pub fn spectra_per_ms_level(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(FileDescriptionT::VT_SPECTRA_PER_MS_LEVEL, None)}
+  }
+  #[inline]
+  pub fn trailer_headers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(FileDescriptionT::VT_TRAILER_HEADERS, None)}
+  }
+  #[inline]
+  pub fn sample_name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_NAME, None)}
+  }
+  #[inline]
+  pub fn sample_vial(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_VIAL, None)}
+  }
+  #[inline]
+  pub fn sample_comment(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_COMMENT, None)}
+  }
+  #[inline]
+  pub fn creation_timestamp(&self) -> i64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i64>(FileDescriptionT::VT_CREATION_TIMESTAMP, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn modification_timestamp(&self) -> i64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i64>(FileDescriptionT::VT_MODIFICATION_TIMESTAMP, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn description(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_DESCRIPTION, None)}
+  }
+  #[inline]
+  pub fn creator_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_CREATOR_ID, None)}
+  }
+  #[inline]
+  pub fn creator_logon(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_CREATOR_LOGON, None)}
+  }
+  #[inline]
+  pub fn modifier_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_MODIFIER_ID, None)}
+  }
+  #[inline]
+  pub fn modifier_logon(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_MODIFIER_LOGON, None)}
+  }
+  #[inline]
+  pub fn revision(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(FileDescriptionT::VT_REVISION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn times_calibrated(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(FileDescriptionT::VT_TIMES_CALIBRATED, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn times_modified(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(FileDescriptionT::VT_TIMES_MODIFIED, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn run_header(&self) -> Option<&'a RunHeaderT> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<RunHeaderT>(FileDescriptionT::VT_RUN_HEADER, None)}
+  }
+  #[inline]
+  pub fn sample_type(&self) -> SampleType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<SampleType>(FileDescriptionT::VT_SAMPLE_TYPE, Some(SampleType::Unknown)).unwrap()}
+  }
+  #[inline]
+  pub fn sample_barcode(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_BARCODE, None)}
+  }
+  #[inline]
+  pub fn sample_barcode_status(&self) -> BarcodeStatus {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<BarcodeStatus>(FileDescriptionT::VT_SAMPLE_BARCODE_STATUS, Some(BarcodeStatus::NotRead)).unwrap()}
+  }
+  #[inline]
+  pub fn injection_volume(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(FileDescriptionT::VT_INJECTION_VOLUME, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn dilution_factor(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(FileDescriptionT::VT_DILUTION_FACTOR, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn sample_weight(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(FileDescriptionT::VT_SAMPLE_WEIGHT, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn sample_volume(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(FileDescriptionT::VT_SAMPLE_VOLUME, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn istd_amount(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(FileDescriptionT::VT_ISTD_AMOUNT, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn calibration_level(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_CALIBRATION_LEVEL, None)}
+  }
+  #[inline]
+  pub fn row_number(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(FileDescriptionT::VT_ROW_NUMBER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn instrument_method_file(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_INSTRUMENT_METHOD_FILE, None)}
+  }
+  #[inline]
+  pub fn processing_method_file(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_PROCESSING_METHOD_FILE, None)}
+  }
+  #[inline]
+  pub fn calibration_file(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_CALIBRATION_FILE, None)}
+  }
+  #[inline]
+  pub fn raw_file_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_RAW_FILE_PATH, None)}
+  }
+  #[inline]
+  pub fn sample_user_text(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(FileDescriptionT::VT_SAMPLE_USER_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for FileDescriptionT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("creation_date", Self::VT_CREATION_DATE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_id", Self::VT_SAMPLE_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("source_file", Self::VT_SOURCE_FILE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("spectra_per_ms_level", Self::VT_SPECTRA_PER_MS_LEVEL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("trailer_headers", Self::VT_TRAILER_HEADERS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_name", Self::VT_SAMPLE_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_vial", Self::VT_SAMPLE_VIAL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_comment", Self::VT_SAMPLE_COMMENT, false)?
+     .visit_field::<i64>("creation_timestamp", Self::VT_CREATION_TIMESTAMP, false)?
+     .visit_field::<i64>("modification_timestamp", Self::VT_MODIFICATION_TIMESTAMP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("description", Self::VT_DESCRIPTION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("creator_id", Self::VT_CREATOR_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("creator_logon", Self::VT_CREATOR_LOGON, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("modifier_id", Self::VT_MODIFIER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("modifier_logon", Self::VT_MODIFIER_LOGON, false)?
+     .visit_field::<i32>("revision", Self::VT_REVISION, false)?
+     .visit_field::<i32>("times_calibrated", Self::VT_TIMES_CALIBRATED, false)?
+     .visit_field::<i32>("times_modified", Self::VT_TIMES_MODIFIED, false)?
+     .visit_field::<RunHeaderT>("run_header", Self::VT_RUN_HEADER, false)?
+     .visit_field::<SampleType>("sample_type", Self::VT_SAMPLE_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_barcode", Self::VT_SAMPLE_BARCODE, false)?
+     .visit_field::<BarcodeStatus>("sample_barcode_status", Self::VT_SAMPLE_BARCODE_STATUS, false)?
+     .visit_field::<f64>("injection_volume", Self::VT_INJECTION_VOLUME, false)?
+     .visit_field::<f64>("dilution_factor", Self::VT_DILUTION_FACTOR, false)?
+     .visit_field::<f64>("sample_weight", Self::VT_SAMPLE_WEIGHT, false)?
+     .visit_field::<f64>("sample_volume", Self::VT_SAMPLE_VOLUME, false)?
+     .visit_field::<f64>("istd_amount", Self::VT_ISTD_AMOUNT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("calibration_level", Self::VT_CALIBRATION_LEVEL, false)?
+     .visit_field::<i32>("row_number", Self::VT_ROW_NUMBER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("instrument_method_file", Self::VT_INSTRUMENT_METHOD_FILE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("processing_method_file", Self::VT_PROCESSING_METHOD_FILE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("calibration_file", Self::VT_CALIBRATION_FILE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("raw_file_path", Self::VT_RAW_FILE_PATH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("sample_user_text", Self::VT_SAMPLE_USER_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FileDescriptionTArgs<'a> {
+    pub creation_date: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub sample_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub source_file: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub spectra_per_ms_level: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub trailer_headers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub sample_name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub sample_vial: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub sample_comment: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub creation_timestamp: i64,
+    pub modification_timestamp: i64,
+    pub description: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub creator_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub creator_logon: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub modifier_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub modifier_logon: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub revision: i32,
+    pub times_calibrated: i32,
+    pub times_modified: i32,
+    pub run_header: Option<&'a RunHeaderT>,
+    pub sample_type: SampleType,
+    pub sample_barcode: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub sample_barcode_status: BarcodeStatus,
+    pub injection_volume: f64,
+    pub dilution_factor: f64,
+    pub sample_weight: f64,
+    pub sample_volume: f64,
+    pub istd_amount: f64,
+    pub calibration_level: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub row_number: i32,
+    pub instrument_method_file: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub processing_method_file: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub calibration_file: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub raw_file_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub sample_user_text: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for FileDescriptionTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FileDescriptionTArgs {
+      creation_date: None,
+      sample_id: None,
+      source_file: None,
+      spectra_per_ms_level: None,
+      trailer_headers: None,
+      sample_name: None,
+      sample_vial: None,
+      sample_comment: None,
+      creation_timestamp: 0,
+      modification_timestamp: 0,
+      description: None,
+      creator_id: None,
+      creator_logon: None,
+      modifier_id: None,
+      modifier_logon: None,
+      revision: 0,
+      times_calibrated: 0,
+      times_modified: 0,
+      run_header: None,
+      sample_type: SampleType::Unknown,
+      sample_barcode: None,
+      sample_barcode_status: BarcodeStatus::NotRead,
+      injection_volume: 0.0,
+      dilution_factor: 0.0,
+      sample_weight: 0.0,
+      sample_volume: 0.0,
+      istd_amount: 0.0,
+      calibration_level: None,
+      row_number: 0,
+      instrument_method_file: None,
+      processing_method_file: None,
+      calibration_file: None,
+      raw_file_path: None,
+      sample_user_text: None,
+    }
+  }
+}
+
+pub struct FileDescriptionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FileDescriptionTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_creation_date(&mut self, creation_date: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_CREATION_DATE, creation_date);
+  }
+  #[inline]
+  pub fn add_sample_id(&mut self, sample_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_ID, sample_id);
+  }
+  #[inline]
+  pub fn add_source_file(&mut self, source_file: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SOURCE_FILE, source_file);
+  }
+  #[inline]
+  pub fn add_spectra_per_ms_level(&mut self, spectra_per_ms_level: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SPECTRA_PER_MS_LEVEL, spectra_per_ms_level);
+  }
+  #[inline]
+  pub fn add_trailer_headers(&mut self, trailer_headers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_TRAILER_HEADERS, trailer_headers);
+  }
+  #[inline]
+  pub fn add_sample_name(&mut self, sample_name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_NAME, sample_name);
+  }
+  #[inline]
+  pub fn add_sample_vial(&mut self, sample_vial: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_VIAL, sample_vial);
+  }
+  #[inline]
+  pub fn add_sample_comment(&mut self, sample_comment: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_COMMENT, sample_comment);
+  }
+  #[inline]
+  pub fn add_creation_timestamp(&mut self, creation_timestamp: i64) {
+    self.fbb_.push_slot::<i64>(FileDescriptionT::VT_CREATION_TIMESTAMP, creation_timestamp, 0);
+  }
+  #[inline]
+  pub fn add_modification_timestamp(&mut self, modification_timestamp: i64) {
+    self.fbb_.push_slot::<i64>(FileDescriptionT::VT_MODIFICATION_TIMESTAMP, modification_timestamp, 0);
+  }
+  #[inline]
+  pub fn add_description(&mut self, description: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_DESCRIPTION, description);
+  }
+  #[inline]
+  pub fn add_creator_id(&mut self, creator_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_CREATOR_ID, creator_id);
+  }
+  #[inline]
+  pub fn add_creator_logon(&mut self, creator_logon: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_CREATOR_LOGON, creator_logon);
+  }
+  #[inline]
+  pub fn add_modifier_id(&mut self, modifier_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_MODIFIER_ID, modifier_id);
+  }
+  #[inline]
+  pub fn add_modifier_logon(&mut self, modifier_logon: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_MODIFIER_LOGON, modifier_logon);
+  }
+  #[inline]
+  pub fn add_revision(&mut self, revision: i32) {
+    self.fbb_.push_slot::<i32>(FileDescriptionT::VT_REVISION, revision, 0);
+  }
+  #[inline]
+  pub fn add_times_calibrated(&mut self, times_calibrated: i32) {
+    self.fbb_.push_slot::<i32>(FileDescriptionT::VT_TIMES_CALIBRATED, times_calibrated, 0);
+  }
+  #[inline]
+  pub fn add_times_modified(&mut self, times_modified: i32) {
+    self.fbb_.push_slot::<i32>(FileDescriptionT::VT_TIMES_MODIFIED, times_modified, 0);
+  }
+  #[inline]
+  pub fn add_run_header(&mut self, run_header: &RunHeaderT) {
+    self.fbb_.push_slot_always::<&RunHeaderT>(FileDescriptionT::VT_RUN_HEADER, run_header);
+  }
+  #[inline]
+  pub fn add_sample_type(&mut self, sample_type: SampleType) {
+    self.fbb_.push_slot::<SampleType>(FileDescriptionT::VT_SAMPLE_TYPE, sample_type, SampleType::Unknown);
+  }
+  #[inline]
+  pub fn add_sample_barcode(&mut self, sample_barcode: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_BARCODE, sample_barcode);
+  }
+  #[inline]
+  pub fn add_sample_barcode_status(&mut self, sample_barcode_status: BarcodeStatus) {
+    self.fbb_.push_slot::<BarcodeStatus>(FileDescriptionT::VT_SAMPLE_BARCODE_STATUS, sample_barcode_status, BarcodeStatus::NotRead);
+  }
+  #[inline]
+  pub fn add_injection_volume(&mut self, injection_volume: f64) {
+    self.fbb_.push_slot::<f64>(FileDescriptionT::VT_INJECTION_VOLUME, injection_volume, 0.0);
+  }
+  #[inline]
+  pub fn add_dilution_factor(&mut self, dilution_factor: f64) {
+    self.fbb_.push_slot::<f64>(FileDescriptionT::VT_DILUTION_FACTOR, dilution_factor, 0.0);
+  }
+  #[inline]
+  pub fn add_sample_weight(&mut self, sample_weight: f64) {
+    self.fbb_.push_slot::<f64>(FileDescriptionT::VT_SAMPLE_WEIGHT, sample_weight, 0.0);
+  }
+  #[inline]
+  pub fn add_sample_volume(&mut self, sample_volume: f64) {
+    self.fbb_.push_slot::<f64>(FileDescriptionT::VT_SAMPLE_VOLUME, sample_volume, 0.0);
+  }
+  #[inline]
+  pub fn add_istd_amount(&mut self, istd_amount: f64) {
+    self.fbb_.push_slot::<f64>(FileDescriptionT::VT_ISTD_AMOUNT, istd_amount, 0.0);
+  }
+  #[inline]
+  pub fn add_calibration_level(&mut self, calibration_level: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_CALIBRATION_LEVEL, calibration_level);
+  }
+  #[inline]
+  pub fn add_row_number(&mut self, row_number: i32) {
+    self.fbb_.push_slot::<i32>(FileDescriptionT::VT_ROW_NUMBER, row_number, 0);
+  }
+  #[inline]
+  pub fn add_instrument_method_file(&mut self, instrument_method_file: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_INSTRUMENT_METHOD_FILE, instrument_method_file);
+  }
+  #[inline]
+  pub fn add_processing_method_file(&mut self, processing_method_file: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_PROCESSING_METHOD_FILE, processing_method_file);
+  }
+  #[inline]
+  pub fn add_calibration_file(&mut self, calibration_file: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_CALIBRATION_FILE, calibration_file);
+  }
+  #[inline]
+  pub fn add_raw_file_path(&mut self, raw_file_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_RAW_FILE_PATH, raw_file_path);
+  }
+  #[inline]
+  pub fn add_sample_user_text(&mut self, sample_user_text: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_USER_TEXT, sample_user_text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FileDescriptionTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FileDescriptionTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FileDescriptionT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FileDescriptionT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FileDescriptionT");
+      ds.field("creation_date", &self.creation_date());
+      ds.field("sample_id", &self.sample_id());
+      ds.field("source_file", &self.source_file());
+      ds.field("spectra_per_ms_level", &self.spectra_per_ms_level());
+      ds.field("trailer_headers", &self.trailer_headers());
+      ds.field("sample_name", &self.sample_name());
+      ds.field("sample_vial", &self.sample_vial());
+      ds.field("sample_comment", &self.sample_comment());
+      ds.field("creation_timestamp", &self.creation_timestamp());
+      ds.field("modification_timestamp", &self.modification_timestamp());
+      ds.field("description", &self.description());
+      ds.field("creator_id", &self.creator_id());
+      ds.field("creator_logon", &self.creator_logon());
+      ds.field("modifier_id", &self.modifier_id());
+      ds.field("modifier_logon", &self.modifier_logon());
+      ds.field("revision", &self.revision());
+      ds.field("times_calibrated", &self.times_calibrated());
+      ds.field("times_modified", &self.times_modified());
+      ds.field("run_header", &self.run_header());
+      ds.field("sample_type", &self.sample_type());
+      ds.field("sample_barcode", &self.sample_barcode());
+      ds.field("sample_barcode_status", &self.sample_barcode_status());
+      ds.field("injection_volume", &self.injection_volume());
+      ds.field("dilution_factor", &self.dilution_factor());
+      ds.field("sample_weight", &self.sample_weight());
+      ds.field("sample_volume", &self.sample_volume());
+      ds.field("istd_amount", &self.istd_amount());
+      ds.field("calibration_level", &self.calibration_level());
+      ds.field("row_number", &self.row_number());
+      ds.field("instrument_method_file", &self.instrument_method_file());
+      ds.field("processing_method_file", &self.processing_method_file());
+      ds.field("calibration_file", &self.calibration_file());
+      ds.field("raw_file_path", &self.raw_file_path());
+      ds.field("sample_user_text", &self.sample_user_text());
+      ds.finish()
+  }
+}
+pub enum InstrumentModelTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct InstrumentModelT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for InstrumentModelT<'a> {
+  type Inner = InstrumentModelT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> InstrumentModelT<'a> {
+  pub const VT_CONFIGURATIONS: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_MODEL: flatbuffers::VOffsetT = 8;
+  pub const VT_SERIAL_NUMBER: flatbuffers::VOffsetT = 10;
+  pub const VT_HARDWARE_VERSION: flatbuffers::VOffsetT = 12;
+  pub const VT_SOFTWARE_VERSION: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    InstrumentModelT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args InstrumentModelTArgs<'args>
+  ) -> flatbuffers::WIPOffset<InstrumentModelT<'bldr>> {
+    let mut builder = InstrumentModelTBuilder::new(_fbb);
+    if let Some(x) = args.software_version { builder.add_software_version(x); }
+    if let Some(x) = args.hardware_version { builder.add_hardware_version(x); }
+    if let Some(x) = args.serial_number { builder.add_serial_number(x); }
+    if let Some(x) = args.model { builder.add_model(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.configurations { builder.add_configurations(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn configurations(&self) -> Option<flatbuffers::Vector<'a, InstrumentConfigurationT>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, InstrumentConfigurationT>>>(InstrumentModelT::VT_CONFIGURATIONS, None)}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn model(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_MODEL, None)}
+  }
+  #[inline]
+  pub fn serial_number(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_SERIAL_NUMBER, None)}
+  }
+  #[inline]
+  pub fn hardware_version(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_HARDWARE_VERSION, None)}
+  }
+  #[inline]
+  pub fn software_version(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_SOFTWARE_VERSION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for InstrumentModelT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, InstrumentConfigurationT>>>("configurations", Self::VT_CONFIGURATIONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("model", Self::VT_MODEL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("serial_number", Self::VT_SERIAL_NUMBER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("hardware_version", Self::VT_HARDWARE_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("software_version", Self::VT_SOFTWARE_VERSION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct InstrumentModelTArgs<'a> {
+    pub configurations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, InstrumentConfigurationT>>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub model: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub serial_number: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub hardware_version: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub software_version: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for InstrumentModelTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    InstrumentModelTArgs {
+      configurations: None,
+      name: None,
+      model: None,
+      serial_number: None,
+      hardware_version: None,
+      software_version: None,
+    }
+  }
+}
+
+pub struct InstrumentModelTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InstrumentModelTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_configurations(&mut self, configurations: flatbuffers::WIPOffset<flatbuffers::Vector<'b , InstrumentConfigurationT>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_CONFIGURATIONS, configurations);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_model(&mut self, model: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_MODEL, model);
+  }
+  #[inline]
+  pub fn add_serial_number(&mut self, serial_number: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_SERIAL_NUMBER, serial_number);
+  }
+  #[inline]
+  pub fn add_hardware_version(&mut self, hardware_version: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_HARDWARE_VERSION, hardware_version);
+  }
+  #[inline]
+  pub fn add_software_version(&mut self, software_version: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_SOFTWARE_VERSION, software_version);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> InstrumentModelTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    InstrumentModelTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<InstrumentModelT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for InstrumentModelT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("InstrumentModelT");
+      ds.field("configurations", &self.configurations());
+      ds.field("name", &self.name());
+      ds.field("model", &self.model());
+      ds.field("serial_number", &self.serial_number());
+      ds.field("hardware_version", &self.hardware_version());
+      ds.field("software_version", &self.software_version());
+      ds.finish()
+  }
+}
+pub enum ActivationDetailsTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ActivationDetailsT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ActivationDetailsT<'a> {
+  type Inner = ActivationDetailsT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ActivationDetailsT<'a> {
+  pub const VT_STEPS: flatbuffers::VOffsetT = 4;
+  pub const VT_STEPPED_COLLISION_ENERGIES: flatbuffers::VOffsetT = 6;
+  pub const VT_SUPPLEMENTAL_ENERGY: flatbuffers::VOffsetT = 8;
+  pub const VT_COLLISION_ENERGY_EV: flatbuffers::VOffsetT = 10;
+  pub const VT_ETD_REACTION_TIME: flatbuffers::VOffsetT = 12;
+  pub const VT_ETD_REAGENT_TARGET: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ActivationDetailsT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ActivationDetailsTArgs<'args>
+  ) -> flatbuffers::WIPOffset<ActivationDetailsT<'bldr>> {
+    let mut builder = ActivationDetailsTBuilder::new(_fbb);
+    if let Some(x) = args.etd_reagent_target { builder.add_etd_reagent_target(x); }
+    if let Some(x) = args.etd_reaction_time { builder.add_etd_reaction_time(x); }
+    if let Some(x) = args.collision_energy_ev { builder.add_collision_energy_ev(x); }
+    if let Some(x) = args.supplemental_energy { builder.add_supplemental_energy(x); }
+    if let Some(x) = args.stepped_collision_energies { builder.add_stepped_collision_energies(x); }
+    if let Some(x) = args.steps { builder.add_steps(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn steps(&self) -> Option<flatbuffers::Vector<'a, ActivationStepT>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, ActivationStepT>>>(ActivationDetailsT::VT_STEPS, None)}
+  }
+  #[inline]
+  pub fn stepped_collision_energies(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(ActivationDetailsT::VT_STEPPED_COLLISION_ENERGIES, None)}
+  }
+  #[inline]
+  pub fn supplemental_energy(&self) -> Option<f64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ActivationDetailsT::VT_SUPPLEMENTAL_ENERGY, None)}
+  }
+  #[inline]
+  pub fn collision_energy_ev(&self) -> Option<f64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ActivationDetailsT::VT_COLLISION_ENERGY_EV, None)}
+  }
+  #[inline]
+  pub fn etd_reaction_time(&self) -> Option<f64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ActivationDetailsT::VT_ETD_REACTION_TIME, None)}
+  }
+  #[inline]
+  pub fn etd_reagent_target(&self) -> Option<f64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ActivationDetailsT::VT_ETD_REAGENT_TARGET, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ActivationDetailsT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, ActivationStepT>>>("steps", Self::VT_STEPS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("stepped_collision_energies", Self::VT_STEPPED_COLLISION_ENERGIES, false)?
+     .visit_field::<f64>("supplemental_energy", Self::VT_SUPPLEMENTAL_ENERGY, false)?
+     .visit_field::<f64>("collision_energy_ev", Self::VT_COLLISION_ENERGY_EV, false)?
+     .visit_field::<f64>("etd_reaction_time", Self::VT_ETD_REACTION_TIME, false)?
+     .visit_field::<f64>("etd_reagent_target", Self::VT_ETD_REAGENT_TARGET, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ActivationDetailsTArgs<'a> {
+    pub steps: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, ActivationStepT>>>,
+    pub stepped_collision_energies: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub supplemental_energy: Option<f64>,
+    pub collision_energy_ev: Option<f64>,
+    pub etd_reaction_time: Option<f64>,
+    pub etd_reagent_target: Option<f64>,
+}
+impl<'a> Default for ActivationDetailsTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ActivationDetailsTArgs {
+      steps: None,
+      stepped_collision_energies: None,
+      supplemental_energy: None,
+      collision_energy_ev: None,
+      etd_reaction_time: None,
+      etd_reagent_target: None,
+    }
+  }
+}
+
+pub struct ActivationDetailsTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ActivationDetailsTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_steps(&mut self, steps: flatbuffers::WIPOffset<flatbuffers::Vector<'b , ActivationStepT>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ActivationDetailsT::VT_STEPS, steps);
+  }
+  #[inline]
+  pub fn add_stepped_collision_energies(&mut self, stepped_collision_energies: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ActivationDetailsT::VT_STEPPED_COLLISION_ENERGIES, stepped_collision_energies);
+  }
+  #[inline]
+  pub fn add_supplemental_energy(&mut self, supplemental_energy: f64) {
+    self.fbb_.push_slot_always::<f64>(ActivationDetailsT::VT_SUPPLEMENTAL_ENERGY, supplemental_energy);
+  }
+  #[inline]
+  pub fn add_collision_energy_ev(&mut self, collision_energy_ev: f64) {
+    self.fbb_.push_slot_always::<f64>(ActivationDetailsT::VT_COLLISION_ENERGY_EV, collision_energy_ev);
+  }
+  #[inline]
+  pub fn add_etd_reaction_time(&mut self, etd_reaction_time: f64) {
+    self.fbb_.push_slot_always::<f64>(ActivationDetailsT::VT_ETD_REACTION_TIME, etd_reaction_time);
+  }
+  #[inline]
+  pub fn add_etd_reagent_target(&mut self, etd_reagent_target: f64) {
+    self.fbb_.push_slot_always::<f64>(ActivationDetailsT::VT_ETD_REAGENT_TARGET, etd_reagent_target);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ActivationDetailsTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ActivationDetailsTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ActivationDetailsT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ActivationDetailsT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ActivationDetailsT");
+      ds.field("steps", &self.steps());
+      ds.field("stepped_collision_energies", &self.stepped_collision_energies());
+      ds.field("supplemental_energy", &self.supplemental_energy());
+      ds.field("collision_energy_ev", &self.collision_energy_ev());
+      ds.field("etd_reaction_time", &self.etd_reaction_time());
+      ds.field("etd_reagent_target", &self.etd_reagent_target());
+      ds.finish()
+  }
+}
+pub enum SpectrumDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpectrumData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpectrumData<'a> {
+  type Inner = SpectrumData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpectrumData<'a> {
+  pub const VT_MZ: flatbuffers::VOffsetT = 4;
+  pub const VT_INTENSITY: flatbuffers::VOffsetT = 6;
+  pub const VT_FLAGS: flatbuffers::VOffsetT = 8;
+  pub const VT_SEGMENT_OFFSETS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpectrumData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SpectrumDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpectrumData<'bldr>> {
+    let mut builder = SpectrumDataBuilder::new(_fbb);
+    if let Some(x) = args.segment_offsets { builder.add_segment_offsets(x); }
+    if let Some(x) = args.flags { builder.add_flags(x); }
+    if let Some(x) = args.intensity { builder.add_intensity(x); }
+    if let Some(x) = args.mz { builder.add_mz(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn mz(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(SpectrumData::VT_MZ, None)}
+  }
+  #[inline]
+  pub fn intensity(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(SpectrumData::VT_INTENSITY, None)}
+  }
+  #[inline]
+  pub fn flags(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(SpectrumData::VT_FLAGS, None)}
+  }
+  #[inline]
+  pub fn segment_offsets(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(SpectrumData::VT_SEGMENT_OFFSETS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SpectrumData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("mz", Self::VT_MZ, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("intensity", Self::VT_INTENSITY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("flags", Self::VT_FLAGS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("segment_offsets", Self::VT_SEGMENT_OFFSETS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpectrumDataArgs<'a> {
+    pub mz: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub intensity: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub flags: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub segment_offsets: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for SpectrumDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpectrumDataArgs {
+      mz: None,
+      intensity: None,
+      flags: None,
+      segment_offsets: None,
+    }
+  }
+}
+
+pub struct SpectrumDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpectrumDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_mz(&mut self, mz: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumData::VT_MZ, mz);
+  }
+  #[inline]
+  pub fn add_intensity(&mut self, intensity: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumData::VT_INTENSITY, intensity);
+  }
+  #[inline]
+  pub fn add_flags(&mut self, flags: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumData::VT_FLAGS, flags);
+  }
+  #[inline]
+  pub fn add_segment_offsets(&mut self, segment_offsets: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumData::VT_SEGMENT_OFFSETS, segment_offsets);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SpectrumDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SpectrumDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpectrumData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpectrumData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpectrumData");
+      ds.field("mz", &self.mz());
+      ds.field("intensity", &self.intensity());
+      ds.field("flags", &self.flags());
+      ds.field("segment_offsets", &self.segment_offsets());
+      ds.finish()
+  }
+}
+pub enum StatusLogFloatTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StatusLogFloatT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StatusLogFloatT<'a> {
+  type Inner = StatusLogFloatT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> StatusLogFloatT<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
+  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StatusLogFloatT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StatusLogFloatTArgs<'args>
+  ) -> flatbuffers::WIPOffset<StatusLogFloatT<'bldr>> {
+    let mut builder = StatusLogFloatTBuilder::new(_fbb);
+    if let Some(x) = args.values { builder.add_values(x); }
+    if let Some(x) = args.times { builder.add_times(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogFloatT::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogFloatT::VT_TIMES, None)}
+  }
+  #[inline]
+  pub fn values(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogFloatT::VT_VALUES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for StatusLogFloatT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("values", Self::VT_VALUES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StatusLogFloatTArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+}
+impl<'a> Default for StatusLogFloatTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StatusLogFloatTArgs {
+      name: None,
+      times: None,
+      values: None,
+    }
+  }
+}
+
+pub struct StatusLogFloatTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogFloatTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogFloatT::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogFloatT::VT_TIMES, times);
+  }
+  #[inline]
+  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogFloatT::VT_VALUES, values);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogFloatTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StatusLogFloatTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogFloatT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StatusLogFloatT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StatusLogFloatT");
+      ds.field("name", &self.name());
+      ds.field("times", &self.times());
+      ds.field("values", &self.values());
+      ds.finish()
+  }
+}
+pub enum StatusLogBoolTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StatusLogBoolT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StatusLogBoolT<'a> {
+  type Inner = StatusLogBoolT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> StatusLogBoolT<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
+  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StatusLogBoolT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StatusLogBoolTArgs<'args>
+  ) -> flatbuffers::WIPOffset<StatusLogBoolT<'bldr>> {
+    let mut builder = StatusLogBoolTBuilder::new(_fbb);
+    if let Some(x) = args.values { builder.add_values(x); }
+    if let Some(x) = args.times { builder.add_times(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogBoolT::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogBoolT::VT_TIMES, None)}
+  }
+  #[inline]
+  pub fn values(&self) -> Option<flatbuffers::Vector<'a, bool>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, bool>>>(StatusLogBoolT::VT_VALUES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for StatusLogBoolT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, bool>>>("values", Self::VT_VALUES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StatusLogBoolTArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, bool>>>,
+}
+impl<'a> Default for StatusLogBoolTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StatusLogBoolTArgs {
+      name: None,
+      times: None,
+      values: None,
+    }
+  }
+}
+
+pub struct StatusLogBoolTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogBoolTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogBoolT::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogBoolT::VT_TIMES, times);
+  }
+  #[inline]
+  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , bool>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogBoolT::VT_VALUES, values);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogBoolTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StatusLogBoolTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogBoolT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StatusLogBoolT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StatusLogBoolT");
+      ds.field("name", &self.name());
+      ds.field("times", &self.times());
+      ds.field("values", &self.values());
+      ds.finish()
+  }
+}
+pub enum StatusLogIntTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StatusLogIntT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StatusLogIntT<'a> {
+  type Inner = StatusLogIntT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> StatusLogIntT<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
+  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StatusLogIntT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StatusLogIntTArgs<'args>
+  ) -> flatbuffers::WIPOffset<StatusLogIntT<'bldr>> {
+    let mut builder = StatusLogIntTBuilder::new(_fbb);
+    if let Some(x) = args.values { builder.add_values(x); }
+    if let Some(x) = args.times { builder.add_times(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogIntT::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogIntT::VT_TIMES, None)}
+  }
+  #[inline]
+  pub fn values(&self) -> Option<flatbuffers::Vector<'a, i64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i64>>>(StatusLogIntT::VT_VALUES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for StatusLogIntT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i64>>>("values", Self::VT_VALUES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StatusLogIntTArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i64>>>,
+}
+impl<'a> Default for StatusLogIntTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StatusLogIntTArgs {
+      name: None,
+      times: None,
+      values: None,
+    }
+  }
+}
+
+pub struct StatusLogIntTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogIntTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogIntT::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogIntT::VT_TIMES, times);
+  }
+  #[inline]
+  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , i64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogIntT::VT_VALUES, values);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogIntTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StatusLogIntTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogIntT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StatusLogIntT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StatusLogIntT");
+      ds.field("name", &self.name());
+      ds.field("times", &self.times());
+      ds.field("values", &self.values());
+      ds.finish()
+  }
+}
+pub enum StatusLogStringTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StatusLogStringT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StatusLogStringT<'a> {
+  type Inner = StatusLogStringT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> StatusLogStringT<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
+  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StatusLogStringT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StatusLogStringTArgs<'args>
+  ) -> flatbuffers::WIPOffset<StatusLogStringT<'bldr>> {
+    let mut builder = StatusLogStringTBuilder::new(_fbb);
+    if let Some(x) = args.values { builder.add_values(x); }
+    if let Some(x) = args.times { builder.add_times(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogStringT::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogStringT::VT_TIMES, None)}
+  }
+  #[inline]
+  pub fn values(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(StatusLogStringT::VT_VALUES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for StatusLogStringT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("values", Self::VT_VALUES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StatusLogStringTArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for StatusLogStringTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StatusLogStringTArgs {
+      name: None,
+      times: None,
+      values: None,
+    }
+  }
+}
+
+pub struct StatusLogStringTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogStringTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogStringT::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogStringT::VT_TIMES, times);
+  }
+  #[inline]
+  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogStringT::VT_VALUES, values);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogStringTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StatusLogStringTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogStringT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StatusLogStringT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StatusLogStringT");
+      ds.field("name", &self.name());
+      ds.field("times", &self.times());
+      ds.field("values", &self.values());
+      ds.finish()
+  }
+}
+pub enum ErrorLogTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ErrorLogT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ErrorLogT<'a> {
+  type Inner = ErrorLogT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ErrorLogT<'a> {
+  pub const VT_TIMES: flatbuffers::VOffsetT = 4;
+  pub const VT_MESSAGES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ErrorLogT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ErrorLogTArgs<'args>
+  ) -> flatbuffers::WIPOffset<ErrorLogT<'bldr>> {
+    let mut builder = ErrorLogTBuilder::new(_fbb);
+    if let Some(x) = args.messages { builder.add_messages(x); }
+    if let Some(x) = args.times { builder.add_times(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(ErrorLogT::VT_TIMES, None)}
+  }
+  #[inline]
+  pub fn messages(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(ErrorLogT::VT_MESSAGES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ErrorLogT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("messages", Self::VT_MESSAGES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ErrorLogTArgs<'a> {
+    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub messages: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for ErrorLogTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ErrorLogTArgs {
+      times: None,
+      messages: None,
+    }
+  }
+}
+
+pub struct ErrorLogTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ErrorLogTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ErrorLogT::VT_TIMES, times);
+  }
+  #[inline]
+  pub fn add_messages(&mut self, messages: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ErrorLogT::VT_MESSAGES, messages);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ErrorLogTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ErrorLogTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ErrorLogT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ErrorLogT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ErrorLogT");
+      ds.field("times", &self.times());
+      ds.field("messages", &self.messages());
+      ds.finish()
+  }
+}
+pub enum StatusLogCollectionTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StatusLogCollectionT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StatusLogCollectionT<'a> {
+  type Inner = StatusLogCollectionT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> StatusLogCollectionT<'a> {
+  pub const VT_FLOAT_LOGS: flatbuffers::VOffsetT = 4;
+  pub const VT_BOOL_LOGS: flatbuffers::VOffsetT = 6;
+  pub const VT_INT_LOGS: flatbuffers::VOffsetT = 8;
+  pub const VT_STRING_LOGS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StatusLogCollectionT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StatusLogCollectionTArgs<'args>
+  ) -> flatbuffers::WIPOffset<StatusLogCollectionT<'bldr>> {
+    let mut builder = StatusLogCollectionTBuilder::new(_fbb);
+    if let Some(x) = args.string_logs { builder.add_string_logs(x); }
+    if let Some(x) = args.int_logs { builder.add_int_logs(x); }
+    if let Some(x) = args.bool_logs { builder.add_bool_logs(x); }
+    if let Some(x) = args.float_logs { builder.add_float_logs(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn float_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogFloatT<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogFloatT>>>>(StatusLogCollectionT::VT_FLOAT_LOGS, None)}
+  }
+  #[inline]
+  pub fn bool_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogBoolT<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogBoolT>>>>(StatusLogCollectionT::VT_BOOL_LOGS, None)}
+  }
+  #[inline]
+  pub fn int_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogIntT<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogIntT>>>>(StatusLogCollectionT::VT_INT_LOGS, None)}
+  }
+  #[inline]
+  pub fn string_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogStringT<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogStringT>>>>(StatusLogCollectionT::VT_STRING_LOGS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for StatusLogCollectionT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogFloatT>>>>("float_logs", Self::VT_FLOAT_LOGS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogBoolT>>>>("bool_logs", Self::VT_BOOL_LOGS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogIntT>>>>("int_logs", Self::VT_INT_LOGS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogStringT>>>>("string_logs", Self::VT_STRING_LOGS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StatusLogCollectionTArgs<'a> {
+    pub float_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogFloatT<'a>>>>>,
+    pub bool_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogBoolT<'a>>>>>,
+    pub int_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogIntT<'a>>>>>,
+    pub string_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogStringT<'a>>>>>,
+}
+impl<'a> Default for StatusLogCollectionTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StatusLogCollectionTArgs {
+      float_logs: None,
+      bool_logs: None,
+      int_logs: None,
+      string_logs: None,
+    }
+  }
+}
+
+pub struct StatusLogCollectionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogCollectionTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_float_logs(&mut self, float_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogFloatT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_FLOAT_LOGS, float_logs);
+  }
+  #[inline]
+  pub fn add_bool_logs(&mut self, bool_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogBoolT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_BOOL_LOGS, bool_logs);
+  }
+  #[inline]
+  pub fn add_int_logs(&mut self, int_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogIntT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_INT_LOGS, int_logs);
+  }
+  #[inline]
+  pub fn add_string_logs(&mut self, string_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogStringT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_STRING_LOGS, string_logs);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogCollectionTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StatusLogCollectionTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogCollectionT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StatusLogCollectionT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StatusLogCollectionT");
+      ds.field("float_logs", &self.float_logs());
+      ds.field("bool_logs", &self.bool_logs());
+      ds.field("int_logs", &self.int_logs());
+      ds.field("string_logs", &self.string_logs());
+      ds.finish()
+  }
+}
+pub enum ExtendedSpectrumDataTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ExtendedSpectrumDataT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ExtendedSpectrumDataT<'a> {
+  type Inner = ExtendedSpectrumDataT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ExtendedSpectrumDataT<'a> {
+  pub const VT_MASS: flatbuffers::VOffsetT = 4;
+  pub const VT_NOISE: flatbuffers::VOffsetT = 6;
+  pub const VT_BASELINE: flatbuffers::VOffsetT = 8;
+  pub const VT_CHARGE: flatbuffers::VOffsetT = 10;
+  pub const VT_RESOLUTION: flatbuffers::VOffsetT = 12;
+  pub const VT_SAMPLED_NOISE: flatbuffers::VOffsetT = 14;
+  pub const VT_SAMPLED_NOISE_BASELINE: flatbuffers::VOffsetT = 16;
+  pub const VT_SAMPLED_NOISE_MZ: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ExtendedSpectrumDataT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ExtendedSpectrumDataTArgs<'args>
+  ) -> flatbuffers::WIPOffset<ExtendedSpectrumDataT<'bldr>> {
+    let mut builder = ExtendedSpectrumDataTBuilder::new(_fbb);
+    if let Some(x) = args.sampled_noise_mz { builder.add_sampled_noise_mz(x); }
+    if let Some(x) = args.sampled_noise_baseline { builder.add_sampled_noise_baseline(x); }
+    if let Some(x) = args.sampled_noise { builder.add_sampled_noise(x); }
+    if let Some(x) = args.resolution { builder.add_resolution(x); }
+    if let Some(x) = args.charge { builder.add_charge(x); }
+    if let Some(x) = args.baseline { builder.add_baseline(x); }
+    if let Some(x) = args.noise { builder.add_noise(x); }
+    if let Some(x) = args.mass { builder.add_mass(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn mass(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(ExtendedSpectrumDataT::VT_MASS, None)}
+  }
+  #[inline]
+  pub fn noise(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_NOISE, None)}
+  }
+  #[inline]
+  pub fn baseline(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_BASELINE, None)}
+  }
+  #[inline]
+  pub fn charge(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_CHARGE, None)}
+  }
+  #[inline]
+  pub fn resolution(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_RESOLUTION, None)}
+  }
+  #[inline]
+  pub fn sampled_noise(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE, None)}
+  }
+  #[inline]
+  pub fn sampled_noise_baseline(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_BASELINE, None)}
+  }
+  #[inline]
+  pub fn sampled_noise_mz(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_MZ, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ExtendedSpectrumDataT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("mass", Self::VT_MASS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("noise", Self::VT_NOISE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("baseline", Self::VT_BASELINE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("charge", Self::VT_CHARGE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("resolution", Self::VT_RESOLUTION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("sampled_noise", Self::VT_SAMPLED_NOISE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("sampled_noise_baseline", Self::VT_SAMPLED_NOISE_BASELINE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("sampled_noise_mz", Self::VT_SAMPLED_NOISE_MZ, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ExtendedSpectrumDataTArgs<'a> {
+    pub mass: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub noise: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub baseline: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub charge: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub resolution: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub sampled_noise: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub sampled_noise_baseline: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub sampled_noise_mz: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+}
+impl<'a> Default for ExtendedSpectrumDataTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ExtendedSpectrumDataTArgs {
+      mass: None,
+      noise: None,
+      baseline: None,
+      charge: None,
+      resolution: None,
+      sampled_noise: None,
+      sampled_noise_baseline: None,
+      sampled_noise_mz: None,
+    }
+  }
+}
+
+pub struct ExtendedSpectrumDataTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ExtendedSpectrumDataTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_mass(&mut self, mass: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_MASS, mass);
+  }
+  #[inline]
+  pub fn add_noise(&mut self, noise: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_NOISE, noise);
+  }
+  #[inline]
+  pub fn add_baseline(&mut self, baseline: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_BASELINE, baseline);
+  }
+  #[inline]
+  pub fn add_charge(&mut self, charge: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_CHARGE, charge);
+  }
+  #[inline]
+  pub fn add_resolution(&mut self, resolution: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_RESOLUTION, resolution);
+  }
+  #[inline]
+  pub fn add_sampled_noise(&mut self, sampled_noise: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE, sampled_noise);
+  }
+  #[inline]
+  pub fn add_sampled_noise_baseline(&mut self, sampled_noise_baseline: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_BASELINE, sampled_noise_baseline);
+  }
+  #[inline]
+  pub fn add_sampled_noise_mz(&mut self, sampled_noise_mz: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_MZ, sampled_noise_mz);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ExtendedSpectrumDataTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ExtendedSpectrumDataTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ExtendedSpectrumDataT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ExtendedSpectrumDataT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ExtendedSpectrumDataT");
+      ds.field("mass", &self.mass());
+      ds.field("noise", &self.noise());
+      ds.field("baseline", &self.baseline());
+      ds.field("charge", &self.charge());
+      ds.field("resolution", &self.resolution());
+      ds.field("sampled_noise", &self.sampled_noise());
+      ds.field("sampled_noise_baseline", &self.sampled_noise_baseline());
+      ds.field("sampled_noise_mz", &self.sampled_noise_mz());
+      ds.finish()
+  }
+}
+pub enum TrailerValueTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TrailerValueT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TrailerValueT<'a> {
+  type Inner = TrailerValueT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TrailerValueT<'a> {
+  pub const VT_LABEL: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TrailerValueT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TrailerValueTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TrailerValueT<'bldr>> {
+    let mut builder = TrailerValueTBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.label { builder.add_label(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn label(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TrailerValueT::VT_LABEL, None)}
+  }
+  #[inline]
+  pub fn value(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TrailerValueT::VT_VALUE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TrailerValueT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TrailerValueTArgs<'a> {
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for TrailerValueTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TrailerValueTArgs {
+      label: None,
+      value: None,
+    }
+  }
+}
+
+pub struct TrailerValueTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerValueTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerValueT::VT_LABEL, label);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerValueT::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerValueTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TrailerValueTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TrailerValueT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TrailerValueT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TrailerValueT");
+      ds.field("label", &self.label());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum TrailerValuesTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TrailerValuesT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TrailerValuesT<'a> {
+  type Inner = TrailerValuesT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TrailerValuesT<'a> {
+  pub const VT_TRAILERS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TrailerValuesT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TrailerValuesTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TrailerValuesT<'bldr>> {
+    let mut builder = TrailerValuesTBuilder::new(_fbb);
+    if let Some(x) = args.trailers { builder.add_trailers(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn trailers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerValueT<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerValueT>>>>(TrailerValuesT::VT_TRAILERS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TrailerValuesT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TrailerValueT>>>>("trailers", Self::VT_TRAILERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TrailerValuesTArgs<'a> {
+    pub trailers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerValueT<'a>>>>>,
+}
+impl<'a> Default for TrailerValuesTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TrailerValuesTArgs {
+      trailers: None,
+    }
+  }
+}
+
+pub struct TrailerValuesTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerValuesTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_trailers(&mut self, trailers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TrailerValueT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerValuesT::VT_TRAILERS, trailers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerValuesTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TrailerValuesTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TrailerValuesT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TrailerValuesT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TrailerValuesT");
+      ds.field("trailers", &self.trailers());
+      ds.finish()
+  }
+}
+pub enum TrailerHeaderTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TrailerHeaderT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TrailerHeaderT<'a> {
+  type Inner = TrailerHeaderT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TrailerHeaderT<'a> {
+  pub const VT_LABEL: flatbuffers::VOffsetT = 4;
+  pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_LENGTH_OR_PRECISION: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TrailerHeaderT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TrailerHeaderTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TrailerHeaderT<'bldr>> {
+    let mut builder = TrailerHeaderTBuilder::new(_fbb);
+    builder.add_length_or_precision(args.length_or_precision);
+    if let Some(x) = args.label { builder.add_label(x); }
+    builder.add_data_type(args.data_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn label(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TrailerHeaderT::VT_LABEL, None)}
+  }
+  #[inline]
+  pub fn data_type(&self) -> TrailerDataType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TrailerDataType>(TrailerHeaderT::VT_DATA_TYPE, Some(TrailerDataType::Null)).unwrap()}
+  }
+  #[inline]
+  pub fn length_or_precision(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(TrailerHeaderT::VT_LENGTH_OR_PRECISION, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TrailerHeaderT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
+     .visit_field::<TrailerDataType>("data_type", Self::VT_DATA_TYPE, false)?
+     .visit_field::<i32>("length_or_precision", Self::VT_LENGTH_OR_PRECISION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TrailerHeaderTArgs<'a> {
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data_type: TrailerDataType,
+    pub length_or_precision: i32,
+}
+impl<'a> Default for TrailerHeaderTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TrailerHeaderTArgs {
+      label: None,
+      data_type: TrailerDataType::Null,
+      length_or_precision: 0,
+    }
+  }
+}
+
+pub struct TrailerHeaderTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerHeaderTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerHeaderT::VT_LABEL, label);
+  }
+  #[inline]
+  pub fn add_data_type(&mut self, data_type: TrailerDataType) {
+    self.fbb_.push_slot::<TrailerDataType>(TrailerHeaderT::VT_DATA_TYPE, data_type, TrailerDataType::Null);
+  }
+  #[inline]
+  pub fn add_length_or_precision(&mut self, length_or_precision: i32) {
+    self.fbb_.push_slot::<i32>(TrailerHeaderT::VT_LENGTH_OR_PRECISION, length_or_precision, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerHeaderTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TrailerHeaderTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TrailerHeaderT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TrailerHeaderT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TrailerHeaderT");
+      ds.field("label", &self.label());
+      ds.field("data_type", &self.data_type());
+      ds.field("length_or_precision", &self.length_or_precision());
+      ds.finish()
+  }
+}
+pub enum TrailerHeaderCollectionTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TrailerHeaderCollectionT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TrailerHeaderCollectionT<'a> {
+  type Inner = TrailerHeaderCollectionT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TrailerHeaderCollectionT<'a> {
+  pub const VT_HEADERS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TrailerHeaderCollectionT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TrailerHeaderCollectionTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TrailerHeaderCollectionT<'bldr>> {
+    let mut builder = TrailerHeaderCollectionTBuilder::new(_fbb);
+    if let Some(x) = args.headers { builder.add_headers(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn headers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerHeaderT<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerHeaderT>>>>(TrailerHeaderCollectionT::VT_HEADERS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TrailerHeaderCollectionT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TrailerHeaderT>>>>("headers", Self::VT_HEADERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TrailerHeaderCollectionTArgs<'a> {
+    pub headers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerHeaderT<'a>>>>>,
+}
+impl<'a> Default for TrailerHeaderCollectionTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TrailerHeaderCollectionTArgs {
+      headers: None,
+    }
+  }
+}
+
+pub struct TrailerHeaderCollectionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerHeaderCollectionTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_headers(&mut self, headers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TrailerHeaderT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerHeaderCollectionT::VT_HEADERS, headers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerHeaderCollectionTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TrailerHeaderCollectionTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
   }
-
-  #[allow(clippy::identity_op)]
-  pub fn set_activation(&mut self, x: &ActivationT) {
-    self.0[48..48 + 16].copy_from_slice(&x.0)
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TrailerHeaderCollectionT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
   }
-
 }
 
-pub enum FileDescriptionTOffset {}
+impl core::fmt::Debug for TrailerHeaderCollectionT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TrailerHeaderCollectionT");
+      ds.field("headers", &self.headers());
+      ds.finish()
+  }
+}
+pub enum TypedTrailerValueTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FileDescriptionT<'a> {
+pub struct TypedTrailerValueT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FileDescriptionT<'a> {
-  type Inner = FileDescriptionT<'a>;
+impl<'a> flatbuffers::Follow<'a> for TypedTrailerValueT<'a> {
+  type Inner = TypedTrailerValueT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> FileDescriptionT<'a> {
-  pub const VT_CREATION_DATE: flatbuffers::VOffsetT = 4;
-  pub const VT_SAMPLE_ID: flatbuffers::VOffsetT = 6;
-  pub const VT_SOURCE_FILE: flatbuffers::VOffsetT = 8;
-  pub const VT_SPECTRA_PER_MS_LEVEL: flatbuffers::VOffsetT = 10;
-  pub const VT_TRAILER_HEADERS: flatbuffers::VOffsetT = 12;
-  pub const VT_SAMPLE_NAME: flatbuffers::VOffsetT = 14;
-  pub const VT_SAMPLE_VIAL: flatbuffers::VOffsetT = 16;
-  pub const VT_SAMPLE_COMMENT: flatbuffers::VOffsetT = 18;
+impl<'a> TypedTrailerValueT<'a> {
+  pub const VT_LABEL: flatbuffers::VOffsetT = 4;
+  pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_FLOAT_VALUE: flatbuffers::VOffsetT = 8;
+  pub const VT_INT_VALUE: flatbuffers::VOffsetT = 10;
+  pub const VT_STRING_VALUE: flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    FileDescriptionT { _tab: table }
+    TypedTrailerValueT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args FileDescriptionTArgs<'args>
-  ) -> flatbuffers::WIPOffset<FileDescriptionT<'bldr>> {
-    let mut builder = FileDescriptionTBuilder::new(_fbb);
-    if let Some(x) = args.sample_comment { builder.add_sample_comment(x); }
-    if let Some(x) = args.sample_vial { builder.add_sample_vial(x); }
-    if let Some(x) = args.sample_name { builder.add_sample_name(x); }
-    if let Some(x) = args.trailer_headers { builder.add_trailer_headers(x); }
-    if let Some(x) = args.spectra_per_ms_level { builder.add_spectra_per_ms_level(x); }
-    if let Some(x) = args.source_file { builder.add_source_file(x); }
-    if let Some(x) = args.sample_id { builder.add_sample_id(x); }
-    if let Some(x) = args.creation_date { builder.add_creation_date(x); }
+    args: &'args TypedTrailerValueTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TypedTrailerValueT<'bldr>> {
+    let mut builder = TypedTrailerValueTBuilder::new(_fbb);
+    builder.add_int_value(args.int_value);
+    builder.add_float_value(args.float_value);
+    if let Some(x) = args.string_value { builder.add_string_value(x); }
+    if let Some(x) = args.label { builder.add_label(x); }
+    builder.add_data_type(args.data_type);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn creation_date(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_CREATION_DATE, None)}
-  }
-  #[inline]
-  pub fn sample_id(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_ID, None)}
-  }
-  #[inline]
-  pub fn source_file(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SOURCE_FILE, None)}
-  }
-  #[inline]
-  pub fn spectra_per_ms_level(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+  pub fn label(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(FileDescriptionT::VT_SPECTRA_PER_MS_LEVEL, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TypedTrailerValueT::VT_LABEL, None)}
   }
   #[inline]
-  pub fn trailer_headers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn data_type(&self) -> TrailerDataType {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(FileDescriptionT::VT_TRAILER_HEADERS, None)}
+    unsafe { self._tab.get::<TrailerDataType>(TypedTrailerValueT::VT_DATA_TYPE, Some(TrailerDataType::Null)).unwrap()}
   }
   #[inline]
-  pub fn sample_name(&self) -> Option<&'a str> {
+  pub fn float_value(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_NAME, None)}
+    unsafe { self._tab.get::<f64>(TypedTrailerValueT::VT_FLOAT_VALUE, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn sample_vial(&self) -> Option<&'a str> {
+  pub fn int_value(&self) -> i64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_VIAL, None)}
+    unsafe { self._tab.get::<i64>(TypedTrailerValueT::VT_INT_VALUE, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn sample_comment(&self) -> Option<&'a str> {
+  pub fn string_value(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(FileDescriptionT::VT_SAMPLE_COMMENT, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TypedTrailerValueT::VT_STRING_VALUE, None)}
   }
 }
 
-impl flatbuffers::Verifiable for FileDescriptionT<'_> {
+impl flatbuffers::Verifiable for TypedTrailerValueT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("creation_date", Self::VT_CREATION_DATE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_id", Self::VT_SAMPLE_ID, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("source_file", Self::VT_SOURCE_FILE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("spectra_per_ms_level", Self::VT_SPECTRA_PER_MS_LEVEL, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("trailer_headers", Self::VT_TRAILER_HEADERS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_name", Self::VT_SAMPLE_NAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_vial", Self::VT_SAMPLE_VIAL, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sample_comment", Self::VT_SAMPLE_COMMENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
+     .visit_field::<TrailerDataType>("data_type", Self::VT_DATA_TYPE, false)?
+     .visit_field::<f64>("float_value", Self::VT_FLOAT_VALUE, false)?
+     .visit_field::<i64>("int_value", Self::VT_INT_VALUE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("string_value", Self::VT_STRING_VALUE, false)?
      .finish();
     Ok(())
   }
 }
-pub struct FileDescriptionTArgs<'a> {
-    pub creation_date: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub sample_id: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub source_file: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub spectra_per_ms_level: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub trailer_headers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
-    pub sample_name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub sample_vial: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub sample_comment: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct TypedTrailerValueTArgs<'a> {
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data_type: TrailerDataType,
+    pub float_value: f64,
+    pub int_value: i64,
+    pub string_value: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for FileDescriptionTArgs<'a> {
+impl<'a> Default for TypedTrailerValueTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    FileDescriptionTArgs {
-      creation_date: None,
-      sample_id: None,
-      source_file: None,
-      spectra_per_ms_level: None,
-      trailer_headers: None,
-      sample_name: None,
-      sample_vial: None,
-      sample_comment: None,
+    TypedTrailerValueTArgs {
+      label: None,
+      data_type: TrailerDataType::Null,
+      float_value: 0.0,
+      int_value: 0,
+      string_value: None,
     }
   }
 }
 
-pub struct FileDescriptionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct TypedTrailerValueTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FileDescriptionTBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_creation_date(&mut self, creation_date: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_CREATION_DATE, creation_date);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TypedTrailerValueTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_sample_id(&mut self, sample_id: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_ID, sample_id);
-  }
-  #[inline]
-  pub fn add_source_file(&mut self, source_file: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SOURCE_FILE, source_file);
-  }
-  #[inline]
-  pub fn add_spectra_per_ms_level(&mut self, spectra_per_ms_level: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SPECTRA_PER_MS_LEVEL, spectra_per_ms_level);
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TypedTrailerValueT::VT_LABEL, label);
   }
   #[inline]
-  pub fn add_trailer_headers(&mut self, trailer_headers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_TRAILER_HEADERS, trailer_headers);
+  pub fn add_data_type(&mut self, data_type: TrailerDataType) {
+    self.fbb_.push_slot::<TrailerDataType>(TypedTrailerValueT::VT_DATA_TYPE, data_type, TrailerDataType::Null);
   }
   #[inline]
-  pub fn add_sample_name(&mut self, sample_name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_NAME, sample_name);
+  pub fn add_float_value(&mut self, float_value: f64) {
+    self.fbb_.push_slot::<f64>(TypedTrailerValueT::VT_FLOAT_VALUE, float_value, 0.0);
   }
   #[inline]
-  pub fn add_sample_vial(&mut self, sample_vial: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_VIAL, sample_vial);
+  pub fn add_int_value(&mut self, int_value: i64) {
+    self.fbb_.push_slot::<i64>(TypedTrailerValueT::VT_INT_VALUE, int_value, 0);
   }
   #[inline]
-  pub fn add_sample_comment(&mut self, sample_comment: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FileDescriptionT::VT_SAMPLE_COMMENT, sample_comment);
+  pub fn add_string_value(&mut self, string_value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TypedTrailerValueT::VT_STRING_VALUE, string_value);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FileDescriptionTBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TypedTrailerValueTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    FileDescriptionTBuilder {
+    TypedTrailerValueTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<FileDescriptionT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<TypedTrailerValueT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for FileDescriptionT<'_> {
+impl core::fmt::Debug for TypedTrailerValueT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("FileDescriptionT");
-      ds.field("creation_date", &self.creation_date());
-      ds.field("sample_id", &self.sample_id());
-      ds.field("source_file", &self.source_file());
-      ds.field("spectra_per_ms_level", &self.spectra_per_ms_level());
-      ds.field("trailer_headers", &self.trailer_headers());
-      ds.field("sample_name", &self.sample_name());
-      ds.field("sample_vial", &self.sample_vial());
-      ds.field("sample_comment", &self.sample_comment());
+    let mut ds = f.debug_struct("TypedTrailerValueT");
+      ds.field("label", &self.label());
+      ds.field("data_type", &self.data_type());
+      ds.field("float_value", &self.float_value());
+      ds.field("int_value", &self.int_value());
+      ds.field("string_value", &self.string_value());
       ds.finish()
   }
 }
-pub enum InstrumentModelTOffset {}
+pub enum TypedTrailerValuesTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct InstrumentModelT<'a> {
+pub struct TypedTrailerValuesT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for InstrumentModelT<'a> {
-  type Inner = InstrumentModelT<'a>;
+impl<'a> flatbuffers::Follow<'a> for TypedTrailerValuesT<'a> {
+  type Inner = TypedTrailerValuesT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> InstrumentModelT<'a> {
-  pub const VT_CONFIGURATIONS: flatbuffers::VOffsetT = 4;
-  pub const VT_NAME: flatbuffers::VOffsetT = 6;
-  pub const VT_MODEL: flatbuffers::VOffsetT = 8;
-  pub const VT_SERIAL_NUMBER: flatbuffers::VOffsetT = 10;
-  pub const VT_HARDWARE_VERSION: flatbuffers::VOffsetT = 12;
-  pub const VT_SOFTWARE_VERSION: flatbuffers::VOffsetT = 14;
+impl<'a> TypedTrailerValuesT<'a> {
+  pub const VT_TRAILERS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    InstrumentModelT { _tab: table }
+    TypedTrailerValuesT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args InstrumentModelTArgs<'args>
-  ) -> flatbuffers::WIPOffset<InstrumentModelT<'bldr>> {
-    let mut builder = InstrumentModelTBuilder::new(_fbb);
-    if let Some(x) = args.software_version { builder.add_software_version(x); }
-    if let Some(x) = args.hardware_version { builder.add_hardware_version(x); }
-    if let Some(x) = args.serial_number { builder.add_serial_number(x); }
-    if let Some(x) = args.model { builder.add_model(x); }
-    if let Some(x) = args.name { builder.add_name(x); }
-    if let Some(x) = args.configurations { builder.add_configurations(x); }
+    args: &'args TypedTrailerValuesTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TypedTrailerValuesT<'bldr>> {
+    let mut builder = TypedTrailerValuesTBuilder::new(_fbb);
+    if let Some(x) = args.trailers { builder.add_trailers(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn configurations(&self) -> Option<flatbuffers::Vector<'a, InstrumentConfigurationT>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, InstrumentConfigurationT>>>(InstrumentModelT::VT_CONFIGURATIONS, None)}
-  }
-  #[inline]
-  pub fn name(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_NAME, None)}
-  }
-  #[inline]
-  pub fn model(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_MODEL, None)}
-  }
-  #[inline]
-  pub fn serial_number(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_SERIAL_NUMBER, None)}
-  }
-  #[inline]
-  pub fn hardware_version(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_HARDWARE_VERSION, None)}
-  }
-  #[inline]
-  pub fn software_version(&self) -> Option<&'a str> {
+  pub fn trailers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TypedTrailerValueT<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentModelT::VT_SOFTWARE_VERSION, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TypedTrailerValueT>>>>(TypedTrailerValuesT::VT_TRAILERS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for InstrumentModelT<'_> {
+impl flatbuffers::Verifiable for TypedTrailerValuesT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, InstrumentConfigurationT>>>("configurations", Self::VT_CONFIGURATIONS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("model", Self::VT_MODEL, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("serial_number", Self::VT_SERIAL_NUMBER, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("hardware_version", Self::VT_HARDWARE_VERSION, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("software_version", Self::VT_SOFTWARE_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TypedTrailerValueT>>>>("trailers", Self::VT_TRAILERS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct InstrumentModelTArgs<'a> {
-    pub configurations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, InstrumentConfigurationT>>>,
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub model: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub serial_number: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub hardware_version: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub software_version: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct TypedTrailerValuesTArgs<'a> {
+    pub trailers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TypedTrailerValueT<'a>>>>>,
 }
-impl<'a> Default for InstrumentModelTArgs<'a> {
+impl<'a> Default for TypedTrailerValuesTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    InstrumentModelTArgs {
-      configurations: None,
-      name: None,
-      model: None,
-      serial_number: None,
-      hardware_version: None,
-      software_version: None,
+    TypedTrailerValuesTArgs {
+      trailers: None,
     }
   }
 }
 
-pub struct InstrumentModelTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct TypedTrailerValuesTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InstrumentModelTBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_configurations(&mut self, configurations: flatbuffers::WIPOffset<flatbuffers::Vector<'b , InstrumentConfigurationT>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_CONFIGURATIONS, configurations);
-  }
-  #[inline]
-  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_NAME, name);
-  }
-  #[inline]
-  pub fn add_model(&mut self, model: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_MODEL, model);
-  }
-  #[inline]
-  pub fn add_serial_number(&mut self, serial_number: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_SERIAL_NUMBER, serial_number);
-  }
-  #[inline]
-  pub fn add_hardware_version(&mut self, hardware_version: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_HARDWARE_VERSION, hardware_version);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TypedTrailerValuesTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_software_version(&mut self, software_version: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentModelT::VT_SOFTWARE_VERSION, software_version);
+  pub fn add_trailers(&mut self, trailers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TypedTrailerValueT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TypedTrailerValuesT::VT_TRAILERS, trailers);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> InstrumentModelTBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TypedTrailerValuesTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    InstrumentModelTBuilder {
+    TypedTrailerValuesTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<InstrumentModelT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<TypedTrailerValuesT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for InstrumentModelT<'_> {
+impl core::fmt::Debug for TypedTrailerValuesT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("InstrumentModelT");
-      ds.field("configurations", &self.configurations());
-      ds.field("name", &self.name());
-      ds.field("model", &self.model());
-      ds.field("serial_number", &self.serial_number());
-      ds.field("hardware_version", &self.hardware_version());
-      ds.field("software_version", &self.software_version());
+    let mut ds = f.debug_struct("TypedTrailerValuesT");
+      ds.field("trailers", &self.trailers());
       ds.finish()
   }
 }
-pub enum SpectrumDataOffset {}
+pub enum TrailerColumnTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SpectrumData<'a> {
+pub struct TrailerColumnT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SpectrumData<'a> {
-  type Inner = SpectrumData<'a>;
+impl<'a> flatbuffers::Follow<'a> for TrailerColumnT<'a> {
+  type Inner = TrailerColumnT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> SpectrumData<'a> {
-  pub const VT_MZ: flatbuffers::VOffsetT = 4;
-  pub const VT_INTENSITY: flatbuffers::VOffsetT = 6;
+impl<'a> TrailerColumnT<'a> {
+  pub const VT_LABEL: flatbuffers::VOffsetT = 4;
+  pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_TIMES: flatbuffers::VOffsetT = 8;
+  pub const VT_FLOAT_VALUES: flatbuffers::VOffsetT = 10;
+  pub const VT_INT_VALUES: flatbuffers::VOffsetT = 12;
+  pub const VT_STRING_VALUES: flatbuffers::VOffsetT = 14;
+  pub const VT_VALID: flatbuffers::VOffsetT = 16;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SpectrumData { _tab: table }
+    TrailerColumnT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SpectrumDataArgs<'args>
-  ) -> flatbuffers::WIPOffset<SpectrumData<'bldr>> {
-    let mut builder = SpectrumDataBuilder::new(_fbb);
-    if let Some(x) = args.intensity { builder.add_intensity(x); }
-    if let Some(x) = args.mz { builder.add_mz(x); }
+    args: &'args TrailerColumnTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TrailerColumnT<'bldr>> {
+    let mut builder = TrailerColumnTBuilder::new(_fbb);
+    if let Some(x) = args.valid { builder.add_valid(x); }
+    if let Some(x) = args.string_values { builder.add_string_values(x); }
+    if let Some(x) = args.int_values { builder.add_int_values(x); }
+    if let Some(x) = args.float_values { builder.add_float_values(x); }
+    if let Some(x) = args.times { builder.add_times(x); }
+    if let Some(x) = args.label { builder.add_label(x); }
+    builder.add_data_type(args.data_type);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn mz(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+  pub fn label(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(SpectrumData::VT_MZ, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TrailerColumnT::VT_LABEL, None)}
   }
   #[inline]
-  pub fn intensity(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn data_type(&self) -> TrailerDataType {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(SpectrumData::VT_INTENSITY, None)}
+    unsafe { self._tab.get::<TrailerDataType>(TrailerColumnT::VT_DATA_TYPE, Some(TrailerDataType::Null)).unwrap()}
+  }
+  #[inline]
+  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(TrailerColumnT::VT_TIMES, None)}
+  }
+  #[inline]
+  pub fn float_values(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(TrailerColumnT::VT_FLOAT_VALUES, None)}
+  }
+  #[inline]
+  pub fn int_values(&self) -> Option<flatbuffers::Vector<'a, i64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i64>>>(TrailerColumnT::VT_INT_VALUES, None)}
+  }
+  #[inline]
+  pub fn string_values(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(TrailerColumnT::VT_STRING_VALUES, None)}
+  }
+  #[inline]
+  pub fn valid(&self) -> Option<flatbuffers::Vector<'a, bool>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, bool>>>(TrailerColumnT::VT_VALID, None)}
   }
 }
 
-impl flatbuffers::Verifiable for SpectrumData<'_> {
+impl flatbuffers::Verifiable for TrailerColumnT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("mz", Self::VT_MZ, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("intensity", Self::VT_INTENSITY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
+     .visit_field::<TrailerDataType>("data_type", Self::VT_DATA_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("float_values", Self::VT_FLOAT_VALUES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i64>>>("int_values", Self::VT_INT_VALUES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("string_values", Self::VT_STRING_VALUES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, bool>>>("valid", Self::VT_VALID, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SpectrumDataArgs<'a> {
-    pub mz: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
-    pub intensity: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+pub struct TrailerColumnTArgs<'a> {
+    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data_type: TrailerDataType,
+    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub float_values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub int_values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i64>>>,
+    pub string_values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub valid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, bool>>>,
 }
-impl<'a> Default for SpectrumDataArgs<'a> {
+impl<'a> Default for TrailerColumnTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SpectrumDataArgs {
-      mz: None,
-      intensity: None,
+    TrailerColumnTArgs {
+      label: None,
+      data_type: TrailerDataType::Null,
+      times: None,
+      float_values: None,
+      int_values: None,
+      string_values: None,
+      valid: None,
     }
   }
 }
 
-pub struct SpectrumDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct TrailerColumnTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpectrumDataBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerColumnTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_mz(&mut self, mz: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumData::VT_MZ, mz);
+  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerColumnT::VT_LABEL, label);
   }
   #[inline]
-  pub fn add_intensity(&mut self, intensity: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumData::VT_INTENSITY, intensity);
+  pub fn add_data_type(&mut self, data_type: TrailerDataType) {
+    self.fbb_.push_slot::<TrailerDataType>(TrailerColumnT::VT_DATA_TYPE, data_type, TrailerDataType::Null);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SpectrumDataBuilder<'a, 'b, A> {
+  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerColumnT::VT_TIMES, times);
+  }
+  #[inline]
+  pub fn add_float_values(&mut self, float_values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerColumnT::VT_FLOAT_VALUES, float_values);
+  }
+  #[inline]
+  pub fn add_int_values(&mut self, int_values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , i64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerColumnT::VT_INT_VALUES, int_values);
+  }
+  #[inline]
+  pub fn add_string_values(&mut self, string_values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerColumnT::VT_STRING_VALUES, string_values);
+  }
+  #[inline]
+  pub fn add_valid(&mut self, valid: flatbuffers::WIPOffset<flatbuffers::Vector<'b , bool>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerColumnT::VT_VALID, valid);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerColumnTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SpectrumDataBuilder {
+    TrailerColumnTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SpectrumData<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<TrailerColumnT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for SpectrumData<'_> {
+impl core::fmt::Debug for TrailerColumnT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SpectrumData");
-      ds.field("mz", &self.mz());
-      ds.field("intensity", &self.intensity());
+    let mut ds = f.debug_struct("TrailerColumnT");
+      ds.field("label", &self.label());
+      ds.field("data_type", &self.data_type());
+      ds.field("times", &self.times());
+      ds.field("float_values", &self.float_values());
+      ds.field("int_values", &self.int_values());
+      ds.field("string_values", &self.string_values());
+      ds.field("valid", &self.valid());
       ds.finish()
   }
 }
-pub enum StatusLogFloatTOffset {}
+pub enum TuneDataSegmentTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct StatusLogFloatT<'a> {
+pub struct TuneDataSegmentT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for StatusLogFloatT<'a> {
-  type Inner = StatusLogFloatT<'a>;
+impl<'a> flatbuffers::Follow<'a> for TuneDataSegmentT<'a> {
+  type Inner = TuneDataSegmentT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> StatusLogFloatT<'a> {
-  pub const VT_NAME: flatbuffers::VOffsetT = 4;
-  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
-  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+impl<'a> TuneDataSegmentT<'a> {
+  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUES: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StatusLogFloatT { _tab: table }
+    TuneDataSegmentT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StatusLogFloatTArgs<'args>
-  ) -> flatbuffers::WIPOffset<StatusLogFloatT<'bldr>> {
-    let mut builder = StatusLogFloatTBuilder::new(_fbb);
+    args: &'args TuneDataSegmentTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TuneDataSegmentT<'bldr>> {
+    let mut builder = TuneDataSegmentTBuilder::new(_fbb);
     if let Some(x) = args.values { builder.add_values(x); }
-    if let Some(x) = args.times { builder.add_times(x); }
-    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_index(args.index);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn name(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogFloatT::VT_NAME, None)}
-  }
-  #[inline]
-  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+  pub fn index(&self) -> i32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogFloatT::VT_TIMES, None)}
+    unsafe { self._tab.get::<i32>(TuneDataSegmentT::VT_INDEX, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn values(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+  pub fn values(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TypedTrailerValueT<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogFloatT::VT_VALUES, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TypedTrailerValueT>>>>(TuneDataSegmentT::VT_VALUES, None)}
   }
 }
 
-impl flatbuffers::Verifiable for StatusLogFloatT<'_> {
+impl flatbuffers::Verifiable for TuneDataSegmentT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("values", Self::VT_VALUES, false)?
+     .visit_field::<i32>("index", Self::VT_INDEX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TypedTrailerValueT>>>>("values", Self::VT_VALUES, false)?
      .finish();
     Ok(())
   }
 }
-pub struct StatusLogFloatTArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
-    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+pub struct TuneDataSegmentTArgs<'a> {
+    pub index: i32,
+    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TypedTrailerValueT<'a>>>>>,
 }
-impl<'a> Default for StatusLogFloatTArgs<'a> {
+impl<'a> Default for TuneDataSegmentTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    StatusLogFloatTArgs {
-      name: None,
-      times: None,
+    TuneDataSegmentTArgs {
+      index: 0,
       values: None,
     }
   }
 }
 
-pub struct StatusLogFloatTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct TuneDataSegmentTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogFloatTBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogFloatT::VT_NAME, name);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TuneDataSegmentTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogFloatT::VT_TIMES, times);
+  pub fn add_index(&mut self, index: i32) {
+    self.fbb_.push_slot::<i32>(TuneDataSegmentT::VT_INDEX, index, 0);
   }
   #[inline]
-  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogFloatT::VT_VALUES, values);
+  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TypedTrailerValueT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TuneDataSegmentT::VT_VALUES, values);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogFloatTBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TuneDataSegmentTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StatusLogFloatTBuilder {
+    TuneDataSegmentTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogFloatT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<TuneDataSegmentT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for StatusLogFloatT<'_> {
+impl core::fmt::Debug for TuneDataSegmentT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StatusLogFloatT");
-      ds.field("name", &self.name());
-      ds.field("times", &self.times());
+    let mut ds = f.debug_struct("TuneDataSegmentT");
+      ds.field("index", &self.index());
       ds.field("values", &self.values());
       ds.finish()
   }
 }
-pub enum StatusLogBoolTOffset {}
+pub enum AcquisitionTreeTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct StatusLogBoolT<'a> {
+pub struct AcquisitionTreeT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for StatusLogBoolT<'a> {
-  type Inner = StatusLogBoolT<'a>;
+impl<'a> flatbuffers::Follow<'a> for AcquisitionTreeT<'a> {
+  type Inner = AcquisitionTreeT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> StatusLogBoolT<'a> {
-  pub const VT_NAME: flatbuffers::VOffsetT = 4;
-  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
-  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+impl<'a> AcquisitionTreeT<'a> {
+  pub const VT_PARENTS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StatusLogBoolT { _tab: table }
+    AcquisitionTreeT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StatusLogBoolTArgs<'args>
-  ) -> flatbuffers::WIPOffset<StatusLogBoolT<'bldr>> {
-    let mut builder = StatusLogBoolTBuilder::new(_fbb);
-    if let Some(x) = args.values { builder.add_values(x); }
-    if let Some(x) = args.times { builder.add_times(x); }
-    if let Some(x) = args.name { builder.add_name(x); }
+    args: &'args AcquisitionTreeTArgs<'args>
+  ) -> flatbuffers::WIPOffset<AcquisitionTreeT<'bldr>> {
+    let mut builder = AcquisitionTreeTBuilder::new(_fbb);
+    if let Some(x) = args.parents { builder.add_parents(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn name(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogBoolT::VT_NAME, None)}
-  }
-  #[inline]
-  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogBoolT::VT_TIMES, None)}
-  }
-  #[inline]
-  pub fn values(&self) -> Option<flatbuffers::Vector<'a, bool>> {
+  pub fn parents(&self) -> Option<flatbuffers::Vector<'a, i32>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, bool>>>(StatusLogBoolT::VT_VALUES, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(AcquisitionTreeT::VT_PARENTS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for StatusLogBoolT<'_> {
+impl flatbuffers::Verifiable for AcquisitionTreeT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, bool>>>("values", Self::VT_VALUES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>("parents", Self::VT_PARENTS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct StatusLogBoolTArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
-    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, bool>>>,
+pub struct AcquisitionTreeTArgs<'a> {
+    pub parents: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
 }
-impl<'a> Default for StatusLogBoolTArgs<'a> {
+impl<'a> Default for AcquisitionTreeTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    StatusLogBoolTArgs {
-      name: None,
-      times: None,
-      values: None,
+    AcquisitionTreeTArgs {
+      parents: None,
     }
   }
 }
 
-pub struct StatusLogBoolTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct AcquisitionTreeTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogBoolTBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogBoolT::VT_NAME, name);
-  }
-  #[inline]
-  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogBoolT::VT_TIMES, times);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AcquisitionTreeTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , bool>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogBoolT::VT_VALUES, values);
+  pub fn add_parents(&mut self, parents: flatbuffers::WIPOffset<flatbuffers::Vector<'b , i32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AcquisitionTreeT::VT_PARENTS, parents);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogBoolTBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AcquisitionTreeTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StatusLogBoolTBuilder {
+    AcquisitionTreeTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogBoolT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<AcquisitionTreeT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for StatusLogBoolT<'_> {
+impl core::fmt::Debug for AcquisitionTreeT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StatusLogBoolT");
-      ds.field("name", &self.name());
-      ds.field("times", &self.times());
-      ds.field("values", &self.values());
+    let mut ds = f.debug_struct("AcquisitionTreeT");
+      ds.field("parents", &self.parents());
       ds.finish()
   }
 }
-pub enum StatusLogIntTOffset {}
+pub enum TuneDataTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct StatusLogIntT<'a> {
+pub struct TuneDataT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for StatusLogIntT<'a> {
-  type Inner = StatusLogIntT<'a>;
+impl<'a> flatbuffers::Follow<'a> for TuneDataT<'a> {
+  type Inner = TuneDataT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> StatusLogIntT<'a> {
-  pub const VT_NAME: flatbuffers::VOffsetT = 4;
-  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
-  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+impl<'a> TuneDataT<'a> {
+  pub const VT_SEGMENTS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StatusLogIntT { _tab: table }
+    TuneDataT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StatusLogIntTArgs<'args>
-  ) -> flatbuffers::WIPOffset<StatusLogIntT<'bldr>> {
-    let mut builder = StatusLogIntTBuilder::new(_fbb);
-    if let Some(x) = args.values { builder.add_values(x); }
-    if let Some(x) = args.times { builder.add_times(x); }
-    if let Some(x) = args.name { builder.add_name(x); }
+    args: &'args TuneDataTArgs<'args>
+  ) -> flatbuffers::WIPOffset<TuneDataT<'bldr>> {
+    let mut builder = TuneDataTBuilder::new(_fbb);
+    if let Some(x) = args.segments { builder.add_segments(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn name(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogIntT::VT_NAME, None)}
-  }
-  #[inline]
-  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogIntT::VT_TIMES, None)}
-  }
-  #[inline]
-  pub fn values(&self) -> Option<flatbuffers::Vector<'a, i64>> {
+  pub fn segments(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TuneDataSegmentT<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i64>>>(StatusLogIntT::VT_VALUES, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TuneDataSegmentT>>>>(TuneDataT::VT_SEGMENTS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for StatusLogIntT<'_> {
+impl flatbuffers::Verifiable for TuneDataT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i64>>>("values", Self::VT_VALUES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TuneDataSegmentT>>>>("segments", Self::VT_SEGMENTS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct StatusLogIntTArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
-    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i64>>>,
+pub struct TuneDataTArgs<'a> {
+    pub segments: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TuneDataSegmentT<'a>>>>>,
 }
-impl<'a> Default for StatusLogIntTArgs<'a> {
+impl<'a> Default for TuneDataTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    StatusLogIntTArgs {
-      name: None,
-      times: None,
-      values: None,
+    TuneDataTArgs {
+      segments: None,
     }
   }
 }
 
-pub struct StatusLogIntTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct TuneDataTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogIntTBuilder<'a, 'b, A> {
-  #[inline]
-  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogIntT::VT_NAME, name);
-  }
-  #[inline]
-  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogIntT::VT_TIMES, times);
-  }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TuneDataTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , i64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogIntT::VT_VALUES, values);
+  pub fn add_segments(&mut self, segments: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TuneDataSegmentT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TuneDataT::VT_SEGMENTS, segments);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogIntTBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TuneDataTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StatusLogIntTBuilder {
+    TuneDataTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogIntT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<TuneDataT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for StatusLogIntT<'_> {
+impl core::fmt::Debug for TuneDataT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StatusLogIntT");
-      ds.field("name", &self.name());
-      ds.field("times", &self.times());
-      ds.field("values", &self.values());
+    let mut ds = f.debug_struct("TuneDataT");
+      ds.field("segments", &self.segments());
       ds.finish()
   }
 }
-pub enum StatusLogStringTOffset {}
+pub enum InstrumentMethodTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct StatusLogStringT<'a> {
+pub struct InstrumentMethodT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for StatusLogStringT<'a> {
-  type Inner = StatusLogStringT<'a>;
+impl<'a> flatbuffers::Follow<'a> for InstrumentMethodT<'a> {
+  type Inner = InstrumentMethodT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> StatusLogStringT<'a> {
-  pub const VT_NAME: flatbuffers::VOffsetT = 4;
-  pub const VT_TIMES: flatbuffers::VOffsetT = 6;
-  pub const VT_VALUES: flatbuffers::VOffsetT = 8;
+impl<'a> InstrumentMethodT<'a> {
+  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 6;
+  pub const VT_DISPLAY_NAME: flatbuffers::VOffsetT = 8;
+  pub const VT_NAME: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StatusLogStringT { _tab: table }
+    InstrumentMethodT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StatusLogStringTArgs<'args>
-  ) -> flatbuffers::WIPOffset<StatusLogStringT<'bldr>> {
-    let mut builder = StatusLogStringTBuilder::new(_fbb);
-    if let Some(x) = args.values { builder.add_values(x); }
-    if let Some(x) = args.times { builder.add_times(x); }
+    args: &'args InstrumentMethodTArgs<'args>
+  ) -> flatbuffers::WIPOffset<InstrumentMethodT<'bldr>> {
+    let mut builder = InstrumentMethodTBuilder::new(_fbb);
     if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.display_name { builder.add_display_name(x); }
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_index(args.index);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn name(&self) -> Option<&'a str> {
+  pub fn index(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StatusLogStringT::VT_NAME, None)}
+    unsafe { self._tab.get::<u8>(InstrumentMethodT::VT_INDEX, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn times(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+  pub fn text(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(StatusLogStringT::VT_TIMES, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentMethodT::VT_TEXT, None)}
   }
   #[inline]
-  pub fn values(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+  pub fn display_name(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(StatusLogStringT::VT_VALUES, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentMethodT::VT_DISPLAY_NAME, None)}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentMethodT::VT_NAME, None)}
   }
 }
 
-impl flatbuffers::Verifiable for StatusLogStringT<'_> {
+impl flatbuffers::Verifiable for InstrumentMethodT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
+     .visit_field::<u8>("index", Self::VT_INDEX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("display_name", Self::VT_DISPLAY_NAME, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("times", Self::VT_TIMES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("values", Self::VT_VALUES, false)?
      .finish();
     Ok(())
   }
 }
-pub struct StatusLogStringTArgs<'a> {
+pub struct InstrumentMethodTArgs<'a> {
+    pub index: u8,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub display_name: Option<flatbuffers::WIPOffset<&'a str>>,
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub times: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
-    pub values: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
 }
-impl<'a> Default for StatusLogStringTArgs<'a> {
+impl<'a> Default for InstrumentMethodTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    StatusLogStringTArgs {
+    InstrumentMethodTArgs {
+      index: 0,
+      text: None,
+      display_name: None,
       name: None,
-      times: None,
-      values: None,
     }
   }
 }
 
-pub struct StatusLogStringTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct InstrumentMethodTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogStringTBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InstrumentMethodTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogStringT::VT_NAME, name);
+  pub fn add_index(&mut self, index: u8) {
+    self.fbb_.push_slot::<u8>(InstrumentMethodT::VT_INDEX, index, 0);
   }
   #[inline]
-  pub fn add_times(&mut self, times: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogStringT::VT_TIMES, times);
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentMethodT::VT_TEXT, text);
   }
   #[inline]
-  pub fn add_values(&mut self, values: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogStringT::VT_VALUES, values);
+  pub fn add_display_name(&mut self, display_name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentMethodT::VT_DISPLAY_NAME, display_name);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogStringTBuilder<'a, 'b, A> {
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentMethodT::VT_NAME, name);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> InstrumentMethodTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StatusLogStringTBuilder {
+    InstrumentMethodTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogStringT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<InstrumentMethodT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for StatusLogStringT<'_> {
+impl core::fmt::Debug for InstrumentMethodT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StatusLogStringT");
+    let mut ds = f.debug_struct("InstrumentMethodT");
+      ds.field("index", &self.index());
+      ds.field("text", &self.text());
+      ds.field("display_name", &self.display_name());
       ds.field("name", &self.name());
-      ds.field("times", &self.times());
-      ds.field("values", &self.values());
       ds.finish()
   }
 }
-pub enum StatusLogCollectionTOffset {}
+pub enum AcquisitionTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct StatusLogCollectionT<'a> {
+pub struct AcquisitionT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for StatusLogCollectionT<'a> {
-  type Inner = StatusLogCollectionT<'a>;
+impl<'a> flatbuffers::Follow<'a> for AcquisitionT<'a> {
+  type Inner = AcquisitionT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> StatusLogCollectionT<'a> {
-  pub const VT_FLOAT_LOGS: flatbuffers::VOffsetT = 4;
-  pub const VT_BOOL_LOGS: flatbuffers::VOffsetT = 6;
-  pub const VT_INT_LOGS: flatbuffers::VOffsetT = 8;
-  pub const VT_STRING_LOGS: flatbuffers::VOffsetT = 10;
+impl<'a> AcquisitionT<'a> {
+  pub const VT_LOW_MZ: flatbuffers::VOffsetT = 4;
+  pub const VT_HIGH_MZ: flatbuffers::VOffsetT = 6;
+  pub const VT_INJECTION_TIME: flatbuffers::VOffsetT = 8;
+  pub const VT_COMPENSATION_VOLTAGES: flatbuffers::VOffsetT = 10;
+  pub const VT_MASS_ANALYZER: flatbuffers::VOffsetT = 12;
+  pub const VT_SCAN_EVENT: flatbuffers::VOffsetT = 14;
+  pub const VT_IONIZATION_MODE: flatbuffers::VOffsetT = 16;
+  pub const VT_RESOLUTION: flatbuffers::VOffsetT = 18;
+  pub const VT_SCAN_WINDOWS: flatbuffers::VOffsetT = 20;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    StatusLogCollectionT { _tab: table }
+    AcquisitionT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args StatusLogCollectionTArgs<'args>
-  ) -> flatbuffers::WIPOffset<StatusLogCollectionT<'bldr>> {
-    let mut builder = StatusLogCollectionTBuilder::new(_fbb);
-    if let Some(x) = args.string_logs { builder.add_string_logs(x); }
-    if let Some(x) = args.int_logs { builder.add_int_logs(x); }
-    if let Some(x) = args.bool_logs { builder.add_bool_logs(x); }
-    if let Some(x) = args.float_logs { builder.add_float_logs(x); }
+    args: &'args AcquisitionTArgs<'args>
+  ) -> flatbuffers::WIPOffset<AcquisitionT<'bldr>> {
+    let mut builder = AcquisitionTBuilder::new(_fbb);
+    builder.add_high_mz(args.high_mz);
+    builder.add_low_mz(args.low_mz);
+    if let Some(x) = args.scan_windows { builder.add_scan_windows(x); }
+    if let Some(x) = args.resolution { builder.add_resolution(x); }
+    builder.add_scan_event(args.scan_event);
+    if let Some(x) = args.compensation_voltages { builder.add_compensation_voltages(x); }
+    builder.add_injection_time(args.injection_time);
+    builder.add_ionization_mode(args.ionization_mode);
+    builder.add_mass_analyzer(args.mass_analyzer);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn float_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogFloatT<'a>>>> {
+  pub fn low_mz(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogFloatT>>>>(StatusLogCollectionT::VT_FLOAT_LOGS, None)}
+    unsafe { self._tab.get::<f64>(AcquisitionT::VT_LOW_MZ, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn bool_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogBoolT<'a>>>> {
+  pub fn high_mz(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogBoolT>>>>(StatusLogCollectionT::VT_BOOL_LOGS, None)}
+    unsafe { self._tab.get::<f64>(AcquisitionT::VT_HIGH_MZ, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn int_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogIntT<'a>>>> {
+  pub fn injection_time(&self) -> f32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogIntT>>>>(StatusLogCollectionT::VT_INT_LOGS, None)}
+    unsafe { self._tab.get::<f32>(AcquisitionT::VT_INJECTION_TIME, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn string_logs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogStringT<'a>>>> {
+  pub fn compensation_voltages(&self) -> Option<flatbuffers::Vector<'a, f32>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogStringT>>>>(StatusLogCollectionT::VT_STRING_LOGS, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(AcquisitionT::VT_COMPENSATION_VOLTAGES, None)}
+  }
+  #[inline]
+  pub fn mass_analyzer(&self) -> MassAnalyzer {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MassAnalyzer>(AcquisitionT::VT_MASS_ANALYZER, Some(MassAnalyzer::FTMS)).unwrap()}
+  }
+  #[inline]
+  pub fn scan_event(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(AcquisitionT::VT_SCAN_EVENT, Some(1)).unwrap()}
+  }
+  #[inline]
+  pub fn ionization_mode(&self) -> IonizationMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<IonizationMode>(AcquisitionT::VT_IONIZATION_MODE, Some(IonizationMode::NanoSpray)).unwrap()}
+  }
+  #[inline]
+  pub fn resolution(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AcquisitionT::VT_RESOLUTION, None)}
+  }
+  #[inline]
+  pub fn scan_windows(&self) -> Option<flatbuffers::Vector<'a, MassRangeT>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, MassRangeT>>>(AcquisitionT::VT_SCAN_WINDOWS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for StatusLogCollectionT<'_> {
+impl flatbuffers::Verifiable for AcquisitionT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogFloatT>>>>("float_logs", Self::VT_FLOAT_LOGS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogBoolT>>>>("bool_logs", Self::VT_BOOL_LOGS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogIntT>>>>("int_logs", Self::VT_INT_LOGS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StatusLogStringT>>>>("string_logs", Self::VT_STRING_LOGS, false)?
+     .visit_field::<f64>("low_mz", Self::VT_LOW_MZ, false)?
+     .visit_field::<f64>("high_mz", Self::VT_HIGH_MZ, false)?
+     .visit_field::<f32>("injection_time", Self::VT_INJECTION_TIME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("compensation_voltages", Self::VT_COMPENSATION_VOLTAGES, false)?
+     .visit_field::<MassAnalyzer>("mass_analyzer", Self::VT_MASS_ANALYZER, false)?
+     .visit_field::<i32>("scan_event", Self::VT_SCAN_EVENT, false)?
+     .visit_field::<IonizationMode>("ionization_mode", Self::VT_IONIZATION_MODE, false)?
+     .visit_field::<f32>("resolution", Self::VT_RESOLUTION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, MassRangeT>>>("scan_windows", Self::VT_SCAN_WINDOWS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct StatusLogCollectionTArgs<'a> {
-    pub float_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogFloatT<'a>>>>>,
-    pub bool_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogBoolT<'a>>>>>,
-    pub int_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogIntT<'a>>>>>,
-    pub string_logs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StatusLogStringT<'a>>>>>,
+pub struct AcquisitionTArgs<'a> {
+    pub low_mz: f64,
+    pub high_mz: f64,
+    pub injection_time: f32,
+    pub compensation_voltages: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub mass_analyzer: MassAnalyzer,
+    pub scan_event: i32,
+    pub ionization_mode: IonizationMode,
+    pub resolution: Option<f32>,
+    pub scan_windows: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, MassRangeT>>>,
 }
-impl<'a> Default for StatusLogCollectionTArgs<'a> {
+impl<'a> Default for AcquisitionTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    StatusLogCollectionTArgs {
-      float_logs: None,
-      bool_logs: None,
-      int_logs: None,
-      string_logs: None,
+    AcquisitionTArgs {
+      low_mz: 0.0,
+      high_mz: 0.0,
+      injection_time: 0.0,
+      compensation_voltages: None,
+      mass_analyzer: MassAnalyzer::FTMS,
+      scan_event: 1,
+      ionization_mode: IonizationMode::NanoSpray,
+      resolution: None,
+      scan_windows: None,
     }
   }
 }
 
-pub struct StatusLogCollectionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct AcquisitionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StatusLogCollectionTBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AcquisitionTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_float_logs(&mut self, float_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogFloatT<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_FLOAT_LOGS, float_logs);
+  pub fn add_low_mz(&mut self, low_mz: f64) {
+    self.fbb_.push_slot::<f64>(AcquisitionT::VT_LOW_MZ, low_mz, 0.0);
   }
   #[inline]
-  pub fn add_bool_logs(&mut self, bool_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogBoolT<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_BOOL_LOGS, bool_logs);
+  pub fn add_high_mz(&mut self, high_mz: f64) {
+    self.fbb_.push_slot::<f64>(AcquisitionT::VT_HIGH_MZ, high_mz, 0.0);
   }
   #[inline]
-  pub fn add_int_logs(&mut self, int_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogIntT<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_INT_LOGS, int_logs);
+  pub fn add_injection_time(&mut self, injection_time: f32) {
+    self.fbb_.push_slot::<f32>(AcquisitionT::VT_INJECTION_TIME, injection_time, 0.0);
   }
   #[inline]
-  pub fn add_string_logs(&mut self, string_logs: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StatusLogStringT<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StatusLogCollectionT::VT_STRING_LOGS, string_logs);
+  pub fn add_compensation_voltages(&mut self, compensation_voltages: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AcquisitionT::VT_COMPENSATION_VOLTAGES, compensation_voltages);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StatusLogCollectionTBuilder<'a, 'b, A> {
+  pub fn add_mass_analyzer(&mut self, mass_analyzer: MassAnalyzer) {
+    self.fbb_.push_slot::<MassAnalyzer>(AcquisitionT::VT_MASS_ANALYZER, mass_analyzer, MassAnalyzer::FTMS);
+  }
+  #[inline]
+  pub fn add_scan_event(&mut self, scan_event: i32) {
+    self.fbb_.push_slot::<i32>(AcquisitionT::VT_SCAN_EVENT, scan_event, 1);
+  }
+  #[inline]
+  pub fn add_ionization_mode(&mut self, ionization_mode: IonizationMode) {
+    self.fbb_.push_slot::<IonizationMode>(AcquisitionT::VT_IONIZATION_MODE, ionization_mode, IonizationMode::NanoSpray);
+  }
+  #[inline]
+  pub fn add_resolution(&mut self, resolution: f32) {
+    self.fbb_.push_slot_always::<f32>(AcquisitionT::VT_RESOLUTION, resolution);
+  }
+  #[inline]
+  pub fn add_scan_windows(&mut self, scan_windows: flatbuffers::WIPOffset<flatbuffers::Vector<'b , MassRangeT>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AcquisitionT::VT_SCAN_WINDOWS, scan_windows);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AcquisitionTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    StatusLogCollectionTBuilder {
+    AcquisitionTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<StatusLogCollectionT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<AcquisitionT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for StatusLogCollectionT<'_> {
+impl core::fmt::Debug for AcquisitionT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("StatusLogCollectionT");
-      ds.field("float_logs", &self.float_logs());
-      ds.field("bool_logs", &self.bool_logs());
-      ds.field("int_logs", &self.int_logs());
-      ds.field("string_logs", &self.string_logs());
+    let mut ds = f.debug_struct("AcquisitionT");
+      ds.field("low_mz", &self.low_mz());
+      ds.field("high_mz", &self.high_mz());
+      ds.field("injection_time", &self.injection_time());
+      ds.field("compensation_voltages", &self.compensation_voltages());
+      ds.field("mass_analyzer", &self.mass_analyzer());
+      ds.field("scan_event", &self.scan_event());
+      ds.field("ionization_mode", &self.ionization_mode());
+      ds.field("resolution", &self.resolution());
+      ds.field("scan_windows", &self.scan_windows());
       ds.finish()
   }
 }
-pub enum ExtendedSpectrumDataTOffset {}
+pub enum ScanFilterTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ExtendedSpectrumDataT<'a> {
+pub struct ScanFilterT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ExtendedSpectrumDataT<'a> {
-  type Inner = ExtendedSpectrumDataT<'a>;
+impl<'a> flatbuffers::Follow<'a> for ScanFilterT<'a> {
+  type Inner = ScanFilterT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> ExtendedSpectrumDataT<'a> {
-  pub const VT_MASS: flatbuffers::VOffsetT = 4;
-  pub const VT_NOISE: flatbuffers::VOffsetT = 6;
-  pub const VT_BASELINE: flatbuffers::VOffsetT = 8;
-  pub const VT_CHARGE: flatbuffers::VOffsetT = 10;
-  pub const VT_RESOLUTION: flatbuffers::VOffsetT = 12;
-  pub const VT_SAMPLED_NOISE: flatbuffers::VOffsetT = 14;
-  pub const VT_SAMPLED_NOISE_BASELINE: flatbuffers::VOffsetT = 16;
-  pub const VT_SAMPLED_NOISE_MZ: flatbuffers::VOffsetT = 18;
+impl<'a> ScanFilterT<'a> {
+  pub const VT_MASS_ANALYZER: flatbuffers::VOffsetT = 4;
+  pub const VT_POLARITY: flatbuffers::VOffsetT = 6;
+  pub const VT_SCAN_MODE: flatbuffers::VOffsetT = 8;
+  pub const VT_MS_ORDER: flatbuffers::VOffsetT = 10;
+  pub const VT_MODE: flatbuffers::VOffsetT = 12;
+  pub const VT_IONIZATION_MODE: flatbuffers::VOffsetT = 14;
+  pub const VT_REACTIONS: flatbuffers::VOffsetT = 16;
+  pub const VT_MASS_RANGES: flatbuffers::VOffsetT = 18;
+  pub const VT_COMPENSATION_VOLTAGES: flatbuffers::VOffsetT = 20;
+  pub const VT_DETECTOR_VALUE: flatbuffers::VOffsetT = 22;
+  pub const VT_SPS: flatbuffers::VOffsetT = 24;
+  pub const VT_DEPENDENT: flatbuffers::VOffsetT = 26;
+  pub const VT_SUPPLEMENTAL_ACTIVATION: flatbuffers::VOffsetT = 28;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    ExtendedSpectrumDataT { _tab: table }
+    ScanFilterT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args ExtendedSpectrumDataTArgs<'args>
-  ) -> flatbuffers::WIPOffset<ExtendedSpectrumDataT<'bldr>> {
-    let mut builder = ExtendedSpectrumDataTBuilder::new(_fbb);
-    if let Some(x) = args.sampled_noise_mz { builder.add_sampled_noise_mz(x); }
-    if let Some(x) = args.sampled_noise_baseline { builder.add_sampled_noise_baseline(x); }
-    if let Some(x) = args.sampled_noise { builder.add_sampled_noise(x); }
-    if let Some(x) = args.resolution { builder.add_resolution(x); }
-    if let Some(x) = args.charge { builder.add_charge(x); }
-    if let Some(x) = args.baseline { builder.add_baseline(x); }
-    if let Some(x) = args.noise { builder.add_noise(x); }
-    if let Some(x) = args.mass { builder.add_mass(x); }
+    args: &'args ScanFilterTArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScanFilterT<'bldr>> {
+    let mut builder = ScanFilterTBuilder::new(_fbb);
+    if let Some(x) = args.detector_value { builder.add_detector_value(x); }
+    if let Some(x) = args.compensation_voltages { builder.add_compensation_voltages(x); }
+    if let Some(x) = args.mass_ranges { builder.add_mass_ranges(x); }
+    if let Some(x) = args.reactions { builder.add_reactions(x); }
+    builder.add_ms_order(args.ms_order);
+    builder.add_supplemental_activation(args.supplemental_activation);
+    builder.add_dependent(args.dependent);
+    builder.add_sps(args.sps);
+    builder.add_ionization_mode(args.ionization_mode);
+    builder.add_mode(args.mode);
+    builder.add_scan_mode(args.scan_mode);
+    builder.add_polarity(args.polarity);
+    builder.add_mass_analyzer(args.mass_analyzer);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn mass(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+  pub fn mass_analyzer(&self) -> MassAnalyzer {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(ExtendedSpectrumDataT::VT_MASS, None)}
+    unsafe { self._tab.get::<MassAnalyzer>(ScanFilterT::VT_MASS_ANALYZER, Some(MassAnalyzer::Unknown)).unwrap()}
   }
   #[inline]
-  pub fn noise(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn polarity(&self) -> Polarity {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_NOISE, None)}
+    unsafe { self._tab.get::<Polarity>(ScanFilterT::VT_POLARITY, Some(Polarity::Unknown)).unwrap()}
   }
   #[inline]
-  pub fn baseline(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn scan_mode(&self) -> ScanMode {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_BASELINE, None)}
+    unsafe { self._tab.get::<ScanMode>(ScanFilterT::VT_SCAN_MODE, Some(ScanMode::Full)).unwrap()}
   }
   #[inline]
-  pub fn charge(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn ms_order(&self) -> MSOrder {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_CHARGE, None)}
+    unsafe { self._tab.get::<MSOrder>(ScanFilterT::VT_MS_ORDER, Some(MSOrder::Unknown)).unwrap()}
   }
   #[inline]
-  pub fn resolution(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn mode(&self) -> SpectrumMode {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_RESOLUTION, None)}
+    unsafe { self._tab.get::<SpectrumMode>(ScanFilterT::VT_MODE, Some(SpectrumMode::Unknown)).unwrap()}
   }
   #[inline]
-  pub fn sampled_noise(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn ionization_mode(&self) -> IonizationMode {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE, None)}
+    unsafe { self._tab.get::<IonizationMode>(ScanFilterT::VT_IONIZATION_MODE, Some(IonizationMode::Any)).unwrap()}
   }
   #[inline]
-  pub fn sampled_noise_baseline(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn reactions(&self) -> Option<flatbuffers::Vector<'a, ScanFilterReactionT>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_BASELINE, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, ScanFilterReactionT>>>(ScanFilterT::VT_REACTIONS, None)}
   }
   #[inline]
-  pub fn sampled_noise_mz(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn mass_ranges(&self) -> Option<flatbuffers::Vector<'a, MassRangeT>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_MZ, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, MassRangeT>>>(ScanFilterT::VT_MASS_RANGES, None)}
+  }
+  #[inline]
+  pub fn compensation_voltages(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(ScanFilterT::VT_COMPENSATION_VOLTAGES, None)}
+  }
+  #[inline]
+  pub fn detector_value(&self) -> Option<f64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ScanFilterT::VT_DETECTOR_VALUE, None)}
+  }
+  #[inline]
+  pub fn sps(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ScanFilterT::VT_SPS, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn dependent(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ScanFilterT::VT_DEPENDENT, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn supplemental_activation(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ScanFilterT::VT_SUPPLEMENTAL_ACTIVATION, Some(false)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for ExtendedSpectrumDataT<'_> {
+impl flatbuffers::Verifiable for ScanFilterT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("mass", Self::VT_MASS, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("noise", Self::VT_NOISE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("baseline", Self::VT_BASELINE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("charge", Self::VT_CHARGE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("resolution", Self::VT_RESOLUTION, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("sampled_noise", Self::VT_SAMPLED_NOISE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("sampled_noise_baseline", Self::VT_SAMPLED_NOISE_BASELINE, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("sampled_noise_mz", Self::VT_SAMPLED_NOISE_MZ, false)?
+     .visit_field::<MassAnalyzer>("mass_analyzer", Self::VT_MASS_ANALYZER, false)?
+     .visit_field::<Polarity>("polarity", Self::VT_POLARITY, false)?
+     .visit_field::<ScanMode>("scan_mode", Self::VT_SCAN_MODE, false)?
+     .visit_field::<MSOrder>("ms_order", Self::VT_MS_ORDER, false)?
+     .visit_field::<SpectrumMode>("mode", Self::VT_MODE, false)?
+     .visit_field::<IonizationMode>("ionization_mode", Self::VT_IONIZATION_MODE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, ScanFilterReactionT>>>("reactions", Self::VT_REACTIONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, MassRangeT>>>("mass_ranges", Self::VT_MASS_RANGES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("compensation_voltages", Self::VT_COMPENSATION_VOLTAGES, false)?
+     .visit_field::<f64>("detector_value", Self::VT_DETECTOR_VALUE, false)?
+     .visit_field::<bool>("sps", Self::VT_SPS, false)?
+     .visit_field::<bool>("dependent", Self::VT_DEPENDENT, false)?
+     .visit_field::<bool>("supplemental_activation", Self::VT_SUPPLEMENTAL_ACTIVATION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct ExtendedSpectrumDataTArgs<'a> {
-    pub mass: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
-    pub noise: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub baseline: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub charge: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub resolution: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub sampled_noise: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub sampled_noise_baseline: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub sampled_noise_mz: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+pub struct ScanFilterTArgs<'a> {
+    pub mass_analyzer: MassAnalyzer,
+    pub polarity: Polarity,
+    pub scan_mode: ScanMode,
+    pub ms_order: MSOrder,
+    pub mode: SpectrumMode,
+    pub ionization_mode: IonizationMode,
+    pub reactions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, ScanFilterReactionT>>>,
+    pub mass_ranges: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, MassRangeT>>>,
+    pub compensation_voltages: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub detector_value: Option<f64>,
+    pub sps: bool,
+    pub dependent: bool,
+    pub supplemental_activation: bool,
 }
-impl<'a> Default for ExtendedSpectrumDataTArgs<'a> {
+impl<'a> Default for ScanFilterTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    ExtendedSpectrumDataTArgs {
-      mass: None,
-      noise: None,
-      baseline: None,
-      charge: None,
-      resolution: None,
-      sampled_noise: None,
-      sampled_noise_baseline: None,
-      sampled_noise_mz: None,
+    ScanFilterTArgs {
+      mass_analyzer: MassAnalyzer::Unknown,
+      polarity: Polarity::Unknown,
+      scan_mode: ScanMode::Full,
+      ms_order: MSOrder::Unknown,
+      mode: SpectrumMode::Unknown,
+      ionization_mode: IonizationMode::Any,
+      reactions: None,
+      mass_ranges: None,
+      compensation_voltages: None,
+      detector_value: None,
+      sps: false,
+      dependent: false,
+      supplemental_activation: false,
     }
   }
 }
 
-pub struct ExtendedSpectrumDataTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ScanFilterTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ExtendedSpectrumDataTBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScanFilterTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_mass(&mut self, mass: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_MASS, mass);
+  pub fn add_mass_analyzer(&mut self, mass_analyzer: MassAnalyzer) {
+    self.fbb_.push_slot::<MassAnalyzer>(ScanFilterT::VT_MASS_ANALYZER, mass_analyzer, MassAnalyzer::Unknown);
   }
   #[inline]
-  pub fn add_noise(&mut self, noise: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_NOISE, noise);
+  pub fn add_polarity(&mut self, polarity: Polarity) {
+    self.fbb_.push_slot::<Polarity>(ScanFilterT::VT_POLARITY, polarity, Polarity::Unknown);
   }
   #[inline]
-  pub fn add_baseline(&mut self, baseline: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_BASELINE, baseline);
+  pub fn add_scan_mode(&mut self, scan_mode: ScanMode) {
+    self.fbb_.push_slot::<ScanMode>(ScanFilterT::VT_SCAN_MODE, scan_mode, ScanMode::Full);
   }
   #[inline]
-  pub fn add_charge(&mut self, charge: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_CHARGE, charge);
+  pub fn add_ms_order(&mut self, ms_order: MSOrder) {
+    self.fbb_.push_slot::<MSOrder>(ScanFilterT::VT_MS_ORDER, ms_order, MSOrder::Unknown);
   }
   #[inline]
-  pub fn add_resolution(&mut self, resolution: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_RESOLUTION, resolution);
+  pub fn add_mode(&mut self, mode: SpectrumMode) {
+    self.fbb_.push_slot::<SpectrumMode>(ScanFilterT::VT_MODE, mode, SpectrumMode::Unknown);
   }
   #[inline]
-  pub fn add_sampled_noise(&mut self, sampled_noise: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE, sampled_noise);
+  pub fn add_ionization_mode(&mut self, ionization_mode: IonizationMode) {
+    self.fbb_.push_slot::<IonizationMode>(ScanFilterT::VT_IONIZATION_MODE, ionization_mode, IonizationMode::Any);
   }
   #[inline]
-  pub fn add_sampled_noise_baseline(&mut self, sampled_noise_baseline: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_BASELINE, sampled_noise_baseline);
+  pub fn add_reactions(&mut self, reactions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , ScanFilterReactionT>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanFilterT::VT_REACTIONS, reactions);
   }
   #[inline]
-  pub fn add_sampled_noise_mz(&mut self, sampled_noise_mz: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ExtendedSpectrumDataT::VT_SAMPLED_NOISE_MZ, sampled_noise_mz);
+  pub fn add_mass_ranges(&mut self, mass_ranges: flatbuffers::WIPOffset<flatbuffers::Vector<'b , MassRangeT>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanFilterT::VT_MASS_RANGES, mass_ranges);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ExtendedSpectrumDataTBuilder<'a, 'b, A> {
+  pub fn add_compensation_voltages(&mut self, compensation_voltages: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanFilterT::VT_COMPENSATION_VOLTAGES, compensation_voltages);
+  }
+  #[inline]
+  pub fn add_detector_value(&mut self, detector_value: f64) {
+    self.fbb_.push_slot_always::<f64>(ScanFilterT::VT_DETECTOR_VALUE, detector_value);
+  }
+  #[inline]
+  pub fn add_sps(&mut self, sps: bool) {
+    self.fbb_.push_slot::<bool>(ScanFilterT::VT_SPS, sps, false);
+  }
+  #[inline]
+  pub fn add_dependent(&mut self, dependent: bool) {
+    self.fbb_.push_slot::<bool>(ScanFilterT::VT_DEPENDENT, dependent, false);
+  }
+  #[inline]
+  pub fn add_supplemental_activation(&mut self, supplemental_activation: bool) {
+    self.fbb_.push_slot::<bool>(ScanFilterT::VT_SUPPLEMENTAL_ACTIVATION, supplemental_activation, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScanFilterTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    ExtendedSpectrumDataTBuilder {
+    ScanFilterTBuilder {
       fbb_: _fbb,
       start_: start,
     }
-  }
-  #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<ExtendedSpectrumDataT<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
-  }
-}
-
-impl core::fmt::Debug for ExtendedSpectrumDataT<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("ExtendedSpectrumDataT");
-      ds.field("mass", &self.mass());
-      ds.field("noise", &self.noise());
-      ds.field("baseline", &self.baseline());
-      ds.field("charge", &self.charge());
-      ds.field("resolution", &self.resolution());
-      ds.field("sampled_noise", &self.sampled_noise());
-      ds.field("sampled_noise_baseline", &self.sampled_noise_baseline());
-      ds.field("sampled_noise_mz", &self.sampled_noise_mz());
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScanFilterT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScanFilterT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScanFilterT");
+      ds.field("mass_analyzer", &self.mass_analyzer());
+      ds.field("polarity", &self.polarity());
+      ds.field("scan_mode", &self.scan_mode());
+      ds.field("ms_order", &self.ms_order());
+      ds.field("mode", &self.mode());
+      ds.field("ionization_mode", &self.ionization_mode());
+      ds.field("reactions", &self.reactions());
+      ds.field("mass_ranges", &self.mass_ranges());
+      ds.field("compensation_voltages", &self.compensation_voltages());
+      ds.field("detector_value", &self.detector_value());
+      ds.field("sps", &self.sps());
+      ds.field("dependent", &self.dependent());
+      ds.field("supplemental_activation", &self.supplemental_activation());
       ds.finish()
   }
 }
-pub enum TrailerValueTOffset {}
+pub enum ScanFilterGroupTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TrailerValueT<'a> {
+pub struct ScanFilterGroupT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TrailerValueT<'a> {
-  type Inner = TrailerValueT<'a>;
+impl<'a> flatbuffers::Follow<'a> for ScanFilterGroupT<'a> {
+  type Inner = ScanFilterGroupT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> TrailerValueT<'a> {
-  pub const VT_LABEL: flatbuffers::VOffsetT = 4;
-  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+impl<'a> ScanFilterGroupT<'a> {
+  pub const VT_FILTER_STRING: flatbuffers::VOffsetT = 4;
+  pub const VT_SCAN_FILTER: flatbuffers::VOffsetT = 6;
+  pub const VT_INDICES: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    TrailerValueT { _tab: table }
+    ScanFilterGroupT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args TrailerValueTArgs<'args>
-  ) -> flatbuffers::WIPOffset<TrailerValueT<'bldr>> {
-    let mut builder = TrailerValueTBuilder::new(_fbb);
-    if let Some(x) = args.value { builder.add_value(x); }
-    if let Some(x) = args.label { builder.add_label(x); }
+    args: &'args ScanFilterGroupTArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScanFilterGroupT<'bldr>> {
+    let mut builder = ScanFilterGroupTBuilder::new(_fbb);
+    if let Some(x) = args.indices { builder.add_indices(x); }
+    if let Some(x) = args.scan_filter { builder.add_scan_filter(x); }
+    if let Some(x) = args.filter_string { builder.add_filter_string(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn label(&self) -> Option<&'a str> {
+  pub fn filter_string(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TrailerValueT::VT_LABEL, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScanFilterGroupT::VT_FILTER_STRING, None)}
   }
   #[inline]
-  pub fn value(&self) -> Option<&'a str> {
+  pub fn scan_filter(&self) -> Option<ScanFilterT<'a>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TrailerValueT::VT_VALUE, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ScanFilterT>>(ScanFilterGroupT::VT_SCAN_FILTER, None)}
+  }
+  #[inline]
+  pub fn indices(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(ScanFilterGroupT::VT_INDICES, None)}
   }
 }
 
-impl flatbuffers::Verifiable for TrailerValueT<'_> {
+impl flatbuffers::Verifiable for ScanFilterGroupT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("label", Self::VT_LABEL, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filter_string", Self::VT_FILTER_STRING, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ScanFilterT>>("scan_filter", Self::VT_SCAN_FILTER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("indices", Self::VT_INDICES, false)?
      .finish();
     Ok(())
   }
 }
-pub struct TrailerValueTArgs<'a> {
-    pub label: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct ScanFilterGroupTArgs<'a> {
+    pub filter_string: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub scan_filter: Option<flatbuffers::WIPOffset<ScanFilterT<'a>>>,
+    pub indices: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for TrailerValueTArgs<'a> {
+impl<'a> Default for ScanFilterGroupTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    TrailerValueTArgs {
-      label: None,
-      value: None,
+    ScanFilterGroupTArgs {
+      filter_string: None,
+      scan_filter: None,
+      indices: None,
     }
   }
 }
 
-pub struct TrailerValueTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ScanFilterGroupTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerValueTBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScanFilterGroupTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_label(&mut self, label: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerValueT::VT_LABEL, label);
+  pub fn add_filter_string(&mut self, filter_string: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanFilterGroupT::VT_FILTER_STRING, filter_string);
   }
   #[inline]
-  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerValueT::VT_VALUE, value);
+  pub fn add_scan_filter(&mut self, scan_filter: flatbuffers::WIPOffset<ScanFilterT<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ScanFilterT>>(ScanFilterGroupT::VT_SCAN_FILTER, scan_filter);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerValueTBuilder<'a, 'b, A> {
+  pub fn add_indices(&mut self, indices: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanFilterGroupT::VT_INDICES, indices);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScanFilterGroupTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    TrailerValueTBuilder {
+    ScanFilterGroupTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<TrailerValueT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScanFilterGroupT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for TrailerValueT<'_> {
+impl core::fmt::Debug for ScanFilterGroupT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("TrailerValueT");
-      ds.field("label", &self.label());
-      ds.field("value", &self.value());
+    let mut ds = f.debug_struct("ScanFilterGroupT");
+      ds.field("filter_string", &self.filter_string());
+      ds.field("scan_filter", &self.scan_filter());
+      ds.field("indices", &self.indices());
       ds.finish()
   }
 }
-pub enum TrailerValuesTOffset {}
+pub enum ScanFilterGroupCollectionTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TrailerValuesT<'a> {
+pub struct ScanFilterGroupCollectionT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TrailerValuesT<'a> {
-  type Inner = TrailerValuesT<'a>;
+impl<'a> flatbuffers::Follow<'a> for ScanFilterGroupCollectionT<'a> {
+  type Inner = ScanFilterGroupCollectionT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> TrailerValuesT<'a> {
-  pub const VT_TRAILERS: flatbuffers::VOffsetT = 4;
+impl<'a> ScanFilterGroupCollectionT<'a> {
+  pub const VT_GROUPS: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    TrailerValuesT { _tab: table }
+    ScanFilterGroupCollectionT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args TrailerValuesTArgs<'args>
-  ) -> flatbuffers::WIPOffset<TrailerValuesT<'bldr>> {
-    let mut builder = TrailerValuesTBuilder::new(_fbb);
-    if let Some(x) = args.trailers { builder.add_trailers(x); }
+    args: &'args ScanFilterGroupCollectionTArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScanFilterGroupCollectionT<'bldr>> {
+    let mut builder = ScanFilterGroupCollectionTBuilder::new(_fbb);
+    if let Some(x) = args.groups { builder.add_groups(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn trailers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerValueT<'a>>>> {
+  pub fn groups(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScanFilterGroupT<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerValueT>>>>(TrailerValuesT::VT_TRAILERS, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScanFilterGroupT>>>>(ScanFilterGroupCollectionT::VT_GROUPS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for TrailerValuesT<'_> {
+impl flatbuffers::Verifiable for ScanFilterGroupCollectionT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TrailerValueT>>>>("trailers", Self::VT_TRAILERS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ScanFilterGroupT>>>>("groups", Self::VT_GROUPS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct TrailerValuesTArgs<'a> {
-    pub trailers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TrailerValueT<'a>>>>>,
+pub struct ScanFilterGroupCollectionTArgs<'a> {
+    pub groups: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScanFilterGroupT<'a>>>>>,
 }
-impl<'a> Default for TrailerValuesTArgs<'a> {
+impl<'a> Default for ScanFilterGroupCollectionTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    TrailerValuesTArgs {
-      trailers: None,
+    ScanFilterGroupCollectionTArgs {
+      groups: None,
     }
   }
 }
 
-pub struct TrailerValuesTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ScanFilterGroupCollectionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TrailerValuesTBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScanFilterGroupCollectionTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_trailers(&mut self, trailers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TrailerValueT<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TrailerValuesT::VT_TRAILERS, trailers);
+  pub fn add_groups(&mut self, groups: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ScanFilterGroupT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanFilterGroupCollectionT::VT_GROUPS, groups);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TrailerValuesTBuilder<'a, 'b, A> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScanFilterGroupCollectionTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    TrailerValuesTBuilder {
+    ScanFilterGroupCollectionTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<TrailerValuesT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScanFilterGroupCollectionT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for TrailerValuesT<'_> {
+impl core::fmt::Debug for ScanFilterGroupCollectionT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("TrailerValuesT");
-      ds.field("trailers", &self.trailers());
+    let mut ds = f.debug_struct("ScanFilterGroupCollectionT");
+      ds.field("groups", &self.groups());
       ds.finish()
   }
 }
-pub enum InstrumentMethodTOffset {}
+pub enum ScanStatisticsTOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct InstrumentMethodT<'a> {
+pub struct ScanStatisticsT<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for InstrumentMethodT<'a> {
-  type Inner = InstrumentMethodT<'a>;
+impl<'a> flatbuffers::Follow<'a> for ScanStatisticsT<'a> {
+  type Inner = ScanStatisticsT<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> InstrumentMethodT<'a> {
-  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
-  pub const VT_TEXT: flatbuffers::VOffsetT = 6;
-  pub const VT_DISPLAY_NAME: flatbuffers::VOffsetT = 8;
-  pub const VT_NAME: flatbuffers::VOffsetT = 10;
+impl<'a> ScanStatisticsT<'a> {
+  pub const VT_TIC: flatbuffers::VOffsetT = 4;
+  pub const VT_BASE_PEAK_MZ: flatbuffers::VOffsetT = 6;
+  pub const VT_BASE_PEAK_INTENSITY: flatbuffers::VOffsetT = 8;
+  pub const VT_LOW_MASS: flatbuffers::VOffsetT = 10;
+  pub const VT_HIGH_MASS: flatbuffers::VOffsetT = 12;
+  pub const VT_PACKET_TYPE: flatbuffers::VOffsetT = 14;
+  pub const VT_PACKET_COUNT: flatbuffers::VOffsetT = 16;
+  pub const VT_SEGMENT_NUMBER: flatbuffers::VOffsetT = 18;
+  pub const VT_CYCLE_NUMBER: flatbuffers::VOffsetT = 20;
+  pub const VT_SCAN_EVENT_NUMBER: flatbuffers::VOffsetT = 22;
+  pub const VT_NUMBER_OF_CHANNELS: flatbuffers::VOffsetT = 24;
+  pub const VT_FREQUENCY: flatbuffers::VOffsetT = 26;
+  pub const VT_IS_UNIFORM_TIME: flatbuffers::VOffsetT = 28;
+  pub const VT_IS_CENTROID: flatbuffers::VOffsetT = 30;
+  pub const VT_SHORT_WAVELENGTH: flatbuffers::VOffsetT = 32;
+  pub const VT_LONG_WAVELENGTH: flatbuffers::VOffsetT = 34;
+  pub const VT_WAVELENGTH_STEP: flatbuffers::VOffsetT = 36;
+  pub const VT_ABSORBANCE_UNIT_SCALE: flatbuffers::VOffsetT = 38;
+  pub const VT_SCAN_TYPE: flatbuffers::VOffsetT = 40;
+  pub const VT_SPECTRUM_COUNT: flatbuffers::VOffsetT = 42;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    InstrumentMethodT { _tab: table }
+    ScanStatisticsT { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args InstrumentMethodTArgs<'args>
-  ) -> flatbuffers::WIPOffset<InstrumentMethodT<'bldr>> {
-    let mut builder = InstrumentMethodTBuilder::new(_fbb);
-    if let Some(x) = args.name { builder.add_name(x); }
-    if let Some(x) = args.display_name { builder.add_display_name(x); }
-    if let Some(x) = args.text { builder.add_text(x); }
-    builder.add_index(args.index);
+    args: &'args ScanStatisticsTArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScanStatisticsT<'bldr>> {
+    let mut builder = ScanStatisticsTBuilder::new(_fbb);
+    builder.add_absorbance_unit_scale(args.absorbance_unit_scale);
+    builder.add_wavelength_step(args.wavelength_step);
+    builder.add_long_wavelength(args.long_wavelength);
+    builder.add_short_wavelength(args.short_wavelength);
+    builder.add_frequency(args.frequency);
+    builder.add_high_mass(args.high_mass);
+    builder.add_low_mass(args.low_mass);
+    builder.add_base_peak_intensity(args.base_peak_intensity);
+    builder.add_base_peak_mz(args.base_peak_mz);
+    builder.add_tic(args.tic);
+    builder.add_spectrum_count(args.spectrum_count);
+    if let Some(x) = args.scan_type { builder.add_scan_type(x); }
+    builder.add_number_of_channels(args.number_of_channels);
+    builder.add_scan_event_number(args.scan_event_number);
+    builder.add_cycle_number(args.cycle_number);
+    builder.add_segment_number(args.segment_number);
+    builder.add_packet_count(args.packet_count);
+    builder.add_packet_type(args.packet_type);
+    builder.add_is_centroid(args.is_centroid);
+    builder.add_is_uniform_time(args.is_uniform_time);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn index(&self) -> u8 {
+  pub fn tic(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u8>(InstrumentMethodT::VT_INDEX, Some(0)).unwrap()}
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_TIC, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn text(&self) -> Option<&'a str> {
+  pub fn base_peak_mz(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentMethodT::VT_TEXT, None)}
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_BASE_PEAK_MZ, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn display_name(&self) -> Option<&'a str> {
+  pub fn base_peak_intensity(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentMethodT::VT_DISPLAY_NAME, None)}
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_BASE_PEAK_INTENSITY, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn name(&self) -> Option<&'a str> {
+  pub fn low_mass(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(InstrumentMethodT::VT_NAME, None)}
-  }
-}
-
-impl flatbuffers::Verifiable for InstrumentMethodT<'_> {
-  #[inline]
-  fn run_verifier(
-    v: &mut flatbuffers::Verifier, pos: usize
-  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-    use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<u8>("index", Self::VT_INDEX, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("display_name", Self::VT_DISPLAY_NAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .finish();
-    Ok(())
-  }
-}
-pub struct InstrumentMethodTArgs<'a> {
-    pub index: u8,
-    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub display_name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-}
-impl<'a> Default for InstrumentMethodTArgs<'a> {
-  #[inline]
-  fn default() -> Self {
-    InstrumentMethodTArgs {
-      index: 0,
-      text: None,
-      display_name: None,
-      name: None,
-    }
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_LOW_MASS, Some(0.0)).unwrap()}
   }
-}
-
-pub struct InstrumentMethodTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InstrumentMethodTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_index(&mut self, index: u8) {
-    self.fbb_.push_slot::<u8>(InstrumentMethodT::VT_INDEX, index, 0);
+  pub fn high_mass(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_HIGH_MASS, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentMethodT::VT_TEXT, text);
+  pub fn packet_type(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(ScanStatisticsT::VT_PACKET_TYPE, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn add_display_name(&mut self, display_name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentMethodT::VT_DISPLAY_NAME, display_name);
+  pub fn packet_count(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(ScanStatisticsT::VT_PACKET_COUNT, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(InstrumentMethodT::VT_NAME, name);
+  pub fn segment_number(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(ScanStatisticsT::VT_SEGMENT_NUMBER, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> InstrumentMethodTBuilder<'a, 'b, A> {
-    let start = _fbb.start_table();
-    InstrumentMethodTBuilder {
-      fbb_: _fbb,
-      start_: start,
-    }
+  pub fn cycle_number(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(ScanStatisticsT::VT_CYCLE_NUMBER, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<InstrumentMethodT<'a>> {
-    let o = self.fbb_.end_table(self.start_);
-    flatbuffers::WIPOffset::new(o.value())
-  }
-}
-
-impl core::fmt::Debug for InstrumentMethodT<'_> {
-  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("InstrumentMethodT");
-      ds.field("index", &self.index());
-      ds.field("text", &self.text());
-      ds.field("display_name", &self.display_name());
-      ds.field("name", &self.name());
-      ds.finish()
+  pub fn scan_event_number(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(ScanStatisticsT::VT_SCAN_EVENT_NUMBER, Some(0)).unwrap()}
   }
-}
-pub enum AcquisitionTOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct AcquisitionT<'a> {
-  pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for AcquisitionT<'a> {
-  type Inner = AcquisitionT<'a>;
   #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  pub fn number_of_channels(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(ScanStatisticsT::VT_NUMBER_OF_CHANNELS, Some(0)).unwrap()}
   }
-}
-
-impl<'a> AcquisitionT<'a> {
-  pub const VT_LOW_MZ: flatbuffers::VOffsetT = 4;
-  pub const VT_HIGH_MZ: flatbuffers::VOffsetT = 6;
-  pub const VT_INJECTION_TIME: flatbuffers::VOffsetT = 8;
-  pub const VT_COMPENSATION_VOLTAGES: flatbuffers::VOffsetT = 10;
-  pub const VT_MASS_ANALYZER: flatbuffers::VOffsetT = 12;
-  pub const VT_SCAN_EVENT: flatbuffers::VOffsetT = 14;
-  pub const VT_IONIZATION_MODE: flatbuffers::VOffsetT = 16;
-  pub const VT_RESOLUTION: flatbuffers::VOffsetT = 18;
-
   #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    AcquisitionT { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args AcquisitionTArgs<'args>
-  ) -> flatbuffers::WIPOffset<AcquisitionT<'bldr>> {
-    let mut builder = AcquisitionTBuilder::new(_fbb);
-    builder.add_high_mz(args.high_mz);
-    builder.add_low_mz(args.low_mz);
-    if let Some(x) = args.resolution { builder.add_resolution(x); }
-    builder.add_scan_event(args.scan_event);
-    if let Some(x) = args.compensation_voltages { builder.add_compensation_voltages(x); }
-    builder.add_injection_time(args.injection_time);
-    builder.add_ionization_mode(args.ionization_mode);
-    builder.add_mass_analyzer(args.mass_analyzer);
-    builder.finish()
+  pub fn frequency(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_FREQUENCY, Some(0.0)).unwrap()}
   }
-
-
   #[inline]
-  pub fn low_mz(&self) -> f64 {
+  pub fn is_uniform_time(&self) -> bool {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f64>(AcquisitionT::VT_LOW_MZ, Some(0.0)).unwrap()}
+    unsafe { self._tab.get::<bool>(ScanStatisticsT::VT_IS_UNIFORM_TIME, Some(false)).unwrap()}
   }
   #[inline]
-  pub fn high_mz(&self) -> f64 {
+  pub fn is_centroid(&self) -> bool {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f64>(AcquisitionT::VT_HIGH_MZ, Some(0.0)).unwrap()}
+    unsafe { self._tab.get::<bool>(ScanStatisticsT::VT_IS_CENTROID, Some(false)).unwrap()}
   }
   #[inline]
-  pub fn injection_time(&self) -> f32 {
+  pub fn short_wavelength(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(AcquisitionT::VT_INJECTION_TIME, Some(0.0)).unwrap()}
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_SHORT_WAVELENGTH, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn compensation_voltages(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+  pub fn long_wavelength(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(AcquisitionT::VT_COMPENSATION_VOLTAGES, None)}
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_LONG_WAVELENGTH, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn mass_analyzer(&self) -> MassAnalyzer {
+  pub fn wavelength_step(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<MassAnalyzer>(AcquisitionT::VT_MASS_ANALYZER, Some(MassAnalyzer::FTMS)).unwrap()}
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_WAVELENGTH_STEP, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn scan_event(&self) -> i32 {
+  pub fn absorbance_unit_scale(&self) -> f64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<i32>(AcquisitionT::VT_SCAN_EVENT, Some(1)).unwrap()}
+    unsafe { self._tab.get::<f64>(ScanStatisticsT::VT_ABSORBANCE_UNIT_SCALE, Some(0.0)).unwrap()}
   }
   #[inline]
-  pub fn ionization_mode(&self) -> IonizationMode {
+  pub fn scan_type(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<IonizationMode>(AcquisitionT::VT_IONIZATION_MODE, Some(IonizationMode::NanoSpray)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScanStatisticsT::VT_SCAN_TYPE, None)}
   }
   #[inline]
-  pub fn resolution(&self) -> Option<f32> {
+  pub fn spectrum_count(&self) -> i32 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(AcquisitionT::VT_RESOLUTION, None)}
+    unsafe { self._tab.get::<i32>(ScanStatisticsT::VT_SPECTRUM_COUNT, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for AcquisitionT<'_> {
+impl flatbuffers::Verifiable for ScanStatisticsT<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<f64>("low_mz", Self::VT_LOW_MZ, false)?
-     .visit_field::<f64>("high_mz", Self::VT_HIGH_MZ, false)?
-     .visit_field::<f32>("injection_time", Self::VT_INJECTION_TIME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("compensation_voltages", Self::VT_COMPENSATION_VOLTAGES, false)?
-     .visit_field::<MassAnalyzer>("mass_analyzer", Self::VT_MASS_ANALYZER, false)?
-     .visit_field::<i32>("scan_event", Self::VT_SCAN_EVENT, false)?
-     .visit_field::<IonizationMode>("ionization_mode", Self::VT_IONIZATION_MODE, false)?
-     .visit_field::<f32>("resolution", Self::VT_RESOLUTION, false)?
+     .visit_field::<f64>("tic", Self::VT_TIC, false)?
+     .visit_field::<f64>("base_peak_mz", Self::VT_BASE_PEAK_MZ, false)?
+     .visit_field::<f64>("base_peak_intensity", Self::VT_BASE_PEAK_INTENSITY, false)?
+     .visit_field::<f64>("low_mass", Self::VT_LOW_MASS, false)?
+     .visit_field::<f64>("high_mass", Self::VT_HIGH_MASS, false)?
+     .visit_field::<i32>("packet_type", Self::VT_PACKET_TYPE, false)?
+     .visit_field::<i32>("packet_count", Self::VT_PACKET_COUNT, false)?
+     .visit_field::<i32>("segment_number", Self::VT_SEGMENT_NUMBER, false)?
+     .visit_field::<i32>("cycle_number", Self::VT_CYCLE_NUMBER, false)?
+     .visit_field::<i32>("scan_event_number", Self::VT_SCAN_EVENT_NUMBER, false)?
+     .visit_field::<i32>("number_of_channels", Self::VT_NUMBER_OF_CHANNELS, false)?
+     .visit_field::<f64>("frequency", Self::VT_FREQUENCY, false)?
+     .visit_field::<bool>("is_uniform_time", Self::VT_IS_UNIFORM_TIME, false)?
+     .visit_field::<bool>("is_centroid", Self::VT_IS_CENTROID, false)?
+     .visit_field::<f64>("short_wavelength", Self::VT_SHORT_WAVELENGTH, false)?
+     .visit_field::<f64>("long_wavelength", Self::VT_LONG_WAVELENGTH, false)?
+     .visit_field::<f64>("wavelength_step", Self::VT_WAVELENGTH_STEP, false)?
+     .visit_field::<f64>("absorbance_unit_scale", Self::VT_ABSORBANCE_UNIT_SCALE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("scan_type", Self::VT_SCAN_TYPE, false)?
+     .visit_field::<i32>("spectrum_count", Self::VT_SPECTRUM_COUNT, false)?
      .finish();
     Ok(())
   }
 }
-pub struct AcquisitionTArgs<'a> {
-    pub low_mz: f64,
-    pub high_mz: f64,
-    pub injection_time: f32,
-    pub compensation_voltages: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub mass_analyzer: MassAnalyzer,
-    pub scan_event: i32,
-    pub ionization_mode: IonizationMode,
-    pub resolution: Option<f32>,
-}
-impl<'a> Default for AcquisitionTArgs<'a> {
+pub struct ScanStatisticsTArgs<'a> {
+    pub tic: f64,
+    pub base_peak_mz: f64,
+    pub base_peak_intensity: f64,
+    pub low_mass: f64,
+    pub high_mass: f64,
+    pub packet_type: i32,
+    pub packet_count: i32,
+    pub segment_number: i32,
+    pub cycle_number: i32,
+    pub scan_event_number: i32,
+    pub number_of_channels: i32,
+    pub frequency: f64,
+    pub is_uniform_time: bool,
+    pub is_centroid: bool,
+    pub short_wavelength: f64,
+    pub long_wavelength: f64,
+    pub wavelength_step: f64,
+    pub absorbance_unit_scale: f64,
+    pub scan_type: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub spectrum_count: i32,
+}
+impl<'a> Default for ScanStatisticsTArgs<'a> {
   #[inline]
   fn default() -> Self {
-    AcquisitionTArgs {
-      low_mz: 0.0,
-      high_mz: 0.0,
-      injection_time: 0.0,
-      compensation_voltages: None,
-      mass_analyzer: MassAnalyzer::FTMS,
-      scan_event: 1,
-      ionization_mode: IonizationMode::NanoSpray,
-      resolution: None,
+    ScanStatisticsTArgs {
+      tic: 0.0,
+      base_peak_mz: 0.0,
+      base_peak_intensity: 0.0,
+      low_mass: 0.0,
+      high_mass: 0.0,
+      packet_type: 0,
+      packet_count: 0,
+      segment_number: 0,
+      cycle_number: 0,
+      scan_event_number: 0,
+      number_of_channels: 0,
+      frequency: 0.0,
+      is_uniform_time: false,
+      is_centroid: false,
+      short_wavelength: 0.0,
+      long_wavelength: 0.0,
+      wavelength_step: 0.0,
+      absorbance_unit_scale: 0.0,
+      scan_type: None,
+      spectrum_count: 0,
     }
   }
 }
 
-pub struct AcquisitionTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ScanStatisticsTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AcquisitionTBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScanStatisticsTBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_low_mz(&mut self, low_mz: f64) {
-    self.fbb_.push_slot::<f64>(AcquisitionT::VT_LOW_MZ, low_mz, 0.0);
+  pub fn add_tic(&mut self, tic: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_TIC, tic, 0.0);
   }
   #[inline]
-  pub fn add_high_mz(&mut self, high_mz: f64) {
-    self.fbb_.push_slot::<f64>(AcquisitionT::VT_HIGH_MZ, high_mz, 0.0);
+  pub fn add_base_peak_mz(&mut self, base_peak_mz: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_BASE_PEAK_MZ, base_peak_mz, 0.0);
   }
   #[inline]
-  pub fn add_injection_time(&mut self, injection_time: f32) {
-    self.fbb_.push_slot::<f32>(AcquisitionT::VT_INJECTION_TIME, injection_time, 0.0);
+  pub fn add_base_peak_intensity(&mut self, base_peak_intensity: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_BASE_PEAK_INTENSITY, base_peak_intensity, 0.0);
   }
   #[inline]
-  pub fn add_compensation_voltages(&mut self, compensation_voltages: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AcquisitionT::VT_COMPENSATION_VOLTAGES, compensation_voltages);
+  pub fn add_low_mass(&mut self, low_mass: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_LOW_MASS, low_mass, 0.0);
   }
   #[inline]
-  pub fn add_mass_analyzer(&mut self, mass_analyzer: MassAnalyzer) {
-    self.fbb_.push_slot::<MassAnalyzer>(AcquisitionT::VT_MASS_ANALYZER, mass_analyzer, MassAnalyzer::FTMS);
+  pub fn add_high_mass(&mut self, high_mass: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_HIGH_MASS, high_mass, 0.0);
   }
   #[inline]
-  pub fn add_scan_event(&mut self, scan_event: i32) {
-    self.fbb_.push_slot::<i32>(AcquisitionT::VT_SCAN_EVENT, scan_event, 1);
+  pub fn add_packet_type(&mut self, packet_type: i32) {
+    self.fbb_.push_slot::<i32>(ScanStatisticsT::VT_PACKET_TYPE, packet_type, 0);
   }
   #[inline]
-  pub fn add_ionization_mode(&mut self, ionization_mode: IonizationMode) {
-    self.fbb_.push_slot::<IonizationMode>(AcquisitionT::VT_IONIZATION_MODE, ionization_mode, IonizationMode::NanoSpray);
+  pub fn add_packet_count(&mut self, packet_count: i32) {
+    self.fbb_.push_slot::<i32>(ScanStatisticsT::VT_PACKET_COUNT, packet_count, 0);
   }
   #[inline]
-  pub fn add_resolution(&mut self, resolution: f32) {
-    self.fbb_.push_slot_always::<f32>(AcquisitionT::VT_RESOLUTION, resolution);
+  pub fn add_segment_number(&mut self, segment_number: i32) {
+    self.fbb_.push_slot::<i32>(ScanStatisticsT::VT_SEGMENT_NUMBER, segment_number, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AcquisitionTBuilder<'a, 'b, A> {
+  pub fn add_cycle_number(&mut self, cycle_number: i32) {
+    self.fbb_.push_slot::<i32>(ScanStatisticsT::VT_CYCLE_NUMBER, cycle_number, 0);
+  }
+  #[inline]
+  pub fn add_scan_event_number(&mut self, scan_event_number: i32) {
+    self.fbb_.push_slot::<i32>(ScanStatisticsT::VT_SCAN_EVENT_NUMBER, scan_event_number, 0);
+  }
+  #[inline]
+  pub fn add_number_of_channels(&mut self, number_of_channels: i32) {
+    self.fbb_.push_slot::<i32>(ScanStatisticsT::VT_NUMBER_OF_CHANNELS, number_of_channels, 0);
+  }
+  #[inline]
+  pub fn add_frequency(&mut self, frequency: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_FREQUENCY, frequency, 0.0);
+  }
+  #[inline]
+  pub fn add_is_uniform_time(&mut self, is_uniform_time: bool) {
+    self.fbb_.push_slot::<bool>(ScanStatisticsT::VT_IS_UNIFORM_TIME, is_uniform_time, false);
+  }
+  #[inline]
+  pub fn add_is_centroid(&mut self, is_centroid: bool) {
+    self.fbb_.push_slot::<bool>(ScanStatisticsT::VT_IS_CENTROID, is_centroid, false);
+  }
+  #[inline]
+  pub fn add_short_wavelength(&mut self, short_wavelength: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_SHORT_WAVELENGTH, short_wavelength, 0.0);
+  }
+  #[inline]
+  pub fn add_long_wavelength(&mut self, long_wavelength: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_LONG_WAVELENGTH, long_wavelength, 0.0);
+  }
+  #[inline]
+  pub fn add_wavelength_step(&mut self, wavelength_step: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_WAVELENGTH_STEP, wavelength_step, 0.0);
+  }
+  #[inline]
+  pub fn add_absorbance_unit_scale(&mut self, absorbance_unit_scale: f64) {
+    self.fbb_.push_slot::<f64>(ScanStatisticsT::VT_ABSORBANCE_UNIT_SCALE, absorbance_unit_scale, 0.0);
+  }
+  #[inline]
+  pub fn add_scan_type(&mut self, scan_type: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScanStatisticsT::VT_SCAN_TYPE, scan_type);
+  }
+  #[inline]
+  pub fn add_spectrum_count(&mut self, spectrum_count: i32) {
+    self.fbb_.push_slot::<i32>(ScanStatisticsT::VT_SPECTRUM_COUNT, spectrum_count, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScanStatisticsTBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    AcquisitionTBuilder {
+    ScanStatisticsTBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<AcquisitionT<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScanStatisticsT<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for AcquisitionT<'_> {
+impl core::fmt::Debug for ScanStatisticsT<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("AcquisitionT");
-      ds.field("low_mz", &self.low_mz());
-      ds.field("high_mz", &self.high_mz());
-      ds.field("injection_time", &self.injection_time());
-      ds.field("compensation_voltages", &self.compensation_voltages());
-      ds.field("mass_analyzer", &self.mass_analyzer());
-      ds.field("scan_event", &self.scan_event());
-      ds.field("ionization_mode", &self.ionization_mode());
-      ds.field("resolution", &self.resolution());
+    let mut ds = f.debug_struct("ScanStatisticsT");
+      ds.field("tic", &self.tic());
+      ds.field("base_peak_mz", &self.base_peak_mz());
+      ds.field("base_peak_intensity", &self.base_peak_intensity());
+      ds.field("low_mass", &self.low_mass());
+      ds.field("high_mass", &self.high_mass());
+      ds.field("packet_type", &self.packet_type());
+      ds.field("packet_count", &self.packet_count());
+      ds.field("segment_number", &self.segment_number());
+      ds.field("cycle_number", &self.cycle_number());
+      ds.field("scan_event_number", &self.scan_event_number());
+      ds.field("number_of_channels", &self.number_of_channels());
+      ds.field("frequency", &self.frequency());
+      ds.field("is_uniform_time", &self.is_uniform_time());
+      ds.field("is_centroid", &self.is_centroid());
+      ds.field("short_wavelength", &self.short_wavelength());
+      ds.field("long_wavelength", &self.long_wavelength());
+      ds.field("wavelength_step", &self.wavelength_step());
+      ds.field("absorbance_unit_scale", &self.absorbance_unit_scale());
+      ds.field("scan_type", &self.scan_type());
+      ds.field("spectrum_count", &self.spectrum_count());
       ds.finish()
   }
 }
@@ -3747,6 +7673,11 @@ impl<'a> SpectrumDescription<'a> {
   pub const VT_ACQUISITION: flatbuffers::VOffsetT = 20;
   pub const VT_MS_ORDER: flatbuffers::VOffsetT = 22;
   pub const VT_SCAN_MODE: flatbuffers::VOffsetT = 24;
+  pub const VT_CONTROLLER_NUMBER: flatbuffers::VOffsetT = 26;
+  pub const VT_SCAN_FILTER: flatbuffers::VOffsetT = 28;
+  pub const VT_STATISTICS: flatbuffers::VOffsetT = 30;
+  pub const VT_PRECURSORS: flatbuffers::VOffsetT = 32;
+  pub const VT_ACTIVATION_DETAILS: flatbuffers::VOffsetT = 34;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3759,6 +7690,11 @@ impl<'a> SpectrumDescription<'a> {
   ) -> flatbuffers::WIPOffset<SpectrumDescription<'bldr>> {
     let mut builder = SpectrumDescriptionBuilder::new(_fbb);
     builder.add_time(args.time);
+    if let Some(x) = args.activation_details { builder.add_activation_details(x); }
+    if let Some(x) = args.precursors { builder.add_precursors(x); }
+    if let Some(x) = args.statistics { builder.add_statistics(x); }
+    if let Some(x) = args.scan_filter { builder.add_scan_filter(x); }
+    builder.add_controller_number(args.controller_number);
     if let Some(x) = args.acquisition { builder.add_acquisition(x); }
     if let Some(x) = args.filter_string { builder.add_filter_string(x); }
     if let Some(x) = args.data { builder.add_data(x); }
@@ -3850,6 +7786,41 @@ impl<'a> SpectrumDescription<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<ScanMode>(SpectrumDescription::VT_SCAN_MODE, Some(ScanMode::Full)).unwrap()}
   }
+  #[inline]
+  pub fn controller_number(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(SpectrumDescription::VT_CONTROLLER_NUMBER, Some(1)).unwrap()}
+  }
+  #[inline]
+  pub fn scan_filter(&self) -> Option<ScanFilterT<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ScanFilterT>>(SpectrumDescription::VT_SCAN_FILTER, None)}
+  }
+  #[inline]
+  pub fn statistics(&self) -> Option<ScanStatisticsT<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ScanStatisticsT>>(SpectrumDescription::VT_STATISTICS, None)}
+  }
+  #[inline]
+  pub fn precursors(&self) -> Option<flatbuffers::Vector<'a, PrecursorT>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, PrecursorT>>>(SpectrumDescription::VT_PRECURSORS, None)}
+  }
+  #[inline]
+  pub fn activation_details(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ActivationDetailsT<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ActivationDetailsT>>>>(SpectrumDescription::VT_ACTIVATION_DETAILS, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for SpectrumDescription<'_> {
@@ -3870,6 +7841,11 @@ impl flatbuffers::Verifiable for SpectrumDescription<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<AcquisitionT>>("acquisition", Self::VT_ACQUISITION, false)?
      .visit_field::<MSOrder>("ms_order", Self::VT_MS_ORDER, false)?
      .visit_field::<ScanMode>("scan_mode", Self::VT_SCAN_MODE, false)?
+     .visit_field::<i32>("controller_number", Self::VT_CONTROLLER_NUMBER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ScanFilterT>>("scan_filter", Self::VT_SCAN_FILTER, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ScanStatisticsT>>("statistics", Self::VT_STATISTICS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, PrecursorT>>>("precursors", Self::VT_PRECURSORS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ActivationDetailsT>>>>("activation_details", Self::VT_ACTIVATION_DETAILS, false)?
      .finish();
     Ok(())
   }
@@ -3886,6 +7862,11 @@ pub struct SpectrumDescriptionArgs<'a> {
     pub acquisition: Option<flatbuffers::WIPOffset<AcquisitionT<'a>>>,
     pub ms_order: MSOrder,
     pub scan_mode: ScanMode,
+    pub controller_number: i32,
+    pub scan_filter: Option<flatbuffers::WIPOffset<ScanFilterT<'a>>>,
+    pub statistics: Option<flatbuffers::WIPOffset<ScanStatisticsT<'a>>>,
+    pub precursors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, PrecursorT>>>,
+    pub activation_details: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ActivationDetailsT<'a>>>>>,
 }
 impl<'a> Default for SpectrumDescriptionArgs<'a> {
   #[inline]
@@ -3902,6 +7883,11 @@ impl<'a> Default for SpectrumDescriptionArgs<'a> {
       acquisition: None,
       ms_order: MSOrder::Unknown,
       scan_mode: ScanMode::Full,
+      controller_number: 1,
+      scan_filter: None,
+      statistics: None,
+      precursors: None,
+      activation_details: None,
     }
   }
 }
@@ -3956,6 +7942,26 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpectrumDescriptionBuilder<'a,
     self.fbb_.push_slot::<ScanMode>(SpectrumDescription::VT_SCAN_MODE, scan_mode, ScanMode::Full);
   }
   #[inline]
+  pub fn add_controller_number(&mut self, controller_number: i32) {
+    self.fbb_.push_slot::<i32>(SpectrumDescription::VT_CONTROLLER_NUMBER, controller_number, 1);
+  }
+  #[inline]
+  pub fn add_scan_filter(&mut self, scan_filter: flatbuffers::WIPOffset<ScanFilterT<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ScanFilterT>>(SpectrumDescription::VT_SCAN_FILTER, scan_filter);
+  }
+  #[inline]
+  pub fn add_statistics(&mut self, statistics: flatbuffers::WIPOffset<ScanStatisticsT<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ScanStatisticsT>>(SpectrumDescription::VT_STATISTICS, statistics);
+  }
+  #[inline]
+  pub fn add_precursors(&mut self, precursors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , PrecursorT>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumDescription::VT_PRECURSORS, precursors);
+  }
+  #[inline]
+  pub fn add_activation_details(&mut self, activation_details: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ActivationDetailsT<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpectrumDescription::VT_ACTIVATION_DETAILS, activation_details);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SpectrumDescriptionBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     SpectrumDescriptionBuilder {
@@ -3984,6 +7990,11 @@ impl core::fmt::Debug for SpectrumDescription<'_> {
       ds.field("acquisition", &self.acquisition());
       ds.field("ms_order", &self.ms_order());
       ds.field("scan_mode", &self.scan_mode());
+      ds.field("controller_number", &self.controller_number());
+      ds.field("scan_filter", &self.scan_filter());
+      ds.field("statistics", &self.statistics());
+      ds.field("precursors", &self.precursors());
+      ds.field("activation_details", &self.activation_details());
       ds.finish()
   }
 }
@@ -4249,6 +8260,188 @@ impl core::fmt::Debug for ChromatogramDescription<'_> {
       ds.finish()
   }
 }
+pub enum WavelengthSpectrumTOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WavelengthSpectrumT<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WavelengthSpectrumT<'a> {
+  type Inner = WavelengthSpectrumT<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> WavelengthSpectrumT<'a> {
+  pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+  pub const VT_TIME: flatbuffers::VOffsetT = 6;
+  pub const VT_LOW_WAVELENGTH: flatbuffers::VOffsetT = 8;
+  pub const VT_HIGH_WAVELENGTH: flatbuffers::VOffsetT = 10;
+  pub const VT_WAVELENGTH: flatbuffers::VOffsetT = 12;
+  pub const VT_ABSORBANCE: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    WavelengthSpectrumT { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args WavelengthSpectrumTArgs<'args>
+  ) -> flatbuffers::WIPOffset<WavelengthSpectrumT<'bldr>> {
+    let mut builder = WavelengthSpectrumTBuilder::new(_fbb);
+    builder.add_high_wavelength(args.high_wavelength);
+    builder.add_low_wavelength(args.low_wavelength);
+    builder.add_time(args.time);
+    if let Some(x) = args.absorbance { builder.add_absorbance(x); }
+    if let Some(x) = args.wavelength { builder.add_wavelength(x); }
+    builder.add_index(args.index);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn index(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(WavelengthSpectrumT::VT_INDEX, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn time(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(WavelengthSpectrumT::VT_TIME, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn low_wavelength(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(WavelengthSpectrumT::VT_LOW_WAVELENGTH, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn high_wavelength(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(WavelengthSpectrumT::VT_HIGH_WAVELENGTH, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn wavelength(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(WavelengthSpectrumT::VT_WAVELENGTH, None)}
+  }
+  #[inline]
+  pub fn absorbance(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(WavelengthSpectrumT::VT_ABSORBANCE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for WavelengthSpectrumT<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("index", Self::VT_INDEX, false)?
+     .visit_field::<f64>("time", Self::VT_TIME, false)?
+     .visit_field::<f64>("low_wavelength", Self::VT_LOW_WAVELENGTH, false)?
+     .visit_field::<f64>("high_wavelength", Self::VT_HIGH_WAVELENGTH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("wavelength", Self::VT_WAVELENGTH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("absorbance", Self::VT_ABSORBANCE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct WavelengthSpectrumTArgs<'a> {
+    pub index: i32,
+    pub time: f64,
+    pub low_wavelength: f64,
+    pub high_wavelength: f64,
+    pub wavelength: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+    pub absorbance: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+}
+impl<'a> Default for WavelengthSpectrumTArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    WavelengthSpectrumTArgs {
+      index: 0,
+      time: 0.0,
+      low_wavelength: 0.0,
+      high_wavelength: 0.0,
+      wavelength: None,
+      absorbance: None,
+    }
+  }
+}
+
+pub struct WavelengthSpectrumTBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> WavelengthSpectrumTBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_index(&mut self, index: i32) {
+    self.fbb_.push_slot::<i32>(WavelengthSpectrumT::VT_INDEX, index, 0);
+  }
+  #[inline]
+  pub fn add_time(&mut self, time: f64) {
+    self.fbb_.push_slot::<f64>(WavelengthSpectrumT::VT_TIME, time, 0.0);
+  }
+  #[inline]
+  pub fn add_low_wavelength(&mut self, low_wavelength: f64) {
+    self.fbb_.push_slot::<f64>(WavelengthSpectrumT::VT_LOW_WAVELENGTH, low_wavelength, 0.0);
+  }
+  #[inline]
+  pub fn add_high_wavelength(&mut self, high_wavelength: f64) {
+    self.fbb_.push_slot::<f64>(WavelengthSpectrumT::VT_HIGH_WAVELENGTH, high_wavelength, 0.0);
+  }
+  #[inline]
+  pub fn add_wavelength(&mut self, wavelength: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(WavelengthSpectrumT::VT_WAVELENGTH, wavelength);
+  }
+  #[inline]
+  pub fn add_absorbance(&mut self, absorbance: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(WavelengthSpectrumT::VT_ABSORBANCE, absorbance);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> WavelengthSpectrumTBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    WavelengthSpectrumTBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<WavelengthSpectrumT<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for WavelengthSpectrumT<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("WavelengthSpectrumT");
+      ds.field("index", &self.index());
+      ds.field("time", &self.time());
+      ds.field("low_wavelength", &self.low_wavelength());
+      ds.field("high_wavelength", &self.high_wavelength());
+      ds.field("wavelength", &self.wavelength());
+      ds.field("absorbance", &self.absorbance());
+      ds.finish()
+  }
+}
 #[inline]
 /// Verifies that a buffer of bytes contains a `SpectrumDescription`
 /// and returns it.
@@ -21,6 +21,7 @@
 //! # Licensing
 //! By using this library, you agree to the [RawFileReader License](https://github.com/thermofisherlsms/RawFileReader/blob/main/License.doc)
 mod constants;
+mod managed;
 pub(crate) mod r#gen;
 pub(crate) mod wrap;
 
@@ -30,9 +31,12 @@ pub use crate::wrap::{
     ChromatogramData, ChromatogramDescription, FileDescription, InstrumentConfiguration,
     InstrumentMethod, InstrumentModel, RawFileReader, RawFileReaderError, RawFileReaderIntoIter,
     RawFileReaderIter, RawSpectrum, SpectrumData, Acquisition, ExtendedSpectrumData,
-    StatusLogCollection, StatusLog, TrailerValue, TrailerValues, OwnedSpectrumData,
+    StatusLogCollection, StatusLog, ErrorLog, TrailerValue, TrailerValues, TrailerColumn, OwnedSpectrumData, Tolerance,
+    WavelengthSpectrum, ScanFilter, ScanFilterGroup, ScanFilterGroups, TuneData, TuneDataSegment,
+    TypedTrailerValue, TypedTrailerValues, TrailerHeader, TrailerHeaders, ActivationDetails,
+    AcquisitionTree, Precursor,
 };
-pub use constants::{IonizationMode, MassAnalyzer, TraceType, MSOrder};
+pub use constants::{IonizationMode, MassAnalyzer, TraceType, MSOrder, DeviceKind, PeakFlags};
 
 #[doc(alias = "Re-exported from `dotnetrawfilereader_sys`")]
 pub use dotnetrawfilereader_sys::{DotNetRuntimeCreationError, set_runtime_dir, try_get_runtime};
\ No newline at end of file
@@ -0,0 +1,101 @@
+use std::ffi::c_void;
+use std::sync::OnceLock;
+
+use netcorehost::hostfxr::{AssemblyDelegateLoader, GetManagedFunctionError, ManagedFunction};
+use netcorehost::pdcstr;
+
+use dotnetrawfilereader_sys::{DotNetRuntimeCreationError, RawVec};
+
+use crate::wrap::RawFileReaderError;
+
+/// Declare the table of functions exported by `librawfilereader.Exports`, each resolved by
+/// name when the table is loaded.
+macro_rules! managed_functions {
+    ($($field:ident = $name:tt: fn($($arg:ty),* $(,)?) $(-> $ret:ty)?;)*) => {
+        /// The .NET functions `librawfilereader` exports, resolved once per runtime.
+        ///
+        /// Resolving a function by name goes through the .NET runtime's reflection machinery,
+        /// which is far more expensive than calling it, so this is done once for every export
+        /// and shared by all [`RawFileReader`](crate::RawFileReader) instances.
+        pub(crate) struct ManagedFunctions {
+            $(pub(crate) $field: ManagedFunction<extern "system" fn($($arg),*) $(-> $ret)?>,)*
+        }
+
+        impl ManagedFunctions {
+            fn load(context: &AssemblyDelegateLoader) -> Result<Self, RawFileReaderError> {
+                Ok(Self {
+                    $($field: context
+                        .get_function_with_unmanaged_callers_only::<fn($($arg),*) $(-> $ret)?>(
+                            pdcstr!("librawfilereader.Exports, librawfilereader"),
+                            pdcstr!($name),
+                        )
+                        .map_err(|e| load_error($name, e))?,)*
+                })
+            }
+        }
+    };
+}
+
+managed_functions! {
+    open_with_options = "OpenWithOptions": fn(*const u8, i32, i32, u32) -> *mut c_void;
+    close = "Close": fn(*mut c_void);
+    status = "Status": fn(*mut c_void) -> u32;
+    error_message_for = "GetErrorMessageFor": fn(*mut c_void) -> RawVec<u8>;
+    spectrum_count = "SpectrumCount": fn(*mut c_void) -> i32;
+    first_spectrum = "FirstSpectrum": fn(*mut c_void) -> i32;
+    last_spectrum = "LastSpectrum": fn(*mut c_void) -> i32;
+    spectrum_description_for_with_options = "SpectrumDescriptionForWithOptions": fn(*mut c_void, i32, i32, i32) -> RawVec<u8>;
+    spectrum_descriptions_for = "SpectrumDescriptionsFor": fn(*mut c_void, *const i32, i32, i32, i32) -> RawVec<u8>;
+    spectrum_data_indirect = "GetSpectrumDataIndirect": fn(*mut c_void, i32, i32, *mut RawVec<u8>, *mut RawVec<u8>, *mut RawVec<u8>) -> u32;
+    advanced_packet_data_for = "AdvancedPacketDataFor": fn(*mut c_void, i32, i32) -> RawVec<u8>;
+    set_precursor_intensity_options = "SetPrecursorIntensityOptions": fn(*mut c_void, f64, u32, u32);
+    instrument_model = "InstrumentModel": fn(*mut c_void) -> RawVec<u8>;
+    file_description = "FileDescription": fn(*mut c_void) -> RawVec<u8>;
+    spectra_per_ms_level = "SpectraPerMSLevel": fn(*mut c_void, *mut u32, i32);
+    instrument_method = "InstrumentMethod": fn(*mut c_void, i32) -> RawVec<u8>;
+    instrument_method_count = "InstrumentMethodCount": fn(*mut c_void) -> u32;
+    tic = "GetTIC": fn(*mut c_void) -> RawVec<u8>;
+    bpc = "GetBPC": fn(*mut c_void) -> RawVec<u8>;
+    summary_trace = "GetSummaryTrace": fn(*mut c_void, i32, *const u8, i32, f64, f64) -> RawVec<u8>;
+    mass_range_traces = "GetMassRangeTraces": fn(*mut c_void, *const f64, i32, *const u8, i32, f64, f64, *mut RawVec<u8>);
+    scan_number_for_time = "ScanNumberForTime": fn(*mut c_void, f64) -> i32;
+    scans_in_time_range = "ScansInTimeRange": fn(*mut c_void, f64, f64, *mut i32, *mut i32);
+    device_count = "DeviceCount": fn(*mut c_void, i32) -> i32;
+    device_spectrum_count = "DeviceSpectrumCount": fn(*mut c_void, i32, i32) -> i32;
+    wavelength_spectrum_for = "WavelengthSpectrumFor": fn(*mut c_void, i32, i32, i32) -> RawVec<u8>;
+    device_trace = "GetDeviceTrace": fn(*mut c_void, i32, i32, i32, f64, f64, f64, f64) -> RawVec<u8>;
+    raw_trailer_values_for = "GetRawTrailerValuesFor": fn(*mut c_void, i32) -> RawVec<u8>;
+    typed_trailer_values_for = "GetTypedTrailerValuesFor": fn(*mut c_void, i32) -> RawVec<u8>;
+    trailer_column = "GetTrailerColumn": fn(*mut c_void, *const u8, i32, i32, i32) -> RawVec<u8>;
+    trailer_headers = "GetTrailerHeaders": fn(*mut c_void) -> RawVec<u8>;
+    tune_data = "GetTuneData": fn(*mut c_void) -> RawVec<u8>;
+    error_log = "GetErrorLog": fn(*mut c_void) -> RawVec<u8>;
+    status_logs = "GetStatusLogs": fn(*mut c_void) -> RawVec<u8>;
+    unique_filters = "GetUniqueFilters": fn(*mut c_void) -> RawVec<u8>;
+    acquisition_tree = "GetAcquisitionTree": fn(*mut c_void) -> RawVec<u8>;
+    take_last_error = "TakeLastError": fn(*mut u32) -> RawVec<u8>;
+}
+
+static MANAGED_FUNCTIONS: OnceLock<ManagedFunctions> = OnceLock::new();
+
+/// Get the shared function table, resolving every export from `context` on first use.
+///
+/// There is only ever one .NET runtime per process, so one table serves every reader.
+pub(crate) fn managed_functions(
+    context: &AssemblyDelegateLoader,
+) -> Result<&'static ManagedFunctions, RawFileReaderError> {
+    if let Some(functions) = MANAGED_FUNCTIONS.get() {
+        return Ok(functions);
+    }
+    let functions = ManagedFunctions::load(context)?;
+    Ok(MANAGED_FUNCTIONS.get_or_init(|| functions))
+}
+
+fn load_error(name: &str, error: GetManagedFunctionError) -> RawFileReaderError {
+    match error {
+        GetManagedFunctionError::Hosting(e) => {
+            RawFileReaderError::RuntimeUnavailable(DotNetRuntimeCreationError::HostingError(e))
+        }
+        e => RawFileReaderError::Error(format!("Failed to load {name} from librawfilereader: {e}")),
+    }
+}
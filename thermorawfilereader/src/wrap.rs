@@ -2,24 +2,31 @@ use std::borrow::Cow;
 use std::ffi::c_void;
 use std::fmt::{Debug, Display};
 use std::iter::{FusedIterator, ExactSizeIterator};
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use std::{io, ptr};
-use std::ops::Deref;
-
-use netcorehost::hostfxr::ManagedFunction;
-use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+use std::ops::{Deref, Range};
 
 use flatbuffers::{root, root_unchecked, Vector};
 
-use dotnetrawfilereader_sys::{try_get_runtime, RawVec};
+use dotnetrawfilereader_sys::{try_get_runtime, DotNetRuntimeCreationError, RawVec};
 
-use crate::constants::{IonizationMode, MSOrder, MassAnalyzer, ScanMode, TraceType};
+use crate::constants::{DeviceKind, IonizationMode, MSOrder, MassAnalyzer, PeakFlags, ScanMode, TraceType};
+use crate::managed::{managed_functions, ManagedFunctions};
 use crate::schema::{
-    root_as_spectrum_description, root_as_spectrum_description_unchecked, AcquisitionT,
-    ChromatogramDescription as ChromatogramDescriptionT, ExtendedSpectrumDataT, FileDescriptionT,
-    InstrumentMethodT, InstrumentModelT, Polarity, PrecursorT, SpectrumData as SpectrumDataT,
-    SpectrumDescription, SpectrumMode, StatusLogCollectionT, TrailerValuesT,
+    root_as_spectrum_description, root_as_spectrum_description_unchecked,
+    size_prefixed_root_as_spectrum_description, size_prefixed_root_as_spectrum_description_unchecked, AcquisitionT,
+    AcquisitionTreeT, ActivationDetailsT, ActivationStepT, BarcodeStatus,
+    ChromatogramDescription as ChromatogramDescriptionT, ErrorLogT,
+    ExtendedSpectrumDataT, FileDescriptionT, InstrumentMethodT, InstrumentModelT, Polarity,
+    PrecursorT, RunHeaderT, SampleType, ScanFilterGroupCollectionT,
+    ScanFilterGroupT, ScanFilterReactionT, ScanFilterT,
+    ScanStatisticsT,
+    SpectrumData as SpectrumDataT,
+    SpectrumDescription, SpectrumMode, StatusLogCollectionT, TrailerColumnT,
+    TrailerDataType, TrailerHeaderCollectionT, TrailerValuesT, TuneDataSegmentT, TuneDataT, TypedTrailerValueT,
+    TypedTrailerValuesT, WavelengthSpectrumT,
 };
 
 macro_rules! view_proxy {
@@ -67,38 +74,102 @@ macro_rules! view_proxy {
     };
 }
 
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-/// A set of error codes to describe how creating and using a `RawFileReader` might fail (or not).
+/// The ways creating and using a [`RawFileReader`] might fail.
+#[derive(Debug)]
 pub enum RawFileReaderError {
-    /// No problem, move along
-    Ok = 0,
     /// The file path given doesn't exist
-    FileNotFound,
-    /// The file path given does exist, but it's not a Thermo RAW file
-    InvalidFormat,
+    FileNotFound(PathBuf),
+    /// The file path given does exist, but it's not a Thermo RAW file. Holds the file error
+    /// message reported by the Thermo library, if any
+    InvalidFormat(String),
     /// The handle provided doesn't exist, someone is doing something odd like making a new [`RawFileReader`]
     /// somehow other than [`RawFileReader::open`]
     HandleNotFound,
+    /// The requested MS controller doesn't exist in the file
+    ControllerNotFound(usize),
+    /// The requested device doesn't exist in the file
+    DeviceNotFound(DeviceKind, usize),
+    /// The [`RawFileReader`]'s handle has already been closed
+    HandleClosed,
+    /// The requested index is past the end of the spectra in the file
+    IndexOutOfRange { index: usize, len: usize },
+    /// The .NET library threw an exception, holding its type and message
+    DotNetException(String),
+    /// The .NET library returned a buffer that could not be decoded
+    MalformedBuffer(String),
+    /// The .NET runtime could not be created
+    RuntimeUnavailable(DotNetRuntimeCreationError),
     /// Some other error occurred
-    Error = 999,
+    Error(String),
 }
 
 impl Display for RawFileReaderError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::FileNotFound(path) => write!(f, "File not found: {}", path.display()),
+            Self::InvalidFormat(message) => {
+                write!(f, "File does not appear to be a valid RAW file. {message}")
+            }
+            Self::HandleNotFound => write!(f, "RAW file handle not found"),
+            Self::ControllerNotFound(index) => write!(f, "MS controller {index} not found"),
+            Self::DeviceNotFound(device, index) => write!(f, "{device:?} device {index} not found"),
+            Self::HandleClosed => write!(f, "RAW file handle already closed"),
+            Self::IndexOutOfRange { index, len } => {
+                write!(f, "Index {index} out of range for {len} spectra")
+            }
+            Self::DotNetException(message) => write!(f, "A .NET exception occurred: {message}"),
+            Self::MalformedBuffer(what) => write!(f, "Failed to decode {what} buffer"),
+            Self::RuntimeUnavailable(e) => write!(f, "{e}"),
+            Self::Error(message) => write!(f, "An unknown error occurred {message}"),
+        }
+    }
+}
+
+impl std::error::Error for RawFileReaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::RuntimeUnavailable(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<DotNetRuntimeCreationError> for RawFileReaderError {
+    fn from(value: DotNetRuntimeCreationError) -> Self {
+        Self::RuntimeUnavailable(value)
+    }
+}
+
+impl From<RawFileReaderError> for io::Error {
+    fn from(value: RawFileReaderError) -> Self {
+        let kind = match &value {
+            RawFileReaderError::FileNotFound(_)
+            | RawFileReaderError::ControllerNotFound(_)
+            | RawFileReaderError::DeviceNotFound(_, _) => io::ErrorKind::NotFound,
+            RawFileReaderError::InvalidFormat(_) | RawFileReaderError::MalformedBuffer(_) => {
+                io::ErrorKind::InvalidData
+            }
+            RawFileReaderError::IndexOutOfRange { .. } => io::ErrorKind::InvalidInput,
+            RawFileReaderError::RuntimeUnavailable(DotNetRuntimeCreationError::IOError(e)) => e.kind(),
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, value)
     }
 }
 
-impl std::error::Error for RawFileReaderError {}
+/// The memory holding a [`RawSpectrum`]'s `SpectrumDescription` message
+enum SpectrumBuffer {
+    /// A buffer holding just this spectrum's message
+    Owned(RawVec<u8>),
+    /// The size-prefixed message at `range` in a buffer shared by a batch of spectra
+    Shared(Arc<RawVec<u8>>, Range<usize>),
+}
 
-impl From<u32> for RawFileReaderError {
-    fn from(value: u32) -> Self {
-        match value {
-            0 => Self::Ok,
-            1 => Self::FileNotFound,
-            2 => Self::InvalidFormat,
-            _ => Self::Error,
+impl SpectrumBuffer {
+    fn len(&self) -> usize {
+        match self {
+            Self::Owned(data) => data.len(),
+            Self::Shared(_, range) => range.len(),
         }
     }
 }
@@ -107,7 +178,7 @@ impl From<u32> for RawFileReaderError {
 /// A wrapper around the `SpectrumDescription` FlatBuffer schema. It mirrors the data
 /// stored there-in.
 pub struct RawSpectrum {
-    data: RawVec<u8>,
+    data: SpectrumBuffer,
 }
 
 impl Debug for RawSpectrum {
@@ -121,27 +192,50 @@ impl Debug for RawSpectrum {
 impl RawSpectrum {
     /// Create a new [`RawSpectrum`] by wrapping an owning memory buffer
     pub fn new(data: RawVec<u8>) -> Self {
-        Self { data }
+        Self { data: SpectrumBuffer::Owned(data) }
+    }
+
+    /// Create a new [`RawSpectrum`] from the size-prefixed message at `range` in a buffer
+    /// shared with other spectra
+    pub(crate) fn shared(buffer: Arc<RawVec<u8>>, range: Range<usize>) -> Self {
+        Self { data: SpectrumBuffer::Shared(buffer, range) }
     }
 
     /// Check that the buffer is a valid `SpectrumDescription`
     pub fn check(&self) -> bool {
-        root_as_spectrum_description(&self.data).is_ok()
+        match &self.data {
+            SpectrumBuffer::Owned(data) => root_as_spectrum_description(data).is_ok(),
+            SpectrumBuffer::Shared(buffer, range) => {
+                size_prefixed_root_as_spectrum_description(&buffer[range.clone()]).is_ok()
+            }
+        }
     }
 
     /// View the underlying buffer as a `SpectrumDescription`
     pub fn view(&self) -> SpectrumDescription<'_> {
-        unsafe { root_as_spectrum_description_unchecked(&self.data) }
+        match &self.data {
+            SpectrumBuffer::Owned(data) => unsafe { root_as_spectrum_description_unchecked(data) },
+            SpectrumBuffer::Shared(buffer, range) => unsafe {
+                size_prefixed_root_as_spectrum_description_unchecked(&buffer[range.clone()])
+            },
+        }
     }
 
     /// Generate the "native ID" string format for the spectrum
     pub fn native_id(&self) -> String {
         format!(
-            "controllerType=0 controllerNumber=1 scan={}",
+            "controllerType=0 controllerNumber={} scan={}",
+            self.controller_number(),
             self.index() + 1
         )
     }
 
+    /// The 1-based number of the MS controller that acquired the spectrum
+    #[inline]
+    pub fn controller_number(&self) -> i32 {
+        self.view().controller_number()
+    }
+
     /// The 0-base index of the spectrum
     pub fn index(&self) -> usize {
         self.view().index() as usize
@@ -173,6 +267,26 @@ impl RawSpectrum {
         self.view().precursor()
     }
 
+    /// Every precursor isolated for the spectrum, each with its own isolation window and activation.
+    ///
+    /// Multiplexed (MSX) spectra carry one precursor per multiplexed reaction, and SPS spectra carry one
+    /// precursor per synchronous precursor selection notch with the notch m/z and no charge state.
+    /// Otherwise this yields just [`RawSpectrum::precursor`]. MS1 spectra have no precursors.
+    pub fn precursors(&self) -> impl Iterator<Item = Precursor<'_>> + '_ {
+        let view = self.view();
+        let details = view.activation_details();
+        view.precursors()
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(move |(i, precursor)| {
+                let activation_details = details
+                    .filter(|d| i < d.len())
+                    .map(|d| ActivationDetails::new(d.get(i)));
+                Precursor::new(precursor, activation_details)
+            })
+    }
+
     pub fn ms_order(&self) -> MSOrder {
         MSOrder::from(self.view().ms_order().0)
     }
@@ -203,6 +317,8 @@ impl RawSpectrum {
                     return Some(SpectrumData {
                         mz: m,
                         intensity: i,
+                        flags: d.flags(),
+                        segment_offsets: d.segment_offsets(),
                     });
                 }
             }
@@ -223,6 +339,41 @@ impl RawSpectrum {
     pub fn acquisition(&self) -> Option<AcquisitionT<'_>> {
         self.view().acquisition()
     }
+
+    /// Get Thermo's summary statistics for the spectrum, including the total ion current,
+    /// base peak, and packet type, segment and cycle numbers.
+    ///
+    /// These are available even when signal loading is disabled with [`RawFileReader::set_signal_loading`].
+    /// This is a raw FlatBuffer struct. See the [schema](https://github.com/mobiusklein/thermorawfilereader.rs/blob/main/schema/schema.fbs) for more details.
+    pub fn statistics(&self) -> Option<ScanStatisticsT<'_>> {
+        self.view().statistics()
+    }
+
+    /// The total ion current of the spectrum
+    pub fn tic(&self) -> Option<f64> {
+        self.statistics().map(|s| s.tic())
+    }
+
+    /// The m/z of the most intense peak in the spectrum
+    pub fn base_peak_mz(&self) -> Option<f64> {
+        self.statistics().map(|s| s.base_peak_mz())
+    }
+
+    /// The intensity of the most intense peak in the spectrum
+    pub fn base_peak_intensity(&self) -> Option<f64> {
+        self.statistics().map(|s| s.base_peak_intensity())
+    }
+
+    /// The number of spectra recorded by the MS controller that acquired the spectrum
+    pub fn spectrum_count(&self) -> Option<usize> {
+        self.statistics().map(|s| s.spectrum_count().max(0) as usize)
+    }
+
+    /// Get the structured form of the spectrum's scan filter, a typed alternative to
+    /// parsing [`RawSpectrum::filter_string`].
+    pub fn scan_filter(&self) -> Option<ScanFilter<'_>> {
+        self.view().scan_filter().map(ScanFilter::new)
+    }
 }
 
 /// A [`SpectrumData`]-like type that instead of using FlatBuffers uses
@@ -237,12 +388,13 @@ impl RawSpectrum {
 pub struct OwnedSpectrumData {
     mz_array: RawVec<u8>,
     intensity_array: RawVec<u8>,
+    flags_array: RawVec<u8>,
     size: usize
 }
 
 impl OwnedSpectrumData {
-    pub(crate) fn new(mz_array: RawVec<u8>, intensity_array: RawVec<u8>, size: usize) -> Self {
-        Self { mz_array, intensity_array, size }
+    pub(crate) fn new(mz_array: RawVec<u8>, intensity_array: RawVec<u8>, flags_array: RawVec<u8>, size: usize) -> Self {
+        Self { mz_array, intensity_array, flags_array, size }
     }
 
     /// Get the number of values in the parallel arrays
@@ -331,6 +483,18 @@ impl OwnedSpectrumData {
         let values: Vec<_> = vs.iter().map(|v| f32::from_le_bytes(*v)).collect();
         Cow::Owned(values)
     }
+
+    /// Get the per-peak label flags, parallel to the m/z and intensity arrays.
+    ///
+    /// These are only available when reading centroids from a profile spectrum's
+    /// label stream, otherwise this will be [`None`].
+    pub fn flags(&self) -> Option<&[PeakFlags]> {
+        let d = self.flags_array.deref();
+        if d.is_empty() {
+            return None
+        }
+        Some(bytemuck::cast_slice(d))
+    }
 }
 
 /// A sub-set of a [`RawSpectrum`] corresponding to the m/z and intensity arrays
@@ -340,6 +504,8 @@ impl OwnedSpectrumData {
 pub struct SpectrumData<'a> {
     mz: Vector<'a, f64>,
     intensity: Vector<'a, f32>,
+    flags: Option<Vector<'a, u8>>,
+    segment_offsets: Option<Vector<'a, u32>>,
 }
 
 impl<'a> SpectrumData<'a> {
@@ -359,6 +525,47 @@ impl<'a> SpectrumData<'a> {
         Cow::Borrowed(bytemuck::cast_slice(self.intensity.bytes()))
     }
 
+    /// The per-peak label flags of the spectrum, parallel to [`SpectrumData::mz`].
+    ///
+    /// These are only available when the spectrum was centroided from a profile
+    /// spectrum's label stream, otherwise this will be [`None`].
+    pub fn flags(&self) -> Option<&'a [PeakFlags]> {
+        self.flags
+            .filter(|f| !f.is_empty())
+            .map(|f| bytemuck::cast_slice(f.bytes()))
+    }
+
+    /// The index of the first point of each scan segment in the data arrays.
+    ///
+    /// These are only available for profile spectra with more than one scan segment.
+    /// Otherwise, including for all centroid spectra, this will be [`None`].
+    pub fn segment_offsets(&self) -> Option<Cow<'a, [u32]>> {
+        let data = self.segment_offsets.filter(|v| !v.is_empty())?;
+        #[cfg(target_endian = "big")]
+        return Some(Cow::Owned(data.iter().collect()));
+        #[cfg(target_endian = "little")]
+        Some(Cow::Borrowed(bytemuck::cast_slice(data.bytes())))
+    }
+
+    /// The index range of each scan segment in the data arrays, in the same order as
+    /// [`Acquisition::scan_windows`].
+    ///
+    /// When segment boundaries are not known, the whole spectrum is treated as a single segment.
+    pub fn segments(&self) -> Vec<Range<usize>> {
+        let n = self.len();
+        match self.segment_offsets() {
+            Some(offsets) => {
+                let mut ranges: Vec<Range<usize>> = Vec::with_capacity(offsets.len());
+                for (i, start) in offsets.iter().enumerate() {
+                    let end = offsets.get(i + 1).map(|v| *v as usize).unwrap_or(n);
+                    ranges.push((*start as usize)..end);
+                }
+                ranges
+            }
+            None => std::iter::once(0..n).collect(),
+        }
+    }
+
     pub fn iter(
         &self,
     ) -> std::iter::Zip<flatbuffers::VectorIter<'a, f64>, flatbuffers::VectorIter<'a, f32>> {
@@ -471,207 +678,630 @@ impl TrailerValues {
     }
 }
 
-pub struct ExtendedSpectrumData {
-    data: RawVec<u8>,
+/// A trailer extra value converted to the Rust type matching the data type its
+/// header declares. See [`RawFileReader::trailer_header_schema`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedTrailerValue {
+    /// A `Float` or `Double` value
+    Float(f64),
+    /// Any signed or unsigned integer value, including the single byte `Char` and `UChar` types
+    Int(i64),
+    /// A true/false, yes/no or on/off value
+    Bool(bool),
+    /// A string value
+    String(String),
+    /// A value that was missing or could not be converted to its declared type
+    Null,
 }
 
-impl ExtendedSpectrumData {
-    pub fn new(data: RawVec<u8>) -> Self {
-        Self { data }
+impl TypedTrailerValue {
+    fn from_parts(
+        data_type: TrailerDataType,
+        float_value: f64,
+        int_value: i64,
+        string_value: Option<&str>,
+    ) -> Self {
+        match data_type {
+            TrailerDataType::Float | TrailerDataType::Double => Self::Float(float_value),
+            TrailerDataType::TrueFalse | TrailerDataType::YesNo | TrailerDataType::OnOff => {
+                Self::Bool(int_value != 0)
+            }
+            TrailerDataType::Char
+            | TrailerDataType::UChar
+            | TrailerDataType::Short
+            | TrailerDataType::UShort
+            | TrailerDataType::Long
+            | TrailerDataType::ULong => Self::Int(int_value),
+            TrailerDataType::CharString | TrailerDataType::WCharString => {
+                Self::String(string_value.unwrap_or_default().to_string())
+            }
+            _ => Self::Null,
+        }
     }
 
-    /// Check that the buffer is a valid `ExtendedSpectrumData`
-    pub fn check(&self) -> bool {
-        root::<ExtendedSpectrumDataT>(&self.data).is_ok()
+    fn from_view(view: TypedTrailerValueT<'_>) -> Self {
+        Self::from_parts(
+            view.data_type(),
+            view.float_value(),
+            view.int_value(),
+            view.string_value(),
+        )
     }
 
-    /// View the underlying buffer as a `ExtendedSpectrumData`
-    pub fn view(&self) -> ExtendedSpectrumDataT<'_> {
-        unsafe { root_unchecked::<ExtendedSpectrumDataT>(&self.data) }
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(v) => Some(*v),
+            Self::Int(v) => Some(*v as f64),
+            _ => None,
+        }
     }
 
-    view_proxy!(
-        noise,
-        "Access the peak-local noise array, if available",
-        Option<Cow<'_, [f32]>>,
-        optcast
-    );
-
-    view_proxy!(
-        baseline,
-        "Access the baseline signal array, if available",
-        Option<Cow<'_, [f32]>>,
-        optcast
-    );
-
-    view_proxy!(
-        charge,
-        "Access the peak charge array, if available",
-        Option<Cow<'_, [f32]>>,
-        optcast
-    );
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
 
-    view_proxy!(
-        resolution,
-        "Access the peak resolution array, if available",
-        Option<Cow<'_, [f32]>>,
-        optcast
-    );
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
 
-    view_proxy!(
-        sampled_noise,
-        "Access the sampled noise array, if available",
-        Option<Cow<'_, [f32]>>,
-        optcast
-    );
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
 
-    view_proxy!(
-        sampled_noise_baseline,
-        "Access the sampled noise baseline array, if available",
-        Option<Cow<'_, [f32]>>,
-        optcast
-    );
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
 
-    view_proxy!(
-        sampled_noise_mz,
-        "Access the sampled noise m/z array, if available",
-        Option<Cow<'_, [f32]>>,
-        optcast
-    );
+/// The trailer extra values for a single scan, converted to the types their headers declare.
+pub struct TypedTrailerValues {
+    data: RawVec<u8>,
 }
 
-/// The signal trace of a chromatogram
-pub struct ChromatogramData<'a> {
-    time: Vector<'a, f64>,
-    intensity: Vector<'a, f32>,
+impl Debug for TypedTrailerValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let items: Vec<_> = self.iter().collect();
+        f.debug_struct("TypedTrailerValues")
+            .field("data-size", &self.data.len())
+            .field("entries", &items)
+            .finish()
+    }
 }
 
-impl<'a> ChromatogramData<'a> {
-    /// The time array of the spectrum, in minutes
-    pub fn time(&self) -> Cow<'a, [f64]> {
-        #[cfg(target_endian = "big")]
-        return Cow::Owned(self.time.iter().copied().collect());
-        #[cfg(target_endian = "little")]
-        Cow::Borrowed(bytemuck::cast_slice(self.time.bytes()))
+impl TypedTrailerValues {
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
     }
 
-    /// The intensity array of the spectrum
-    pub fn intensity(&self) -> Cow<'a, [f32]> {
-        #[cfg(target_endian = "big")]
-        return Cow::Owned(self.intensity.iter().copied().collect());
-        #[cfg(target_endian = "little")]
-        Cow::Borrowed(bytemuck::cast_slice(self.intensity.bytes()))
+    /// Check that the buffer is a valid `TypedTrailerValuesT`
+    pub fn check(&self) -> bool {
+        root::<TypedTrailerValuesT>(&self.data).is_ok()
     }
 
-    /// Iterate over time-intensity pairs
-    pub fn iter(
-        &self,
-    ) -> std::iter::Zip<flatbuffers::VectorIter<'a, f64>, flatbuffers::VectorIter<'a, f32>> {
-        let it = self.time.iter().zip(self.intensity.iter());
-        it
+    /// View the underlying buffer as a `TypedTrailerValuesT`
+    pub fn view(&self) -> TypedTrailerValuesT<'_> {
+        unsafe { root_unchecked::<TypedTrailerValuesT>(&self.data) }
     }
 
     pub fn len(&self) -> usize {
-        self.time.len()
+        self.view().trailers().map(|v| v.len()).unwrap_or_default()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.time.is_empty()
+        self.len() == 0
     }
-}
 
-impl<'a> IntoIterator for ChromatogramData<'a> {
-    type Item = (f64, f32);
+    /// Iterate over the label and value of each trailer
+    pub fn iter(&self) -> impl Iterator<Item = (&str, TypedTrailerValue)> + '_ {
+        self.view()
+            .trailers()
+            .into_iter()
+            .flatten()
+            .map(|i| (i.label().unwrap_or_default(), TypedTrailerValue::from_view(i)))
+    }
 
-    type IntoIter =
-        std::iter::Zip<flatbuffers::VectorIter<'a, f64>, flatbuffers::VectorIter<'a, f32>>;
+    pub fn get(&self, index: usize) -> Option<(&str, TypedTrailerValue)> {
+        let trailers = self.view().trailers()?;
+        if index >= trailers.len() {
+            None
+        } else {
+            let i = trailers.get(index);
+            Some((i.label().unwrap_or_default(), TypedTrailerValue::from_view(i)))
+        }
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    pub fn get_label(&self, label: &str) -> Option<TypedTrailerValue> {
+        self.iter().find(|(l, _)| *l == label).map(|(_, v)| v)
     }
 }
 
-/// A wrapper around the `InstrumentModel` FlatBuffer schema. It mirrors the data
-/// stored there-in.
+/// The values of a single trailer extra across a range of scans, analogous to a [`StatusLog`].
 ///
-/// It contains a description of the instrument hardware and control software.
-pub struct InstrumentModel {
+/// Values are stored in the vector matching the data type declared by the trailer's header, so
+/// only one of [`TrailerColumn::float_values`], [`TrailerColumn::int_values`] or
+/// [`TrailerColumn::string_values`] will be populated. Boolean types are stored as 0 or 1 in
+/// [`TrailerColumn::int_values`]. Missing floating point values are `NaN`.
+pub struct TrailerColumn {
     data: RawVec<u8>,
 }
 
-/// An instrument configuration is a set of hardware components
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct InstrumentConfiguration {
-    /// The mass analyzer used in this configuration
-    pub mass_analyzer: MassAnalyzer,
-    /// The ionization mode used in this configuration
-    pub ionization_mode: IonizationMode,
-}
-
-impl Display for InstrumentConfiguration {
+impl Debug for TrailerColumn {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+        f.debug_struct("TrailerColumn")
+            .field("data-size", &self.data.len())
+            .field("label", &self.label())
+            .field("data_type", &self.data_type())
+            .field("len", &self.len())
+            .finish()
     }
 }
 
-impl InstrumentModel {
-    /// Create a new [`InstrumentModel`] by wrapping an owning memory buffer
+impl TrailerColumn {
     pub fn new(data: RawVec<u8>) -> Self {
         Self { data }
     }
 
-    /// Check that the buffer is a valid `InstrumentModelT`
+    /// Check that the buffer is a valid `TrailerColumnT`
     pub fn check(&self) -> bool {
-        root::<InstrumentModelT>(&self.data).is_ok()
+        root::<TrailerColumnT>(&self.data).is_ok()
     }
 
-    /// View the underlying buffer as a `InstrumentModelT`
-    pub fn view(&self) -> InstrumentModelT<'_> {
-        root::<InstrumentModelT>(&self.data).unwrap()
+    /// View the underlying buffer as a `TrailerColumnT`
+    pub fn view(&self) -> TrailerColumnT<'_> {
+        unsafe { root_unchecked::<TrailerColumnT>(&self.data) }
     }
 
-    /// The instrument model's name as a string.
-    ///
-    /// The majority of instrument models map onto the PSI-MS
-    /// controlled vocabulary uniquely, but not all do.
-    pub fn model(&self) -> Option<&str> {
-        self.view().model()
+    /// The human-readable label for this trailer
+    pub fn label(&self) -> &str {
+        self.view().label().unwrap_or_default()
     }
 
-    /// The instrument's name as configured by the
-    /// operator.
-    pub fn name(&self) -> Option<&str> {
-        self.view().name()
+    /// The data type declared by this trailer's header. [`TrailerDataType`] is a FlatBuffer enum
+    pub fn data_type(&self) -> TrailerDataType {
+        self.view().data_type()
     }
 
-    /// The instrument's serial number as specified by
-    /// the manufacturer.
-    pub fn serial_number(&self) -> Option<&str> {
-        self.view().serial_number()
+    /// The number of scans read
+    pub fn len(&self) -> usize {
+        self.view().times().map(|v| v.len()).unwrap_or_default()
     }
 
-    /// The hardware version string.
-    pub fn hardware_version(&self) -> Option<&str> {
-        self.view().hardware_version()
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
-    /// The acquisition software version string.
-    ///
-    /// This corresponds to the version of Xcalibur.
-    pub fn software_version(&self) -> Option<&str> {
-        self.view().software_version()
-    }
+    view_proxy!(times, "The retention time of each scan", Option<Cow<'_, [f64]>>, optcast);
+    view_proxy!(
+        float_values,
+        "The values of a `Float` or `Double` trailer",
+        Option<Cow<'_, [f64]>>,
+        optcast
+    );
+    view_proxy!(
+        int_values,
+        "The values of an integer or boolean trailer",
+        Option<Cow<'_, [i64]>>,
+        optcast
+    );
 
-    /// The set of distinct instrument configuration names.
-    pub fn configurations(&self) -> impl Iterator<Item = InstrumentConfiguration> + '_ {
-        self.view().configurations().into_iter().flatten().map(|c| {
-            let ionization_mode = c.ionization_mode().0.into();
-            let mass_analyzer = c.mass_analyzer().0.into();
-            InstrumentConfiguration {
-                ionization_mode,
-                mass_analyzer,
-            }
-        })
+    /// The values of a character or string trailer
+    pub fn string_values(&self) -> Option<Vector<'_, flatbuffers::ForwardsUOffset<&str>>> {
+        self.view().string_values()
+    }
+
+    /// Whether the value at `index` was present and could be converted to [`TrailerColumn::data_type`].
+    /// Integer and boolean values that failed to convert are stored as zero, so check this
+    /// before reading [`TrailerColumn::int_values`] directly.
+    pub fn is_valid(&self, index: usize) -> bool {
+        if index >= self.len() {
+            return false;
+        }
+        self.view().valid().is_none_or(|v| v.get(index))
+    }
+
+    /// Get the value for the `index`-th scan, or `None` if it is out of range or the
+    /// value was missing or could not be converted
+    pub fn get(&self, index: usize) -> Option<TypedTrailerValue> {
+        if !self.is_valid(index) {
+            return None;
+        }
+        let view = self.view();
+        Some(TypedTrailerValue::from_parts(
+            view.data_type(),
+            view.float_values().map(|v| v.get(index)).unwrap_or(f64::NAN),
+            view.int_values().map(|v| v.get(index)).unwrap_or_default(),
+            view.string_values().map(|v| v.get(index)),
+        ))
+    }
+
+    /// Iterate over the retention time and value for each scan, where the value is `None`
+    /// if it was missing or could not be converted
+    pub fn iter(&self) -> impl Iterator<Item = (f64, Option<TypedTrailerValue>)> + '_ {
+        self.view()
+            .times()
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, t)| (t, self.get(i)))
+    }
+}
+
+/// The label and declared data type of a single trailer extra header.
+///
+/// This borrows its storage from the original buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct TrailerHeader<'a> {
+    /// The human-readable label for this trailer
+    pub label: &'a str,
+    /// The data type the instrument declared for this trailer's values. [`TrailerDataType`] is a FlatBuffer enum
+    pub data_type: TrailerDataType,
+    /// The string length for text types or the display precision for numeric types
+    pub length_or_precision: i32,
+}
+
+/// The trailer extra headers declared by the RAW file, in the order their values are stored.
+pub struct TrailerHeaders {
+    data: RawVec<u8>,
+}
+
+impl Debug for TrailerHeaders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let items: Vec<_> = self.iter().collect();
+        f.debug_struct("TrailerHeaders")
+            .field("data-size", &self.data.len())
+            .field("entries", &items)
+            .finish()
+    }
+}
+
+impl TrailerHeaders {
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// Check that the buffer is a valid `TrailerHeaderCollectionT`
+    pub fn check(&self) -> bool {
+        root::<TrailerHeaderCollectionT>(&self.data).is_ok()
+    }
+
+    /// View the underlying buffer as a `TrailerHeaderCollectionT`
+    pub fn view(&self) -> TrailerHeaderCollectionT<'_> {
+        unsafe { root_unchecked::<TrailerHeaderCollectionT>(&self.data) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.view().headers().map(|v| v.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = TrailerHeader<'_>> + '_ {
+        self.view()
+            .headers()
+            .into_iter()
+            .flatten()
+            .map(|i| TrailerHeader {
+                label: i.label().unwrap_or_default(),
+                data_type: i.data_type(),
+                length_or_precision: i.length_or_precision(),
+            })
+    }
+
+    pub fn get(&self, index: usize) -> Option<TrailerHeader<'_>> {
+        self.iter().nth(index)
+    }
+
+    pub fn get_label(&self, label: &str) -> Option<TrailerHeader<'_>> {
+        self.iter().find(|i| i.label == label)
+    }
+}
+
+/// The instrument tune settings for a single tune segment, as label-value pairs
+pub struct TuneDataSegment<'a> {
+    data: TuneDataSegmentT<'a>,
+}
+
+impl<'a> TuneDataSegment<'a> {
+    pub fn new(data: TuneDataSegmentT<'a>) -> Self {
+        Self { data }
+    }
+
+    /// The 0-based index of the tune segment
+    pub fn index(&self) -> usize {
+        self.data.index() as usize
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.values().map(|v| v.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the label and value of each tune setting
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, TypedTrailerValue)> + 'a {
+        self.data
+            .values()
+            .into_iter()
+            .flatten()
+            .map(|i| (i.label().unwrap_or_default(), TypedTrailerValue::from_view(i)))
+    }
+
+    pub fn get(&self, index: usize) -> Option<(&'a str, TypedTrailerValue)> {
+        let values = self.data.values()?;
+        if index >= values.len() {
+            None
+        } else {
+            let i = values.get(index);
+            Some((i.label().unwrap_or_default(), TypedTrailerValue::from_view(i)))
+        }
+    }
+
+    pub fn get_label(&self, label: &str) -> Option<TypedTrailerValue> {
+        self.iter().find(|(l, _)| *l == label).map(|(_, v)| v)
+    }
+}
+
+/// The instrument tune settings recorded in the RAW file, one [`TuneDataSegment`]
+/// per tune segment.
+///
+/// Like [`TypedTrailerValues`], each value is converted to the type declared by its
+/// tune data header.
+pub struct TuneData {
+    data: RawVec<u8>,
+}
+
+impl TuneData {
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// Check that the buffer is a valid `TuneDataT`
+    pub fn check(&self) -> bool {
+        root::<TuneDataT>(&self.data).is_ok()
+    }
+
+    /// View the underlying buffer as a `TuneDataT`
+    pub fn view(&self) -> TuneDataT<'_> {
+        root::<TuneDataT>(&self.data).unwrap()
+    }
+
+    /// The number of tune segments
+    pub fn len(&self) -> usize {
+        self.view().segments().map(|v| v.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = TuneDataSegment<'_>> + '_ {
+        self.view().segments().into_iter().flatten().map(TuneDataSegment::new)
+    }
+
+    pub fn get(&self, index: usize) -> Option<TuneDataSegment<'_>> {
+        let segments = self.view().segments()?;
+        if index >= segments.len() {
+            None
+        } else {
+            Some(TuneDataSegment::new(segments.get(index)))
+        }
+    }
+}
+
+pub struct ExtendedSpectrumData {
+    data: RawVec<u8>,
+}
+
+impl ExtendedSpectrumData {
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// Check that the buffer is a valid `ExtendedSpectrumData`
+    pub fn check(&self) -> bool {
+        root::<ExtendedSpectrumDataT>(&self.data).is_ok()
+    }
+
+    /// View the underlying buffer as a `ExtendedSpectrumData`
+    pub fn view(&self) -> ExtendedSpectrumDataT<'_> {
+        unsafe { root_unchecked::<ExtendedSpectrumDataT>(&self.data) }
+    }
+
+    view_proxy!(
+        noise,
+        "Access the peak-local noise array, if available",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+
+    view_proxy!(
+        baseline,
+        "Access the baseline signal array, if available",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+
+    view_proxy!(
+        charge,
+        "Access the peak charge array, if available",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+
+    view_proxy!(
+        resolution,
+        "Access the peak resolution array, if available",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+
+    view_proxy!(
+        sampled_noise,
+        "Access the sampled noise array, if available",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+
+    view_proxy!(
+        sampled_noise_baseline,
+        "Access the sampled noise baseline array, if available",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+
+    view_proxy!(
+        sampled_noise_mz,
+        "Access the sampled noise m/z array, if available",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+}
+
+/// The signal trace of a chromatogram
+pub struct ChromatogramData<'a> {
+    time: Vector<'a, f64>,
+    intensity: Vector<'a, f32>,
+}
+
+impl<'a> ChromatogramData<'a> {
+    /// The time array of the spectrum, in minutes
+    pub fn time(&self) -> Cow<'a, [f64]> {
+        #[cfg(target_endian = "big")]
+        return Cow::Owned(self.time.iter().copied().collect());
+        #[cfg(target_endian = "little")]
+        Cow::Borrowed(bytemuck::cast_slice(self.time.bytes()))
+    }
+
+    /// The intensity array of the spectrum
+    pub fn intensity(&self) -> Cow<'a, [f32]> {
+        #[cfg(target_endian = "big")]
+        return Cow::Owned(self.intensity.iter().copied().collect());
+        #[cfg(target_endian = "little")]
+        Cow::Borrowed(bytemuck::cast_slice(self.intensity.bytes()))
+    }
+
+    /// Iterate over time-intensity pairs
+    pub fn iter(
+        &self,
+    ) -> std::iter::Zip<flatbuffers::VectorIter<'a, f64>, flatbuffers::VectorIter<'a, f32>> {
+        let it = self.time.iter().zip(self.intensity.iter());
+        it
+    }
+
+    pub fn len(&self) -> usize {
+        self.time.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.time.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for ChromatogramData<'a> {
+    type Item = (f64, f32);
+
+    type IntoIter =
+        std::iter::Zip<flatbuffers::VectorIter<'a, f64>, flatbuffers::VectorIter<'a, f32>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A wrapper around the `InstrumentModel` FlatBuffer schema. It mirrors the data
+/// stored there-in.
+///
+/// It contains a description of the instrument hardware and control software.
+pub struct InstrumentModel {
+    data: RawVec<u8>,
+}
+
+/// An instrument configuration is a set of hardware components
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InstrumentConfiguration {
+    /// The mass analyzer used in this configuration
+    pub mass_analyzer: MassAnalyzer,
+    /// The ionization mode used in this configuration
+    pub ionization_mode: IonizationMode,
+}
+
+impl Display for InstrumentConfiguration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl InstrumentModel {
+    /// Create a new [`InstrumentModel`] by wrapping an owning memory buffer
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// Check that the buffer is a valid `InstrumentModelT`
+    pub fn check(&self) -> bool {
+        root::<InstrumentModelT>(&self.data).is_ok()
+    }
+
+    /// View the underlying buffer as a `InstrumentModelT`
+    pub fn view(&self) -> InstrumentModelT<'_> {
+        root::<InstrumentModelT>(&self.data).unwrap()
+    }
+
+    /// The instrument model's name as a string.
+    ///
+    /// The majority of instrument models map onto the PSI-MS
+    /// controlled vocabulary uniquely, but not all do.
+    pub fn model(&self) -> Option<&str> {
+        self.view().model()
+    }
+
+    /// The instrument's name as configured by the
+    /// operator.
+    pub fn name(&self) -> Option<&str> {
+        self.view().name()
+    }
+
+    /// The instrument's serial number as specified by
+    /// the manufacturer.
+    pub fn serial_number(&self) -> Option<&str> {
+        self.view().serial_number()
+    }
+
+    /// The hardware version string.
+    pub fn hardware_version(&self) -> Option<&str> {
+        self.view().hardware_version()
+    }
+
+    /// The acquisition software version string.
+    ///
+    /// This corresponds to the version of Xcalibur.
+    pub fn software_version(&self) -> Option<&str> {
+        self.view().software_version()
+    }
+
+    /// The set of distinct instrument configuration names.
+    pub fn configurations(&self) -> impl Iterator<Item = InstrumentConfiguration> + '_ {
+        self.view().configurations().into_iter().flatten().map(|c| {
+            let ionization_mode = c.ionization_mode().0.into();
+            let mass_analyzer = c.mass_analyzer().0.into();
+            InstrumentConfiguration {
+                ionization_mode,
+                mass_analyzer,
+            }
+        })
     }
 }
 
@@ -684,6 +1314,15 @@ pub struct FileDescription {
     data: RawVec<u8>,
 }
 
+fn timestamp_from_millis(millis: i64) -> SystemTime {
+    let offset = Duration::from_millis(millis.unsigned_abs());
+    if millis >= 0 {
+        UNIX_EPOCH + offset
+    } else {
+        UNIX_EPOCH - offset
+    }
+}
+
 impl FileDescription {
     pub fn new(data: RawVec<u8>) -> Self {
         Self { data }
@@ -700,34 +1339,126 @@ impl FileDescription {
     }
 
     view_proxy!(
-        sample_id,
-        "The sample identifier provided by the user, if one is present"
+        sample_id,
+        "The sample identifier provided by the user, if one is present"
+    );
+    view_proxy!(
+        sample_vial,
+        "The sample vial name provided by the user or sample handling system, if present"
+    );
+    view_proxy!(
+        sample_comment,
+        "The comment describing the sample as provided by the user, if present"
+    );
+    view_proxy!(
+        sample_name,
+        "The sample name provided by the user, if one is present"
+    );
+    view_proxy!(
+        source_file,
+        "The name of the RAW file being described, as it was recorded by the control software"
+    );
+    view_proxy!(
+        creation_date,
+        "The date the RAW file was created, or that the instrument run was performed"
+    );
+    view_proxy!(description, "The free-text description of the RAW file, if present");
+    view_proxy!(creator_id, "The identifier of the user who created the RAW file");
+    view_proxy!(
+        creator_logon,
+        "The operating system logon of the user who created the RAW file"
+    );
+    view_proxy!(
+        modifier_id,
+        "The identifier of the user who last modified the RAW file"
+    );
+    view_proxy!(
+        modifier_logon,
+        "The operating system logon of the user who last modified the RAW file"
+    );
+    view_proxy!(revision, "The revision of the RAW file format", i32);
+    view_proxy!(
+        times_calibrated,
+        "The number of times the RAW file has been calibrated",
+        i32
+    );
+    view_proxy!(
+        times_modified,
+        "The number of times the RAW file has been modified",
+        i32
+    );
+
+    view_proxy!(
+        sample_type,
+        "The role of the sample in the acquisition sequence. [`SampleType`] is a FlatBuffer enum",
+        SampleType
+    );
+    view_proxy!(sample_barcode, "The barcode read from the sample, if present");
+    view_proxy!(
+        sample_barcode_status,
+        "Whether the sample's barcode was read. [`BarcodeStatus`] is a FlatBuffer enum",
+        BarcodeStatus
+    );
+    view_proxy!(injection_volume, "The volume of sample injected", f64);
+    view_proxy!(dilution_factor, "The dilution factor of the sample", f64);
+    view_proxy!(sample_weight, "The weight of the sample", f64);
+    view_proxy!(sample_volume, "The volume of the sample", f64);
+    view_proxy!(
+        istd_amount,
+        "The amount of internal standard added to the sample",
+        f64
     );
     view_proxy!(
-        sample_vial,
-        "The sample vial name provided by the user or sample handling system, if present"
+        calibration_level,
+        "The calibration or QC level of the sample, if present"
     );
+    view_proxy!(row_number, "The row number of the sample in the sequence", i32);
     view_proxy!(
-        sample_comment,
-        "The comment describing the sample as provided by the user, if present"
+        instrument_method_file,
+        "The path to the instrument method file used to acquire the sample, if present"
     );
     view_proxy!(
-        sample_name,
-        "The sample name provided by the user, if one is present"
+        processing_method_file,
+        "The path to the processing method file for the sample, if present"
     );
     view_proxy!(
-        source_file,
-        "The name of the RAW file being described, as it was recorded by the control software"
+        calibration_file,
+        "The path to the calibration file for the sample, if present"
     );
     view_proxy!(
-        creation_date,
-        "The date the RAW file was created, or that the instrument run was performed"
+        raw_file_path,
+        "The path the RAW file was written to, as recorded in the sequence"
     );
 
+    /// The user-defined sample text fields
+    pub fn sample_user_text(&self) -> impl Iterator<Item = &str> + '_ {
+        self.view().sample_user_text().into_iter().flatten()
+    }
+
+    /// The time the RAW file was created, or that the instrument run was performed
+    pub fn creation_timestamp(&self) -> SystemTime {
+        timestamp_from_millis(self.view().creation_timestamp())
+    }
+
+    /// The time the RAW file was last modified
+    pub fn modification_timestamp(&self) -> SystemTime {
+        timestamp_from_millis(self.view().modification_timestamp())
+    }
+
+    /// Summary information about the MS instrument run, like the time and mass ranges covered.
+    ///
+    /// This is a raw FlatBuffer struct. See the [schema](https://github.com/mobiusklein/thermorawfilereader.rs/blob/main/schema/schema.fbs) for more details.
+    pub fn run_header(&self) -> Option<&RunHeaderT> {
+        self.view().run_header()
+    }
+
     /// The number of spectra at MS levels 1-10.
     ///
     /// This returns a [`flatbuffers::Vector`] of counts where index `i` corresponds
     /// to the number of MS level `i+1` spectra in the RAW file.
+    ///
+    /// This is absent if the file was opened with [`RawFileReader::open_lazy`] and the spectra
+    /// have not been counted yet. Use [`RawFileReader::spectra_per_ms_level`] to count them.
     pub fn spectra_per_ms_level(&self) -> Option<flatbuffers::Vector<'_, u32>> {
         self.view().spectra_per_ms_level()
     }
@@ -827,62 +1558,508 @@ impl ChromatogramDescription {
     }
 }
 
-/// Describes a scan acquisition.
-///
-/// Acts as a wrapper around [`AcquisitionT`] that translates
-/// raw FlatBuffer encodings.
-pub struct Acquisition<'a> {
-    data: AcquisitionT<'a>,
+/// An absorbance spectrum recorded by a PDA or scanning UV device, the
+/// non-MS sibling of [`RawSpectrum`].
+pub struct WavelengthSpectrum {
+    data: RawVec<u8>,
+}
+
+impl WavelengthSpectrum {
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// Check that the buffer is a valid `WavelengthSpectrumT`
+    pub fn check(&self) -> bool {
+        root::<WavelengthSpectrumT>(&self.data).is_ok()
+    }
+
+    /// View the underlying buffer as a `WavelengthSpectrumT`
+    pub fn view(&self) -> WavelengthSpectrumT<'_> {
+        root::<WavelengthSpectrumT>(&self.data).unwrap()
+    }
+
+    /// Get the index of the scan on its device
+    #[inline]
+    pub fn index(&self) -> usize {
+        (self.view().index() as usize).saturating_sub(1)
+    }
+
+    view_proxy!(time, "Get the scan start time in minutes", f64);
+    view_proxy!(
+        low_wavelength,
+        "Get the shortest wavelength scanned, in nanometers",
+        f64
+    );
+    view_proxy!(
+        high_wavelength,
+        "Get the longest wavelength scanned, in nanometers",
+        f64
+    );
+
+    view_proxy!(
+        wavelength,
+        "Access the wavelength array, in nanometers",
+        Option<Cow<'_, [f64]>>,
+        optcast
+    );
+
+    view_proxy!(
+        absorbance,
+        "Access the absorbance array",
+        Option<Cow<'_, [f32]>>,
+        optcast
+    );
+
+    /// The number of points in the spectrum
+    pub fn len(&self) -> usize {
+        self.view().wavelength().map(|v| v.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A mass accuracy tolerance used to define an m/z interval around a target m/z
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// A tolerance in parts-per-million of the target m/z
+    PPM(f64),
+    /// A tolerance in Daltons (Thomsons)
+    Da(f64),
+}
+
+impl Tolerance {
+    /// Compute the m/z interval `(low, high)` centered on `mz`
+    pub fn bounds(&self, mz: f64) -> (f64, f64) {
+        let width = match self {
+            Tolerance::PPM(ppm) => mz * ppm / 1e6,
+            Tolerance::Da(da) => *da,
+        };
+        (mz - width, mz + width)
+    }
+}
+
+/// The parent-child relationships between spectra, linking each MS1 spectrum to the MS2
+/// spectra acquired from it, each MS2 spectrum to its MS3 spectra, and so on.
+///
+/// See [`RawFileReader::acquisition_tree`].
+#[derive(Debug, Default, Clone)]
+pub struct AcquisitionTree {
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+}
+
+impl AcquisitionTree {
+    fn from_parents(parents: &[i32], size: usize) -> Self {
+        let parents: Vec<Option<usize>> = (0..size)
+            .map(|i| {
+                parents
+                    .get(i)
+                    .copied()
+                    .filter(|p| *p >= 0 && (*p as usize) < size)
+                    .map(|p| p as usize)
+            })
+            .collect();
+        let mut children = vec![Vec::new(); size];
+        for (i, parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                children[*parent].push(i);
+            }
+        }
+        Self { parents, children }
+    }
+
+    /// The number of spectra in the tree
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The index of the spectrum the precursor of the spectrum at `index` was selected from
+    pub fn parent_of(&self, index: usize) -> Option<usize> {
+        self.parents.get(index).copied().flatten()
+    }
+
+    /// The indices of the spectra whose precursors were selected from the spectrum at `index`
+    pub fn children_of(&self, index: usize) -> &[usize] {
+        self.children.get(index).map(|c| c.as_slice()).unwrap_or_default()
+    }
+
+    /// All the spectra derived from the spectrum at `index`, directly or through intermediate
+    /// spectra, in depth-first order
+    pub fn descendants_of(&self, index: usize) -> Vec<usize> {
+        let mut descendants = Vec::new();
+        let mut stack: Vec<usize> = self.children_of(index).iter().rev().copied().collect();
+        while let Some(i) = stack.pop() {
+            descendants.push(i);
+            stack.extend(self.children_of(i).iter().rev().copied());
+        }
+        descendants
+    }
+
+    /// The indices of the spectra without a parent, usually the MS1 spectra
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_none())
+            .map(|(i, _)| i)
+    }
+}
+
+/// Describes a scan acquisition.
+///
+/// Acts as a wrapper around [`AcquisitionT`] that translates
+/// raw FlatBuffer encodings.
+pub struct Acquisition<'a> {
+    data: AcquisitionT<'a>,
+}
+
+impl<'a> Acquisition<'a> {
+    pub fn new(data: AcquisitionT<'a>) -> Self {
+        Self { data }
+    }
+
+    #[inline(always)]
+    pub fn low_mz(&self) -> f64 {
+        self.data.low_mz()
+    }
+
+    #[inline(always)]
+    pub fn high_mz(&self) -> f64 {
+        self.data.high_mz()
+    }
+
+    #[inline(always)]
+    pub fn injection_time(&self) -> f32 {
+        self.data.injection_time()
+    }
+
+    #[inline(always)]
+    pub fn compensation_voltage(&self) -> Option<f32> {
+        self.data.compensation_voltages()?.iter().next()
+    }
+
+    #[inline(always)]
+    pub fn compensation_voltages(&self) -> Option<Vec<f32>> {
+        self.data.compensation_voltages().map(|v| v.iter().collect())
+    }
+
+    #[inline(always)]
+    pub fn mass_analyzer(&self) -> MassAnalyzer {
+        self.data.mass_analyzer().0.into()
+    }
+
+    #[inline(always)]
+    pub fn scan_event(&self) -> i32 {
+        self.data.scan_event()
+    }
+
+    #[inline(always)]
+    pub fn ionization_mode(&self) -> IonizationMode {
+        self.data.ionization_mode().0.into()
+    }
+
+    #[inline(always)]
+    pub fn resolution(&self) -> Option<f32> {
+        self.data.resolution()
+    }
+
+    /// The m/z range of each scan window, as `(low, high)` pairs.
+    ///
+    /// Multi-range and SIM-stitched scans will have more than one window, one for
+    /// each segment listed by [`SpectrumData::segments`].
+    pub fn scan_windows(&self) -> impl Iterator<Item = (f64, f64)> + 'a {
+        self.data
+            .scan_windows()
+            .into_iter()
+            .flatten()
+            .map(|r| (r.low(), r.high()))
+    }
+}
+
+/// The structured form of a Thermo scan filter describing how a spectrum was acquired.
+///
+/// Acts as a wrapper around [`ScanFilterT`] that translates
+/// raw FlatBuffer encodings.
+pub struct ScanFilter<'a> {
+    data: ScanFilterT<'a>,
+}
+
+impl<'a> ScanFilter<'a> {
+    pub fn new(data: ScanFilterT<'a>) -> Self {
+        Self { data }
+    }
+
+    #[inline(always)]
+    pub fn mass_analyzer(&self) -> MassAnalyzer {
+        self.data.mass_analyzer().0.into()
+    }
+
+    /// Whether the scan is positive or negative mode.
+    /// [`Polarity`] is a FlatBuffer enum
+    #[inline(always)]
+    pub fn polarity(&self) -> Polarity {
+        self.data.polarity()
+    }
+
+    #[inline(always)]
+    pub fn scan_mode(&self) -> ScanMode {
+        self.data.scan_mode().0.into()
+    }
+
+    #[inline(always)]
+    pub fn ms_order(&self) -> MSOrder {
+        self.data.ms_order().0.into()
+    }
+
+    /// Whether the scan was acquired in profile or centroid mode.
+    /// [`SpectrumMode`] is a FlatBuffer enum
+    #[inline(always)]
+    pub fn mode(&self) -> SpectrumMode {
+        self.data.mode()
+    }
+
+    #[inline(always)]
+    pub fn ionization_mode(&self) -> IonizationMode {
+        self.data.ionization_mode().0.into()
+    }
+
+    /// The precursor reactions of the scan, one per activation step. An activation step that
+    /// re-activates the same precursor, like the HCD step in EThcD, is marked with
+    /// [`ScanFilterReactionT::multiple_activation`].
+    pub fn reactions(&self) -> impl Iterator<Item = &'a ScanFilterReactionT> + 'a {
+        self.data.reactions().into_iter().flatten()
+    }
+
+    /// The m/z ranges scanned, as `(low, high)` pairs
+    pub fn mass_ranges(&self) -> impl Iterator<Item = (f64, f64)> + 'a {
+        self.data
+            .mass_ranges()
+            .into_iter()
+            .flatten()
+            .map(|r| (r.low(), r.high()))
+    }
+
+    /// The FAIMS compensation voltages, if a FAIMS device was in use
+    pub fn compensation_voltages(&self) -> Vec<f64> {
+        self.data
+            .compensation_voltages()
+            .map(|v| v.iter().collect())
+            .unwrap_or_default()
+    }
+
+    #[inline(always)]
+    pub fn detector_value(&self) -> Option<f64> {
+        self.data.detector_value()
+    }
+
+    /// Whether this is a synchronous precursor selection (multi-notch) scan
+    #[inline(always)]
+    pub fn is_sps(&self) -> bool {
+        self.data.sps()
+    }
+
+    /// Whether this is a data-dependent scan
+    #[inline(always)]
+    pub fn is_dependent(&self) -> bool {
+        self.data.dependent()
+    }
+
+    #[inline(always)]
+    pub fn has_supplemental_activation(&self) -> bool {
+        self.data.supplemental_activation()
+    }
+}
+
+/// Every activation applied to a spectrum's precursor, in contrast to the single
+/// dissociation method and energy of [`PrecursorT::activation`].
+///
+/// Acts as a wrapper around [`ActivationDetailsT`]. Energies and reaction parameters
+/// that the instrument did not record are `None`.
+pub struct ActivationDetails<'a> {
+    data: ActivationDetailsT<'a>,
+}
+
+impl<'a> ActivationDetails<'a> {
+    pub fn new(data: ActivationDetailsT<'a>) -> Self {
+        Self { data }
+    }
+
+    /// Each activation step in the order it was applied. The first step is the primary
+    /// activation and later steps are supplemental activations of the same precursor.
+    pub fn steps(&self) -> impl Iterator<Item = &'a ActivationStepT> + 'a {
+        self.data.steps().into_iter().flatten()
+    }
+
+    /// The collision energies used by a stepped collision energy scan, or an empty
+    /// list if a single energy was used
+    pub fn stepped_collision_energies(&self) -> Vec<f64> {
+        self.data
+            .stepped_collision_energies()
+            .map(|v| v.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether the precursor was activated with more than one collision energy
+    #[inline(always)]
+    pub fn is_stepped(&self) -> bool {
+        self.data
+            .stepped_collision_energies()
+            .is_some_and(|v| v.len() > 1)
+    }
+
+    /// Whether the precursor was activated more than once, like EThcD or ETciD
+    #[inline(always)]
+    pub fn has_supplemental_activation(&self) -> bool {
+        self.steps().any(|s| s.supplemental())
+    }
+
+    /// The energy of the supplemental activation step
+    #[inline(always)]
+    pub fn supplemental_energy(&self) -> Option<f64> {
+        self.data.supplemental_energy()
+    }
+
+    /// The absolute collision energy in electronvolts
+    #[inline(always)]
+    pub fn collision_energy_ev(&self) -> Option<f64> {
+        self.data.collision_energy_ev()
+    }
+
+    /// The ETD reaction time in milliseconds
+    #[inline(always)]
+    pub fn etd_reaction_time(&self) -> Option<f64> {
+        self.data.etd_reaction_time()
+    }
+
+    /// The ETD reagent ion target
+    #[inline(always)]
+    pub fn etd_reagent_target(&self) -> Option<f64> {
+        self.data.etd_reagent_target()
+    }
+}
+
+/// One precursor isolated for a spectrum, along with every activation applied to it.
+///
+/// Dereferences to the underlying [`PrecursorT`].
+pub struct Precursor<'a> {
+    precursor: &'a PrecursorT,
+    activation_details: Option<ActivationDetails<'a>>,
+}
+
+impl<'a> Precursor<'a> {
+    pub fn new(precursor: &'a PrecursorT, activation_details: Option<ActivationDetails<'a>>) -> Self {
+        Self {
+            precursor,
+            activation_details,
+        }
+    }
+
+    /// Get every activation applied to this precursor, including supplemental activation,
+    /// stepped collision energies and ETD reaction parameters.
+    pub fn activation_details(&self) -> Option<&ActivationDetails<'a>> {
+        self.activation_details.as_ref()
+    }
+}
+
+impl Deref for Precursor<'_> {
+    type Target = PrecursorT;
+
+    fn deref(&self) -> &Self::Target {
+        self.precursor
+    }
+}
+
+/// A distinct scan filter and the spectra acquired with it
+pub struct ScanFilterGroup<'a> {
+    data: ScanFilterGroupT<'a>,
 }
 
-impl<'a> Acquisition<'a> {
-    pub fn new(data: AcquisitionT<'a>) -> Self {
+impl<'a> ScanFilterGroup<'a> {
+    pub fn new(data: ScanFilterGroupT<'a>) -> Self {
         Self { data }
     }
 
-    #[inline(always)]
-    pub fn low_mz(&self) -> f64 {
-        self.data.low_mz()
+    /// The filter string shared by all the spectra in the group
+    pub fn filter_string(&self) -> &'a str {
+        self.data.filter_string().unwrap_or_default()
     }
 
-    #[inline(always)]
-    pub fn high_mz(&self) -> f64 {
-        self.data.high_mz()
+    /// The structured form of the group's scan filter
+    pub fn scan_filter(&self) -> Option<ScanFilter<'a>> {
+        self.data.scan_filter().map(ScanFilter::new)
     }
 
-    #[inline(always)]
-    pub fn injection_time(&self) -> f32 {
-        self.data.injection_time()
+    /// The indices of the spectra in the group, in acquisition order
+    pub fn indices(&self) -> Cow<'a, [u32]> {
+        let data = self.data.indices().unwrap_or_default();
+        #[cfg(target_endian = "big")]
+        return Cow::Owned(data.iter().collect());
+        #[cfg(target_endian = "little")]
+        return Cow::Borrowed(bytemuck::cast_slice(data.bytes()));
     }
 
-    #[inline(always)]
-    pub fn compensation_voltage(&self) -> Option<f32> {
-        self.data.compensation_voltages()?.iter().next()
+    /// The number of spectra in the group
+    pub fn len(&self) -> usize {
+        self.data.indices().map(|v| v.len()).unwrap_or_default()
     }
 
-    #[inline(always)]
-    pub fn compensation_voltages(&self) -> Option<Vec<f32>> {
-        self.data.compensation_voltages().map(|v| v.iter().collect())
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
+}
 
-    #[inline(always)]
-    pub fn mass_analyzer(&self) -> MassAnalyzer {
-        self.data.mass_analyzer().0.into()
+/// The distinct scan filters used in a RAW file, each grouped with the
+/// spectra acquired with it. See [`RawFileReader::unique_filters`].
+pub struct ScanFilterGroups {
+    data: RawVec<u8>,
+}
+
+impl ScanFilterGroups {
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
     }
 
-    #[inline(always)]
-    pub fn scan_event(&self) -> i32 {
-        self.data.scan_event()
+    /// Check that the buffer is a valid `ScanFilterGroupCollectionT`
+    pub fn check(&self) -> bool {
+        root::<ScanFilterGroupCollectionT>(&self.data).is_ok()
     }
 
-    #[inline(always)]
-    pub fn ionization_mode(&self) -> IonizationMode {
-        self.data.ionization_mode().0.into()
+    /// View the underlying buffer as a `ScanFilterGroupCollectionT`
+    pub fn view(&self) -> ScanFilterGroupCollectionT<'_> {
+        root::<ScanFilterGroupCollectionT>(&self.data).unwrap()
     }
 
-    #[inline(always)]
-    pub fn resolution(&self) -> Option<f32> {
-        self.data.resolution()
+    pub fn len(&self) -> usize {
+        self.view().groups().map(|v| v.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ScanFilterGroup<'_>> + '_ {
+        self.view().groups().into_iter().flatten().map(ScanFilterGroup::new)
+    }
+
+    pub fn get(&self, index: usize) -> Option<ScanFilterGroup<'_>> {
+        let groups = self.view().groups()?;
+        if index >= groups.len() {
+            None
+        } else {
+            Some(ScanFilterGroup::new(groups.get(index)))
+        }
+    }
+
+    /// Find the group for a particular filter string
+    pub fn get_filter(&self, filter_string: &str) -> Option<ScanFilterGroup<'_>> {
+        self.iter().find(|g| g.filter_string() == filter_string)
     }
 }
 
@@ -951,6 +2128,58 @@ impl StatusLogCollection {
     }
 }
 
+/// The instrument error log, a series of messages recorded over the course of the run,
+/// like spray instability or vacuum faults.
+pub struct ErrorLog {
+    data: RawVec<u8>,
+}
+
+impl ErrorLog {
+    pub fn new(data: RawVec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// Check that the buffer is a valid `ErrorLogT`
+    pub fn check(&self) -> bool {
+        root::<ErrorLogT>(&self.data).is_ok()
+    }
+
+    /// View the underlying buffer as a `ErrorLogT`
+    pub fn view(&self) -> ErrorLogT<'_> {
+        root::<ErrorLogT>(&self.data).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.view().times().map(|v| v.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The retention times, in minutes, each message was recorded at
+    pub fn times(&self) -> Cow<'_, [f64]> {
+        let data = self.view().times().unwrap_or_default();
+        #[cfg(target_endian = "big")]
+        return Cow::Owned(data.iter().collect());
+        #[cfg(target_endian = "little")]
+        return Cow::Borrowed(bytemuck::cast_slice(data.bytes()));
+    }
+
+    /// Iterate over `(retention time, message)` pairs
+    pub fn iter(&self) -> impl Iterator<Item = (f64, &str)> + '_ {
+        let view = self.view();
+        view.times()
+            .unwrap_or_default()
+            .iter()
+            .zip(view.messages().unwrap_or_default().iter())
+    }
+
+    pub fn get(&self, index: usize) -> Option<(f64, &str)> {
+        self.iter().nth(index)
+    }
+}
+
 pub struct StatusLog<'a, T> {
     pub name: String,
     times: Vector<'a, f64>,
@@ -1021,14 +2250,22 @@ impl<'a, T: bytemuck::Pod> StatusLog<'a, T> {
 pub struct RawFileReader {
     /// The token controlling the `RawFileReader` this object references
     raw_file_reader: *mut c_void,
-    /// A reference to the .NET runtime
-    context: Arc<AssemblyDelegateLoader>,
+    /// The functions exported by the .NET library, shared by every `RawFileReader`
+    functions: &'static ManagedFunctions,
+    /// The path the RAW file was opened from
+    path: PathBuf,
     /// A cache for the number of spectra in the RAW file
     size: usize,
+    /// The 0-based index of the MS controller spectra are read from
+    ms_controller: usize,
+    /// A cache for the parent-child relationships between spectra, built on first use
+    acquisition_tree: OnceLock<AcquisitionTree>,
     include_signal: bool,
     centroid_spectra: bool,
-    /// A FFI function pointer to get spectra through.
-    vget: ManagedFunction<extern "system" fn(*mut c_void, i32, i32, i32) -> RawVec<u8>>,
+    /// The tolerance for matching a precursor m/z in its parent scan, or `None` if disabled
+    precursor_intensity_tolerance: Option<Tolerance>,
+    /// Whether to match the monoisotopic m/z rather than the selected m/z in the parent scan
+    precursor_intensity_monoisotopic: bool,
 }
 
 unsafe impl Send for RawFileReader {}
@@ -1044,10 +2281,13 @@ impl Debug for RawFileReader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RawFileReader")
             .field("raw_file_reader", &self.raw_file_reader)
-            .field("context", &"?")
+            .field("path", &self.path)
             .field("size", &self.size)
+            .field("ms_controller", &self.ms_controller)
             .field("include_signal", &self.include_signal)
             .field("centroid_spectra", &self.centroid_spectra)
+            .field("precursor_intensity_tolerance", &self.precursor_intensity_tolerance)
+            .field("precursor_intensity_monoisotopic", &self.precursor_intensity_monoisotopic)
             .finish()
     }
 }
@@ -1055,96 +2295,98 @@ impl Debug for RawFileReader {
 impl RawFileReader {
     /// Open a ThermoFisher RAW file from a path. This may also create the .NET runtime
     /// if this is the first time it was called.
-    pub fn open<P: Into<PathBuf>>(path: P) -> io::Result<Self> {
-        let context = try_get_runtime().map_err(|e| match e {
-            dotnetrawfilereader_sys::DotNetRuntimeCreationError::FailedToWriteDLLBundle(r) => {
-                io::Error::new(io::ErrorKind::Other, r)
-            }
-            dotnetrawfilereader_sys::DotNetRuntimeCreationError::LoadHostfxrError(r) => {
-                io::Error::new(io::ErrorKind::Other, r)
-            }
-            dotnetrawfilereader_sys::DotNetRuntimeCreationError::HostingError(r) => {
-                io::Error::new(io::ErrorKind::Other, r)
-            }
-            dotnetrawfilereader_sys::DotNetRuntimeCreationError::IOError(r) => r,
-        })?;
-        let open_fn = context.get_function_with_unmanaged_callers_only::<fn(text_ptr: *const u8, text_length: i32) -> *mut c_void>(
-            pdcstr!("librawfilereader.Exports, librawfilereader"),
-            pdcstr!("Open")
-        ).unwrap();
+    ///
+    /// Spectra are read from the first MS controller. See [`RawFileReader::open_ms_controller`]
+    /// to read from another one.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self, RawFileReaderError> {
+        Self::open_ms_controller(path, 0)
+    }
+
+    /// Open a ThermoFisher RAW file from a path, reading spectra from the MS controller
+    /// at `ms_controller`, starting from zero. This may also create the .NET runtime
+    /// if this is the first time it was called.
+    ///
+    /// Use [`RawFileReader::ms_controller_count`] to find out how many MS controllers
+    /// a file has.
+    pub fn open_ms_controller<P: Into<PathBuf>>(path: P, ms_controller: usize) -> Result<Self, RawFileReaderError> {
+        Self::_impl_open(path, ms_controller, false)
+    }
+
+    /// Open a ThermoFisher RAW file from a path without indexing every scan up front, reading
+    /// spectra from the first MS controller. This may also create the .NET runtime if this is
+    /// the first time it was called.
+    ///
+    /// Opening a long run with [`RawFileReader::open`] visits every scan filter and scan event
+    /// to count spectra per MS level and find the instrument configurations. This defers that
+    /// work until [`RawFileReader::spectra_per_ms_level`] or [`RawFileReader::instrument_model`]
+    /// first need it, which is much faster when only a few spectra or the file's metadata are wanted.
+    pub fn open_lazy<P: Into<PathBuf>>(path: P) -> Result<Self, RawFileReaderError> {
+        Self::open_lazy_ms_controller(path, 0)
+    }
+
+    /// Open a ThermoFisher RAW file from a path without indexing every scan up front, reading
+    /// spectra from the MS controller at `ms_controller`, starting from zero.
+    ///
+    /// See [`RawFileReader::open_lazy`] and [`RawFileReader::open_ms_controller`].
+    pub fn open_lazy_ms_controller<P: Into<PathBuf>>(path: P, ms_controller: usize) -> Result<Self, RawFileReaderError> {
+        Self::_impl_open(path, ms_controller, true)
+    }
+
+    fn _impl_open<P: Into<PathBuf>>(path: P, ms_controller: usize, lazy: bool) -> Result<Self, RawFileReaderError> {
+        let context = try_get_runtime()?;
+        let functions = managed_functions(&context)?;
+        let open_fn = &functions.open_with_options;
         let path: PathBuf = path.into();
-        let path = path.to_string_lossy().to_string();
-        let raw_file_reader = open_fn(path.as_ptr(), path.len() as i32);
-
-        let buffer_fn = context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void, i32, i32, i32) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("SpectrumDescriptionForWithOptions"),
-            )
-            .unwrap();
+        let path_str = path.to_string_lossy().to_string();
+        let raw_file_reader = open_fn(path_str.as_ptr(), path_str.len() as i32, (ms_controller as i32) + 1, lazy as u32);
+        if raw_file_reader.is_null() {
+            Self::_impl_take_last_error(functions)?;
+            return Err(RawFileReaderError::Error(format!(
+                "Failed to open {}, no reader was created",
+                path.display()
+            )));
+        }
 
         let mut handle = Self {
             raw_file_reader,
-            context,
+            functions,
             include_signal: true,
             centroid_spectra: false,
+            precursor_intensity_tolerance: Some(Tolerance::PPM(20.0)),
+            precursor_intensity_monoisotopic: true,
+            acquisition_tree: OnceLock::new(),
             size: 0,
-            vget: buffer_fn,
+            ms_controller,
+            path,
         };
 
-        match &handle.status() {
-            RawFileReaderError::Ok => {}
-            RawFileReaderError::FileNotFound => {
-                return Err(io::Error::new(io::ErrorKind::NotFound, "File not found"))
-            }
-            RawFileReaderError::InvalidFormat => {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!(
-                        "File does not appear to be a valid RAW file. {}",
-                        handle.error_message().unwrap_or_default()
-                    ),
-                ))
-            }
-            RawFileReaderError::Error | RawFileReaderError::HandleNotFound => {
-                return Err(io::Error::new(
-                    io::ErrorKind::Other,
-                    format!(
-                        "An unknown error occured {}",
-                        handle.error_message().unwrap_or_default()
-                    ),
-                ))
-            }
-        }
+        handle.status()?;
 
-        handle.size = handle._impl_len();
+        handle.size = handle._impl_len()?;
 
         Ok(handle)
     }
 
     /// Get the scan number of the first spectrum
-    pub fn first_spectrum(&self) -> i32 {
-        self.validate_impl();
-        let index_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> i32>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("FirstSpectrum"),
-            )
-            .unwrap();
-        index_fn(self.raw_file_reader)
+    pub fn first_spectrum(&self) -> Result<i32, RawFileReaderError> {
+        self.validate_impl()?;
+        let index_fn = &self.functions.first_spectrum;
+        let scan_number = index_fn(self.raw_file_reader);
+        if scan_number < 0 {
+            self.take_last_error()?;
+        }
+        Ok(scan_number)
     }
 
     /// Get the scan number of the last spectrum
-    pub fn last_spectrum(&self) -> i32 {
-        let index_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> i32>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("LastSpectrum"),
-            )
-            .unwrap();
-        index_fn(self.raw_file_reader)
+    pub fn last_spectrum(&self) -> Result<i32, RawFileReaderError> {
+        self.validate_impl()?;
+        let index_fn = &self.functions.last_spectrum;
+        let scan_number = index_fn(self.raw_file_reader);
+        if scan_number < 0 {
+            self.take_last_error()?;
+        }
+        Ok(scan_number)
     }
 
     /// Get whether or not to retrieve the spectrum signal data when retrieving spectra
@@ -1167,129 +2409,493 @@ impl RawFileReader {
         self.centroid_spectra = value;
     }
 
+    /// Get the tolerance used to match a precursor's m/z in its parent scan when looking
+    /// up the precursor's intensity, or `None` if the lookup is disabled. Defaults to 20 ppm.
+    pub fn get_precursor_intensity_tolerance(&self) -> Option<Tolerance> {
+        self.precursor_intensity_tolerance
+    }
+
+    /// Set the tolerance used to match a precursor's m/z in its parent scan when looking
+    /// up the precursor's intensity. Only signal inside the isolation window is considered.
+    ///
+    /// # Cost
+    /// The lookup reads the parent scan's signal for every MSn spectrum retrieved with
+    /// [`RawFileReader::get`]. Only the most recently read parent scan is cached, so reading
+    /// MSn spectra in acquisition order is cheap, but random access or reading from several
+    /// threads at once will read a parent scan for nearly every MSn spectrum.
+    ///
+    /// The lookup is skipped when signal loading is disabled with [`RawFileReader::set_signal_loading`],
+    /// and passing `None` disables it entirely. Either way, [`PrecursorT::intensity`] is left as zero.
+    pub fn set_precursor_intensity_tolerance(&mut self, tolerance: Option<Tolerance>) -> Result<(), RawFileReaderError> {
+        self.precursor_intensity_tolerance = tolerance;
+        self._impl_set_precursor_intensity_options()
+    }
+
+    /// Get whether the precursor intensity lookup matches the monoisotopic m/z rather than
+    /// the m/z selected for isolation. Defaults to `true`.
+    pub fn get_precursor_intensity_monoisotopic(&self) -> bool {
+        self.precursor_intensity_monoisotopic
+    }
+
+    /// Set whether the precursor intensity lookup matches the monoisotopic m/z (`true`) or
+    /// the m/z selected for isolation by the scan filter (`false`).
+    pub fn set_precursor_intensity_monoisotopic(&mut self, value: bool) -> Result<(), RawFileReaderError> {
+        self.precursor_intensity_monoisotopic = value;
+        self._impl_set_precursor_intensity_options()
+    }
+
+    fn _impl_set_precursor_intensity_options(&self) -> Result<(), RawFileReaderError> {
+        self.validate_impl()?;
+        let options_fn = &self.functions.set_precursor_intensity_options;
+        let (tolerance, is_ppm) = match self.precursor_intensity_tolerance {
+            Some(Tolerance::PPM(ppm)) => (ppm, true),
+            Some(Tolerance::Da(da)) => (da, false),
+            None => (0.0, false),
+        };
+        options_fn(
+            self.raw_file_reader,
+            tolerance,
+            is_ppm as u32,
+            self.precursor_intensity_monoisotopic as u32,
+        );
+        self.take_last_error()
+    }
+
     /// Get a [`InstrumentModel`] message describing the instrument configuration used
     /// to acquire the RAW file.
-    pub fn instrument_model(&self) -> InstrumentModel {
-        self.validate_impl();
-        let instrument_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("InstrumentModel"),
-            )
-            .unwrap();
+    pub fn instrument_model(&self) -> Result<InstrumentModel, RawFileReaderError> {
+        self.validate_impl()?;
+        let instrument_fn = &self.functions.instrument_model;
         let buf = instrument_fn(self.raw_file_reader);
-        root::<InstrumentModelT>(&buf).unwrap();
-        InstrumentModel::new(buf)
+        self.check_buffer(InstrumentModel::new(buf), InstrumentModel::check, "instrument model")
     }
 
     /// Retrieve descriptive metadata about the file and summary measures
-    pub fn file_description(&self) -> FileDescription {
-        self.validate_impl();
-        let descr_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("FileDescription"),
-            )
-            .unwrap();
+    pub fn file_description(&self) -> Result<FileDescription, RawFileReaderError> {
+        self.validate_impl()?;
+        let descr_fn = &self.functions.file_description;
         let buf = descr_fn(self.raw_file_reader);
-        root::<FileDescriptionT>(&buf).unwrap();
-        FileDescription::new(buf)
+        self.check_buffer(FileDescription::new(buf), FileDescription::check, "file description")
+    }
+
+    /// Count the spectra at MS levels 1-10, where index `i` holds the number of MS level `i+1`
+    /// spectra in the RAW file.
+    ///
+    /// If the file was opened with [`RawFileReader::open_lazy`], the first call visits every scan
+    /// filter, after which [`FileDescription::spectra_per_ms_level`] is also available.
+    pub fn spectra_per_ms_level(&self) -> Result<Vec<u32>, RawFileReaderError> {
+        self.validate_impl()?;
+        let counts_fn = &self.functions.spectra_per_ms_level;
+        let mut counts = vec![0u32; 10];
+        counts_fn(self.raw_file_reader, counts.as_mut_ptr(), counts.len() as i32);
+        self.take_last_error()?;
+        Ok(counts)
     }
 
     /// Read the `index`-th instrument method.
     ///
     /// If no instrument method is found, the Thermo library returns an
     /// empty string. Instead, this returns `None`.
-    pub fn instrument_method(&self, index: u8) -> Option<InstrumentMethod> {
-        self.validate_impl();
-        let descr_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void, i32) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("InstrumentMethod"),
-            )
-            .unwrap();
+    pub fn instrument_method(&self, index: u8) -> Result<Option<InstrumentMethod>, RawFileReaderError> {
+        self.validate_impl()?;
+        let descr_fn = &self.functions.instrument_method;
         let buf = descr_fn(self.raw_file_reader, index as i32);
-        root::<InstrumentMethodT>(&buf).unwrap();
-        let method = InstrumentMethod::new(buf);
+        let method = self.check_buffer(InstrumentMethod::new(buf), InstrumentMethod::check, "instrument method")?;
         if method.text().is_none() || method.text().is_some_and(|s| s.is_empty()) {
-            None
+            Ok(None)
         } else {
-            Some(method)
+            Ok(Some(method))
         }
     }
 
     /// Get the number of instrument methods that are present in the file
-    pub fn instrument_method_count(&self) -> usize {
-        self.validate_impl();
-        let descr_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> u32>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("InstrumentMethodCount"),
-            )
-            .unwrap();
+    pub fn instrument_method_count(&self) -> Result<usize, RawFileReaderError> {
+        self.validate_impl()?;
+        let descr_fn = &self.functions.instrument_method_count;
         let n = descr_fn(self.raw_file_reader);
-        n as usize
+        if n == 0 {
+            self.take_last_error()?;
+        }
+        Ok(n as usize)
     }
 
     /// Read the total ion current chromatogram spanning the entire MS run
-    pub fn tic(&self) -> ChromatogramDescription {
-        self.validate_impl();
-        let descr_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("GetTIC"),
-            )
-            .unwrap();
+    pub fn tic(&self) -> Result<ChromatogramDescription, RawFileReaderError> {
+        self.validate_impl()?;
+        let descr_fn = &self.functions.tic;
         let buf = descr_fn(self.raw_file_reader);
-        ChromatogramDescription::new(buf)
+        self.check_buffer(ChromatogramDescription::new(buf), ChromatogramDescription::check, "chromatogram")
     }
 
     /// Read the base peak current chromatogram spanning the entire MS run
-    pub fn bpc(&self) -> ChromatogramDescription {
-        self.validate_impl();
-        let descr_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("GetBPC"),
-            )
-            .unwrap();
+    pub fn bpc(&self) -> Result<ChromatogramDescription, RawFileReaderError> {
+        self.validate_impl()?;
+        let descr_fn = &self.functions.bpc;
         let buf = descr_fn(self.raw_file_reader);
-        ChromatogramDescription::new(buf)
+        self.check_buffer(ChromatogramDescription::new(buf), ChromatogramDescription::check, "chromatogram")
+    }
+
+    /// Read a summary chromatogram like the TIC or BPC restricted to the scans that match
+    /// a scan filter within a retention time window.
+    ///
+    /// # Arguments
+    /// - `trace_type`: The kind of summary chromatogram to read, usually [`TraceType::TIC`] or [`TraceType::BasePeak`]
+    /// - `time_range`: The retention time window in minutes to restrict the chromatogram to, or the entire run if `None`.
+    ///   Only scans whose start times fall inside the window, inclusive, contribute, and a window that ends before
+    ///   it starts is an error. A window without any scans yields an empty chromatogram.
+    /// - `filter`: A Thermo scan filter string like `"ms"` or `"FTMS + p NSI Full ms"` restricting which
+    ///   scans contribute signal, or all scans if `None`
+    pub fn summary_chromatogram(
+        &self,
+        trace_type: TraceType,
+        time_range: Option<Range<f64>>,
+        filter: Option<&str>,
+    ) -> Result<ChromatogramDescription, RawFileReaderError> {
+        self.validate_impl()?;
+        let descr_fn = &self.functions.summary_trace;
+        let (start_time, end_time) = time_range
+            .map(|r| (r.start, r.end))
+            .unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+        let filter = filter.unwrap_or_default();
+        let buf = descr_fn(
+            self.raw_file_reader,
+            trace_type as i32,
+            filter.as_ptr(),
+            filter.len() as i32,
+            start_time,
+            end_time,
+        );
+        self.check_buffer(ChromatogramDescription::new(buf), ChromatogramDescription::check, "chromatogram")
+    }
+
+    /// Read the extracted ion chromatogram for the signal within `tolerance` of `mz`.
+    ///
+    /// # Arguments
+    /// - `mz`: The m/z to extract signal around
+    /// - `tolerance`: The mass accuracy tolerance defining the m/z interval around `mz`, which must not be negative
+    /// - `time_range`: The retention time window in minutes to restrict the chromatogram to, or the entire run if `None`.
+    ///   This follows the same rules as [`RawFileReader::summary_chromatogram`].
+    /// - `filter`: A Thermo scan filter string like `"ms"` or `"FTMS + p NSI Full ms"` restricting which
+    ///   scans contribute signal, or all scans if `None`
+    pub fn xic(
+        &self,
+        mz: f64,
+        tolerance: Tolerance,
+        time_range: Option<Range<f64>>,
+        filter: Option<&str>,
+    ) -> Result<ChromatogramDescription, RawFileReaderError> {
+        let mut chromatograms = self.xics(&[(mz, tolerance)], time_range, filter)?;
+        chromatograms
+            .pop()
+            .ok_or_else(|| RawFileReaderError::MalformedBuffer("chromatogram".to_string()))
+    }
+
+    /// Read an extracted ion chromatogram for each `(mz, tolerance)` pair in `targets`.
+    ///
+    /// All of the chromatograms are extracted in a single pass over the RAW file, which
+    /// is much faster than calling [`RawFileReader::xic`] repeatedly. The chromatograms
+    /// are returned in the same order as `targets`.
+    ///
+    /// See [`RawFileReader::xic`] for the meaning of `time_range` and `filter`.
+    pub fn xics(
+        &self,
+        targets: &[(f64, Tolerance)],
+        time_range: Option<Range<f64>>,
+        filter: Option<&str>,
+    ) -> Result<Vec<ChromatogramDescription>, RawFileReaderError> {
+        self.validate_impl()?;
+        let descr_fn = &self.functions.mass_range_traces;
+
+        let mz_ranges: Vec<f64> = targets
+            .iter()
+            .flat_map(|(mz, tolerance)| {
+                let (low, high) = tolerance.bounds(*mz);
+                [low, high]
+            })
+            .collect();
+        let (start_time, end_time) = time_range
+            .map(|r| (r.start, r.end))
+            .unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+        let filter = filter.unwrap_or_default();
+
+        let mut buffers: Vec<RawVec<u8>> = (0..targets.len())
+            .map(|_| RawVec::from_vec(Vec::new()))
+            .collect();
+        descr_fn(
+            self.raw_file_reader,
+            mz_ranges.as_ptr(),
+            targets.len() as i32,
+            filter.as_ptr(),
+            filter.len() as i32,
+            start_time,
+            end_time,
+            buffers.as_mut_ptr(),
+        );
+        self.take_last_error()?;
+        buffers
+            .into_iter()
+            .map(|buf| self.check_buffer(ChromatogramDescription::new(buf), ChromatogramDescription::check, "chromatogram"))
+            .collect()
+    }
+
+    /// Find the index of the spectrum whose scan start time is closest to `time`, in minutes
+    ///
+    /// Returns `None` if the file has no spectra.
+    pub fn index_for_time(&self, time: f64) -> Result<Option<usize>, RawFileReaderError> {
+        self.validate_impl()?;
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let index_fn = &self.functions.scan_number_for_time;
+        let scan_number = index_fn(self.raw_file_reader, time);
+        if scan_number < 1 {
+            self.take_last_error()?;
+            Ok(None)
+        } else {
+            Ok(Some((scan_number as usize) - 1))
+        }
+    }
+
+    /// Find the indices of the spectra whose scan start times fall between `start` and `end`,
+    /// inclusive, in minutes.
+    ///
+    /// If no spectra fall in that window, the range will be empty.
+    pub fn indices_in_time_range(&self, start: f64, end: f64) -> Result<Range<usize>, RawFileReaderError> {
+        self.validate_impl()?;
+        let range_fn = &self.functions.scans_in_time_range;
+        let mut start_scan: i32 = 0;
+        let mut end_scan: i32 = 0;
+        range_fn(self.raw_file_reader, start, end, &mut start_scan, &mut end_scan);
+        self.take_last_error()?;
+        let start_index = (start_scan.max(1) as usize) - 1;
+        let end_index = (end_scan.max(0) as usize).max(start_index);
+        Ok(start_index..end_index)
+    }
+
+    /// Count the number of MS controllers that recorded spectra in the RAW file
+    pub fn ms_controller_count(&self) -> Result<usize, RawFileReaderError> {
+        self.device_count(DeviceKind::MS)
+    }
+
+    /// The 0-based index of the MS controller this reader reads spectra from
+    pub fn ms_controller(&self) -> usize {
+        self.ms_controller
+    }
+
+    /// The path the RAW file was opened from
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Count the number of devices of kind `device` that recorded data in the RAW file
+    pub fn device_count(&self, device: DeviceKind) -> Result<usize, RawFileReaderError> {
+        self.validate_impl()?;
+        let count_fn = &self.functions.device_count;
+        let n = count_fn(self.raw_file_reader, device as i32);
+        if n < 0 {
+            self.take_last_error()?;
+        }
+        Ok(n.max(0) as usize)
+    }
+
+    /// Count the number of scans recorded by a particular device
+    pub fn device_spectrum_count(&self, device: DeviceKind, device_index: usize) -> Result<usize, RawFileReaderError> {
+        if device_index >= self.device_count(device)? {
+            return Err(RawFileReaderError::DeviceNotFound(device, device_index));
+        }
+        let count_fn = &self.functions.device_spectrum_count;
+        let n = count_fn(self.raw_file_reader, device as i32, (device_index as i32) + 1);
+        if n < 0 {
+            self.take_last_error()?;
+        }
+        Ok(n.max(0) as usize)
+    }
+
+    /// Get the absorbance spectrum at `index` recorded by a PDA or scanning UV device
+    pub fn get_wavelength_spectrum(
+        &self,
+        device: DeviceKind,
+        device_index: usize,
+        index: usize,
+    ) -> Result<WavelengthSpectrum, RawFileReaderError> {
+        let len = self.device_spectrum_count(device, device_index)?;
+        if index >= len {
+            return Err(RawFileReaderError::IndexOutOfRange { index, len });
+        }
+        self._impl_get_wavelength_spectrum(device, device_index, index)
+    }
+
+    fn _impl_get_wavelength_spectrum(
+        &self,
+        device: DeviceKind,
+        device_index: usize,
+        index: usize,
+    ) -> Result<WavelengthSpectrum, RawFileReaderError> {
+        let buffer_fn = &self.functions.wavelength_spectrum_for;
+        let buff = buffer_fn(
+            self.raw_file_reader,
+            device as i32,
+            (device_index as i32) + 1,
+            (index as i32) + 1,
+        );
+        self.check_buffer(WavelengthSpectrum::new(buff), WavelengthSpectrum::check, "wavelength spectrum")
+    }
+
+    /// Iterate over the absorbance spectra recorded by a PDA or scanning UV device in
+    /// acquisition order
+    pub fn iter_wavelength_spectra(
+        &self,
+        device: DeviceKind,
+        device_index: usize,
+    ) -> Result<impl Iterator<Item = Result<WavelengthSpectrum, RawFileReaderError>> + '_, RawFileReaderError> {
+        let n = self.device_spectrum_count(device, device_index)?;
+        Ok((0..n).map(move |i| self._impl_get_wavelength_spectrum(device, device_index, i)))
+    }
+
+    /// Read a chromatogram recorded by a non-MS device, like an analog pump pressure channel
+    /// or a UV detector channel.
+    ///
+    /// # Arguments
+    /// - `device`: The kind of device to read from
+    /// - `device_index`: Which device of that kind to read from, starting from zero
+    /// - `trace_type`: The kind of chromatogram to read, which must be one the device records, e.g.
+    ///   [`TraceType::Analog1`] for [`DeviceKind::Analog`], [`TraceType::ChannelA`] for [`DeviceKind::UV`],
+    ///   [`TraceType::TotalAbsorbance`] for [`DeviceKind::PDA`] or [`TraceType::A2DChannel1`] for [`DeviceKind::MSAnalog`]
+    /// - `time_range`: The retention time window in minutes to restrict the chromatogram to, or the entire run if `None`.
+    ///   This follows the same rules as [`RawFileReader::summary_chromatogram`], and a device that recorded no
+    ///   scans yields an empty chromatogram.
+    ///
+    /// Returns [`RawFileReaderError::DeviceNotFound`] if there is no such device. To read a
+    /// [`TraceType::WavelengthRange`] chromatogram, use [`RawFileReader::wavelength_chromatogram`].
+    pub fn device_chromatogram(
+        &self,
+        device: DeviceKind,
+        device_index: usize,
+        trace_type: TraceType,
+        time_range: Option<Range<f64>>,
+    ) -> Result<ChromatogramDescription, RawFileReaderError> {
+        self._impl_device_chromatogram(device, device_index, trace_type, 0.0..0.0, time_range)
+    }
+
+    /// Read the absorbance summed over a range of wavelengths in nanometers from a PDA device
+    ///
+    /// Returns [`RawFileReaderError::DeviceNotFound`] if there is no such device.
+    pub fn wavelength_chromatogram(
+        &self,
+        device_index: usize,
+        wavelengths: Range<f64>,
+        time_range: Option<Range<f64>>,
+    ) -> Result<ChromatogramDescription, RawFileReaderError> {
+        self._impl_device_chromatogram(
+            DeviceKind::PDA,
+            device_index,
+            TraceType::WavelengthRange,
+            wavelengths,
+            time_range,
+        )
+    }
+
+    fn _impl_device_chromatogram(
+        &self,
+        device: DeviceKind,
+        device_index: usize,
+        trace_type: TraceType,
+        range: Range<f64>,
+        time_range: Option<Range<f64>>,
+    ) -> Result<ChromatogramDescription, RawFileReaderError> {
+        if device_index >= self.device_count(device)? {
+            return Err(RawFileReaderError::DeviceNotFound(device, device_index));
+        }
+        let descr_fn = &self.functions.device_trace;
+        let (start_time, end_time) = time_range
+            .map(|r| (r.start, r.end))
+            .unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+        let buf = descr_fn(
+            self.raw_file_reader,
+            device as i32,
+            (device_index as i32) + 1,
+            trace_type as i32,
+            range.start,
+            range.end,
+            start_time,
+            end_time,
+        );
+        self.check_buffer(ChromatogramDescription::new(buf), ChromatogramDescription::check, "chromatogram")
     }
 
     #[inline]
-    fn validate_impl(&self) {
+    fn validate_impl(&self) -> Result<(), RawFileReaderError> {
         if self.raw_file_reader.is_null() {
-            panic!("Internal handle already closed.")
+            Err(RawFileReaderError::HandleClosed)
+        } else {
+            Ok(())
         }
     }
 
-    fn _impl_len(&self) -> usize {
-        self.validate_impl();
-        let index_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> i32>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("SpectrumCount"),
-            )
-            .unwrap();
-        index_fn(self.raw_file_reader) as usize
+    #[inline]
+    fn validate_index(&self, index: usize) -> Result<(), RawFileReaderError> {
+        self.validate_impl()?;
+        let len = self.len();
+        if index >= len {
+            Err(RawFileReaderError::IndexOutOfRange { index, len })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn _impl_take_last_error(functions: &ManagedFunctions) -> Result<(), RawFileReaderError> {
+        let error_fn = &functions.take_last_error;
+        let mut kind: u32 = 0;
+        let message = error_fn(&mut kind);
+        if message.is_empty() {
+            Ok(())
+        } else if kind == 3 {
+            // The same code that `status` uses for a handle that isn't open
+            Err(RawFileReaderError::HandleNotFound)
+        } else {
+            Err(RawFileReaderError::DotNetException(
+                String::from_utf8_lossy(&message).into_owned(),
+            ))
+        }
+    }
+
+    /// Collect the exception caught by the last call into .NET on this thread, if there was one.
+    ///
+    /// Exports signal failure with an empty buffer or a negative number, so this is only worth
+    /// calling after seeing one of those.
+    fn take_last_error(&self) -> Result<(), RawFileReaderError> {
+        Self::_impl_take_last_error(self.functions)
+    }
+
+    /// Verify a FlatBuffer message received from .NET, blaming the exception that produced it
+    /// if there was one.
+    fn check_buffer<T>(
+        &self,
+        message: T,
+        check: impl FnOnce(&T) -> bool,
+        what: &str,
+    ) -> Result<T, RawFileReaderError> {
+        if check(&message) {
+            return Ok(message);
+        }
+        self.take_last_error()?;
+        Err(RawFileReaderError::MalformedBuffer(what.to_string()))
+    }
+
+    fn _impl_len(&self) -> Result<usize, RawFileReaderError> {
+        self.validate_impl()?;
+        let index_fn = &self.functions.spectrum_count;
+        let n = index_fn(self.raw_file_reader);
+        if n < 0 {
+            self.take_last_error()?;
+        }
+        Ok(n.max(0) as usize)
     }
 
     #[inline]
     /// Get the number of spectra in the RAW file
     pub fn len(&self) -> usize {
-        if self.size != 0 {
-            self.size
-        } else {
-            self._impl_len()
-        }
+        self.size
     }
 
     pub fn is_empty(&self) -> bool {
@@ -1302,13 +2908,7 @@ impl RawFileReader {
     /// This method is called on `drop`.
     fn close(&mut self) {
         if !self.raw_file_reader.is_null() {
-            let close_fn = self
-                .context
-                .get_function_with_unmanaged_callers_only::<fn(*mut c_void)>(
-                    pdcstr!("librawfilereader.Exports, librawfilereader"),
-                    pdcstr!("Close"),
-                )
-                .unwrap();
+            let close_fn = &self.functions.close;
             close_fn(self.raw_file_reader);
             self.raw_file_reader = ptr::null_mut();
         }
@@ -1317,19 +2917,85 @@ impl RawFileReader {
     /// Get the spectrum at index `index`
     ///
     /// **Note**: The index of a spectrum is one less than its scan number
-    pub fn get(&self, index: usize) -> Option<RawSpectrum> {
-        if index >= self.len() {
-            return None;
-        }
-        self.validate_impl();
-        let buffer_fn = &self.vget;
+    pub fn get(&self, index: usize) -> Result<RawSpectrum, RawFileReaderError> {
+        self.validate_index(index)?;
+        let buffer_fn = &self.functions.spectrum_description_for_with_options;
         let buffer = buffer_fn(
             self.raw_file_reader,
             (index as i32) + 1,
             self.include_signal as i32,
             self.centroid_spectra as i32,
         );
-        Some(RawSpectrum::new(buffer))
+        self.check_buffer(RawSpectrum::new(buffer), RawSpectrum::check, "spectrum")
+    }
+
+    /// Get the spectra at each index in `indices` in a single call into .NET, in the same order.
+    ///
+    /// This amortizes the cost of crossing the FFI boundary and setting up the data access
+    /// over many spectra, which is worthwhile when reading a large portion of the file.
+    ///
+    /// Fails if any index is out of bounds.
+    pub fn get_many(&self, indices: &[usize]) -> Result<Vec<RawSpectrum>, RawFileReaderError> {
+        for index in indices {
+            self.validate_index(*index)?;
+        }
+        let scan_numbers: Vec<i32> = indices.iter().map(|i| (*i as i32) + 1).collect();
+        self._impl_get_many(&scan_numbers)
+    }
+
+    /// Get the spectra in `index_range` in a single call into .NET, like [`RawFileReader::get_many`].
+    ///
+    /// Like [`RawFileReader::get_many`], this fails with [`RawFileReaderError::IndexOutOfRange`]
+    /// for the first index past the end of the file if the range does not fit in it. An empty
+    /// range always yields no spectra.
+    pub fn get_range(&self, index_range: Range<usize>) -> Result<Vec<RawSpectrum>, RawFileReaderError> {
+        self.validate_impl()?;
+        let len = self.len();
+        if !index_range.is_empty() && index_range.end > len {
+            return Err(RawFileReaderError::IndexOutOfRange { index: index_range.start.max(len), len });
+        }
+        let scan_numbers: Vec<i32> = index_range.map(|i| (i as i32) + 1).collect();
+        self._impl_get_many(&scan_numbers)
+    }
+
+    fn _impl_get_many(&self, scan_numbers: &[i32]) -> Result<Vec<RawSpectrum>, RawFileReaderError> {
+        self.validate_impl()?;
+        if scan_numbers.is_empty() {
+            return Ok(Vec::new());
+        }
+        let buffer_fn = &self.functions.spectrum_descriptions_for;
+        let buffer = buffer_fn(
+            self.raw_file_reader,
+            scan_numbers.as_ptr(),
+            scan_numbers.len() as i32,
+            self.include_signal as i32,
+            self.centroid_spectra as i32,
+        );
+        if buffer.is_empty() {
+            self.take_last_error()?;
+        }
+
+        // Each message is prefixed by its size. Every spectrum views its own message in the
+        // shared buffer, which also keeps the messages aligned as they were written.
+        let buffer = Arc::new(buffer);
+        let mut spectra = Vec::with_capacity(scan_numbers.len());
+        let mut start = 0;
+        while start < buffer.len() {
+            let Some(size) = buffer[start..].first_chunk::<4>() else {
+                return Err(RawFileReaderError::MalformedBuffer("spectrum batch".to_string()));
+            };
+            let end = start + 4 + u32::from_le_bytes(*size) as usize;
+            if end > buffer.len() {
+                return Err(RawFileReaderError::MalformedBuffer("spectrum batch".to_string()));
+            }
+            let spectrum = RawSpectrum::shared(Arc::clone(&buffer), start..end);
+            spectra.push(self.check_buffer(spectrum, RawSpectrum::check, "spectrum")?);
+            start = end;
+        }
+        if spectra.len() != scan_numbers.len() {
+            return Err(RawFileReaderError::MalformedBuffer("spectrum batch".to_string()));
+        }
+        Ok(spectra)
     }
 
     /// Retrieve extra signal information like the baseline, charge and noise
@@ -1337,38 +3003,27 @@ impl RawFileReader {
     ///
     /// ## Note
     /// This method is experimental and may not work reliably.
-    pub fn get_extended_spectrum_data(&self, index: usize, include_sampled_noise: bool) -> Option<ExtendedSpectrumData> {
-        if index >= self.len() {
-            return None;
-        }
-        self.validate_impl();
+    pub fn get_extended_spectrum_data(&self, index: usize, include_sampled_noise: bool) -> Result<ExtendedSpectrumData, RawFileReaderError> {
+        self.validate_index(index)?;
 
-        let buffer_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void, i32, i32) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("AdvancedPacketDataFor"),
-            )
-            .unwrap();
+        let buffer_fn = &self.functions.advanced_packet_data_for;
 
         let buff = buffer_fn(self.raw_file_reader, (index as i32) + 1, include_sampled_noise as i32);
-        Some(ExtendedSpectrumData::new(buff))
+        self.check_buffer(ExtendedSpectrumData::new(buff), ExtendedSpectrumData::check, "extended spectrum data")
     }
 
     /// Read spectrum signal data separately and explicitly without reading all related metadata
-    pub fn get_spectrum_data(&self, index: usize, centroid_spectra: bool) -> Option<OwnedSpectrumData> {
+    pub fn get_spectrum_data(&self, index: usize, centroid_spectra: bool) -> Result<OwnedSpectrumData, RawFileReaderError> {
         let mut mz_bytes: RawVec<u8> = RawVec::from_vec(Vec::new());
         let mut intensity_bytes: RawVec<u8> = RawVec::from_vec(Vec::new());
-        if index >= self.len() {
-            return None;
+        let mut flags_bytes: RawVec<u8> = RawVec::from_vec(Vec::new());
+        self.validate_index(index)?;
+        let buffer_fn = &self.functions.spectrum_data_indirect;
+        let n = buffer_fn(self.raw_file_reader, (index as i32) + 1, centroid_spectra as i32, &mut mz_bytes, &mut intensity_bytes, &mut flags_bytes);
+        if n == 0 {
+            self.take_last_error()?;
         }
-        self.validate_impl();
-        let buffer_fn = self.context.get_function_with_unmanaged_callers_only::<fn(*mut c_void, i32, i32, *mut RawVec<u8>, *mut RawVec<u8>) -> u32>(
-            pdcstr!("librawfilereader.Exports, librawfilereader"),
-            pdcstr!("GetSpectrumDataIndirect")
-        ).unwrap();
-        let n = buffer_fn(self.raw_file_reader, (index as i32) + 1, centroid_spectra as i32, &mut mz_bytes, &mut intensity_bytes);
-        Some(OwnedSpectrumData::new(mz_bytes, intensity_bytes, n as usize))
+        Ok(OwnedSpectrumData::new(mz_bytes, intensity_bytes, flags_bytes, n as usize))
     }
 
     /// Get the trailer extra values for scan at `index`.
@@ -1377,53 +3032,148 @@ impl RawFileReader {
     /// is under dependent upon the instrument and method used. All values
     /// are passed as strings which must be parsed into the appropriate Rust
     /// type to be useful.
-    pub fn get_raw_trailers_for(&self, index: usize) -> Option<TrailerValues> {
-        if index >= self.len() {
-            return None;
-        }
-        self.validate_impl();
+    pub fn get_raw_trailers_for(&self, index: usize) -> Result<TrailerValues, RawFileReaderError> {
+        self.validate_index(index)?;
 
-        let buffer_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void, i32) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("GetRawTrailerValuesFor"),
-            )
-            .unwrap();
+        let buffer_fn = &self.functions.raw_trailer_values_for;
 
         let buff = buffer_fn(self.raw_file_reader, (index as i32) + 1);
-        Some(TrailerValues::new(buff))
+        self.check_buffer(TrailerValues::new(buff), TrailerValues::check, "trailer values")
     }
 
-    pub fn get_status_logs(&self) -> Option<StatusLogCollection> {
-        self.validate_impl();
+    /// Get the trailer extra values for scan at `index`, converted to the types
+    /// declared by [`RawFileReader::trailer_header_schema`].
+    pub fn get_trailers_for(&self, index: usize) -> Result<TypedTrailerValues, RawFileReaderError> {
+        self.validate_index(index)?;
 
-        let descr_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("GetStatusLogs"),
-            )
-            .unwrap();
+        let buffer_fn = &self.functions.typed_trailer_values_for;
+
+        let buff = buffer_fn(self.raw_file_reader, (index as i32) + 1);
+        self.check_buffer(TypedTrailerValues::new(buff), TypedTrailerValues::check, "trailer values")
+    }
+
+    /// Read the values of the trailer extra labeled `label` for every scan in `index_range`
+    /// in a single call, typed according to its header.
+    ///
+    /// Returns `None` if there is no trailer with that label.
+    pub fn trailer_column(&self, label: &str, index_range: Range<usize>) -> Result<Option<TrailerColumn>, RawFileReaderError> {
+        self.validate_impl()?;
+        let end = index_range.end.min(self.len());
+        let start = index_range.start.min(end);
+
+        let buffer_fn = &self.functions.trailer_column;
+
+        let buff = buffer_fn(
+            self.raw_file_reader,
+            label.as_ptr(),
+            label.len() as i32,
+            (start as i32) + 1,
+            end as i32,
+        );
+        let column = self.check_buffer(TrailerColumn::new(buff), TrailerColumn::check, "trailer column")?;
+        Ok(column.view().label().is_some().then_some(column))
+    }
+
+    /// Describe the label and data type of each trailer extra header in the RAW file
+    pub fn trailer_header_schema(&self) -> Result<TrailerHeaders, RawFileReaderError> {
+        self.validate_impl()?;
+
+        let buffer_fn = &self.functions.trailer_headers;
+
+        let buff = buffer_fn(self.raw_file_reader);
+        self.check_buffer(TrailerHeaders::new(buff), TrailerHeaders::check, "trailer headers")
+    }
+
+    /// Read the instrument tune settings for each tune segment in the RAW file
+    pub fn tune_data(&self) -> Result<TuneData, RawFileReaderError> {
+        self.validate_impl()?;
+
+        let descr_fn = &self.functions.tune_data;
 
         let buff = descr_fn(self.raw_file_reader);
-        Some(StatusLogCollection::new(buff))
+        self.check_buffer(TuneData::new(buff), TuneData::check, "tune data")
+    }
+
+    /// Read the instrument error log, pairing each message with the retention time
+    /// it was recorded at
+    pub fn error_log(&self) -> Result<ErrorLog, RawFileReaderError> {
+        self.validate_impl()?;
+
+        let descr_fn = &self.functions.error_log;
+
+        let buff = descr_fn(self.raw_file_reader);
+        self.check_buffer(ErrorLog::new(buff), ErrorLog::check, "error log")
+    }
+
+    pub fn get_status_logs(&self) -> Result<StatusLogCollection, RawFileReaderError> {
+        self.validate_impl()?;
+
+        let descr_fn = &self.functions.status_logs;
+
+        let buff = descr_fn(self.raw_file_reader);
+        self.check_buffer(StatusLogCollection::new(buff), StatusLogCollection::check, "status logs")
+    }
+
+    /// Find the distinct scan filters used in the RAW file, grouping the indices of the
+    /// spectra acquired with each one.
+    ///
+    /// This is computed in a single call, which is much faster than reading each spectrum
+    /// with [`RawFileReader::get`] to inspect its filter.
+    pub fn unique_filters(&self) -> Result<ScanFilterGroups, RawFileReaderError> {
+        self.validate_impl()?;
+
+        let descr_fn = &self.functions.unique_filters;
+
+        let buff = descr_fn(self.raw_file_reader);
+        self.check_buffer(ScanFilterGroups::new(buff), ScanFilterGroups::check, "scan filters")
+    }
+
+    /// Get the parent-child relationships between all spectra in the RAW file.
+    ///
+    /// This is built in a single call the first time it is requested and cached for
+    /// the lifetime of the reader.
+    pub fn acquisition_tree(&self) -> Result<&AcquisitionTree, RawFileReaderError> {
+        if let Some(tree) = self.acquisition_tree.get() {
+            return Ok(tree);
+        }
+        self.validate_impl()?;
+        let tree_fn = &self.functions.acquisition_tree;
+        let buff = tree_fn(self.raw_file_reader);
+        let view = match root::<AcquisitionTreeT>(&buff) {
+            Ok(view) => view,
+            Err(_) => {
+                self.take_last_error()?;
+                return Err(RawFileReaderError::MalformedBuffer("acquisition tree".to_string()));
+            }
+        };
+        let parents: Vec<i32> = view.parents().map(|v| v.iter().collect()).unwrap_or_default();
+        let tree = AcquisitionTree::from_parents(&parents, self.len());
+        Ok(self.acquisition_tree.get_or_init(|| tree))
+    }
+
+    /// Get the indices of the spectra whose precursors were selected from the spectrum at `index`,
+    /// like the MS2 spectra of an MS1 spectrum, in acquisition order.
+    ///
+    /// See [`RawFileReader::acquisition_tree`].
+    pub fn children_of(&self, index: usize) -> Result<&[usize], RawFileReaderError> {
+        self.validate_index(index)?;
+        Ok(self.acquisition_tree()?.children_of(index))
     }
 
     /// A utility for debugging, get a spectrum and access some of its fields, printing them
     /// to `STDOUT`
-    pub fn describe(&self, index: usize) {
-        if let Some(buf) = self.get(index) {
-            let descr = buf.view();
-            println!(
-                "{}|{:?} -> {:?} | has data? {}",
-                descr.index(),
-                descr.polarity(),
-                descr.precursor(),
-                descr.data().is_some()
-            );
-            println!("Filter: {}", descr.filter_string().unwrap());
-            let acq = descr.acquisition().unwrap();
+    pub fn describe(&self, index: usize) -> Result<(), RawFileReaderError> {
+        let buf = self.get(index)?;
+        let descr = buf.view();
+        println!(
+            "{}|{:?} -> {:?} | has data? {}",
+            descr.index(),
+            descr.polarity(),
+            descr.precursor(),
+            descr.data().is_some()
+        );
+        println!("Filter: {}", descr.filter_string().unwrap_or_default());
+        if let Some(acq) = descr.acquisition() {
             println!(
                 "{:?} {:?} {}-{}",
                 acq.mass_analyzer(),
@@ -1431,19 +3181,16 @@ impl RawFileReader {
                 acq.low_mz(),
                 acq.high_mz()
             );
-            if let Some(dat) = descr.data() {
-                let intens_opt = dat.intensity();
-                let intens = intens_opt.as_ref().unwrap();
-                let val = intens.iter().max_by(|a, b| a.total_cmp(b)).unwrap();
-                println!(
-                    "Received {} data points, base peak intensity {}",
-                    intens.len(),
-                    val
-                );
-            }
-        } else {
-            println!("Spectrum at {index}/{} not found", self.len())
         }
+        if let Some(intens) = descr.data().and_then(|dat| dat.intensity()) {
+            let val = intens.iter().max_by(|a, b| a.total_cmp(b)).unwrap_or_default();
+            println!(
+                "Received {} data points, base peak intensity {}",
+                intens.len(),
+                val
+            );
+        }
+        Ok(())
     }
 
     /// Create an iterator over the RAW file, reading successive spectra
@@ -1451,38 +3198,40 @@ impl RawFileReader {
         RawFileReaderIter::new(self)
     }
 
-    /// Retrieve the status of the .NET `RawFileReader`
-    pub fn status(&self) -> RawFileReaderError {
-        self.validate_impl();
-        let status_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> u32>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("Status"),
-            )
-            .unwrap();
-        let code = status_fn(self.raw_file_reader);
-        code.into()
+    /// Retrieve the status of the .NET `RawFileReader`, failing with the problem
+    /// it encountered opening the file, if any
+    pub fn status(&self) -> Result<(), RawFileReaderError> {
+        self.validate_impl()?;
+        let status_fn = &self.functions.status;
+        match status_fn(self.raw_file_reader) {
+            0 => Ok(()),
+            1 => Err(RawFileReaderError::FileNotFound(self.path.clone())),
+            2 => Err(RawFileReaderError::InvalidFormat(self.error_message()?.unwrap_or_default())),
+            3 => Err(RawFileReaderError::HandleNotFound),
+            4 => Err(RawFileReaderError::ControllerNotFound(self.ms_controller)),
+            _ => {
+                self.take_last_error()?;
+                Err(RawFileReaderError::Error(self.error_message()?.unwrap_or_default()))
+            }
+        }
     }
 
     /// Retrieve the "file error" status message. This message may
     /// or may not be meaningful depending upon what went wrong.
-    pub fn error_message(&self) -> Option<String> {
-        self.validate_impl();
-        let status_fn = self
-            .context
-            .get_function_with_unmanaged_callers_only::<fn(*mut c_void) -> RawVec<u8>>(
-                pdcstr!("librawfilereader.Exports, librawfilereader"),
-                pdcstr!("GetErrorMessageFor"),
-            )
-            .unwrap();
+    pub fn error_message(&self) -> Result<Option<String>, RawFileReaderError> {
+        self.validate_impl()?;
+        let status_fn = &self.functions.error_message_for;
         let result = status_fn(self.raw_file_reader);
-        if result.len() == 0 || result.len() == 1 && result[0] == 0 {
-            return None;
+        if result.is_empty() {
+            self.take_last_error()?;
+            return Ok(None);
         }
-        let message =
-            String::from_utf8(result.to_vec()).expect("Failed to decode message, invalid UTF8");
-        Some(message)
+        if result.len() == 1 && result[0] == 0 {
+            return Ok(None);
+        }
+        let message = String::from_utf8(result.to_vec())
+            .map_err(|_| RawFileReaderError::MalformedBuffer("error message".to_string()))?;
+        Ok(Some(message))
     }
 }
 
@@ -1508,13 +3257,13 @@ impl<'a> RawFileReaderIter<'a> {
 }
 
 impl<'a> Iterator for RawFileReaderIter<'a> {
-    type Item = RawSpectrum;
+    type Item = Result<RawSpectrum, RawFileReaderError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index < self.size {
             let buffer = self.handle.get(self.index);
             self.index += 1;
-            buffer
+            Some(buffer)
         } else {
             None
         }
@@ -1542,13 +3291,13 @@ impl RawFileReaderIntoIter {
 }
 
 impl Iterator for RawFileReaderIntoIter {
-    type Item = RawSpectrum;
+    type Item = Result<RawSpectrum, RawFileReaderError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index < self.size {
             let buffer = self.handle.get(self.index);
             self.index += 1;
-            buffer
+            Some(buffer)
         } else {
             None
         }
@@ -1556,7 +3305,7 @@ impl Iterator for RawFileReaderIntoIter {
 }
 
 impl IntoIterator for RawFileReader {
-    type Item = RawSpectrum;
+    type Item = Result<RawSpectrum, RawFileReaderError>;
 
     type IntoIter = RawFileReaderIntoIter;
 
@@ -1566,7 +3315,7 @@ impl IntoIterator for RawFileReader {
 }
 
 impl<'a> IntoIterator for &'a RawFileReader {
-    type Item = RawSpectrum;
+    type Item = Result<RawSpectrum, RawFileReaderError>;
 
     type IntoIter = RawFileReaderIter<'a>;
 
@@ -1619,6 +3368,69 @@ mod test {
         assert_eq!(dat.len(), 19800);
         let dat = handle.get_spectrum_data(1, true).unwrap();
         assert_eq!(dat.len(), 0);
+
+        assert!(matches!(
+            handle.get(48),
+            Err(RawFileReaderError::IndexOutOfRange { index: 48, len: 48 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_errors() -> io::Result<()> {
+        let missing = PathBuf::from("../tests/data/missing.RAW");
+        match RawFileReader::open(&missing) {
+            Err(RawFileReaderError::FileNotFound(path)) => assert_eq!(path, missing),
+            other => panic!("expected FileNotFound, got {other:?}"),
+        }
+
+        assert!(matches!(
+            RawFileReader::open("../tests/data/small.mgf"),
+            Err(RawFileReaderError::InvalidFormat(_))
+        ));
+
+        let mut handle = RawFileReader::open("../tests/data/small.RAW")?;
+        assert_eq!(handle.path(), Path::new("../tests/data/small.RAW"));
+        handle.close();
+        assert!(matches!(handle.get(0), Err(RawFileReaderError::HandleClosed)));
+        assert!(matches!(handle.file_description(), Err(RawFileReaderError::HandleClosed)));
+        assert!(matches!(handle.status(), Err(RawFileReaderError::HandleClosed)));
+
+        // A handle .NET no longer knows about, like one closed behind the reader's back
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        (handle.functions.close)(handle.raw_file_reader);
+        assert!(matches!(handle.get(0), Err(RawFileReaderError::HandleNotFound)));
+        assert!(matches!(handle.status(), Err(RawFileReaderError::HandleNotFound)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_many() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        let batch = handle.get_range(0..10)?;
+        assert_eq!(batch.len(), 10);
+        for (i, spec) in batch.iter().enumerate() {
+            assert!(spec.check());
+            let single = handle.get(i).unwrap();
+            assert_eq!(spec.index(), i);
+            assert_eq!(spec.data().unwrap().mz(), single.data().unwrap().mz());
+            assert_eq!(spec.data().unwrap().intensity(), single.data().unwrap().intensity());
+            assert_eq!(spec.time(), single.time());
+        }
+
+        let batch = handle.get_many(&[5, 2]).unwrap();
+        assert_eq!(batch.iter().map(|s| s.index()).collect::<Vec<_>>(), vec![5, 2]);
+        assert!(matches!(
+            handle.get_many(&[0, 48]),
+            Err(RawFileReaderError::IndexOutOfRange { index: 48, len: 48 })
+        ));
+        assert_eq!(handle.get_range(40..48)?.len(), 8);
+        assert!(matches!(
+            handle.get_range(40..100),
+            Err(RawFileReaderError::IndexOutOfRange { index: 48, len: 48 })
+        ));
+        assert!(handle.get_range(60..60)?.is_empty());
         Ok(())
     }
 
@@ -1635,6 +3447,58 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_typed_trailers() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        let schema = handle.trailer_header_schema()?;
+        assert_eq!(schema.len(), 26);
+        let header = schema.get_label("Charge State").unwrap();
+        assert_eq!(header.data_type, TrailerDataType::Short);
+
+        let trailers = handle.get_trailers_for(5).unwrap();
+        assert_eq!(trailers.len(), schema.len());
+        let raw = handle.get_raw_trailers_for(5).unwrap();
+        let charge: i64 = raw.get_label("Charge State").unwrap().value.parse().unwrap();
+        assert_eq!(
+            trailers.get_label("Charge State"),
+            Some(TypedTrailerValue::Int(charge))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailer_column() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        let column = handle.trailer_column("Ion Injection Time (ms)", 0..handle.len())?.unwrap();
+        assert_eq!(column.len(), 48);
+        assert_eq!(column.data_type(), TrailerDataType::Float);
+        let values = column.float_values().unwrap();
+        for (i, value) in values.iter().enumerate().step_by(10) {
+            let raw = handle.get_raw_trailers_for(i).unwrap();
+            let expected: f64 = raw.get_label("Ion Injection Time (ms)").unwrap().value.parse().unwrap();
+            assert!((value - expected).abs() < 1e-2);
+        }
+        assert!((column.times().unwrap()[2] - handle.get(2).unwrap().time()).abs() < 1e-6);
+
+        assert!((0..column.len()).all(|i| column.is_valid(i)));
+        assert!(column.iter().all(|(_, v)| matches!(v, Some(TypedTrailerValue::Float(_)))));
+
+        let column = handle.trailer_column("Charge State", 10..20)?.unwrap();
+        assert_eq!(column.len(), 10);
+        assert!(column.int_values().is_some());
+        for (i, (_, value)) in column.iter().enumerate() {
+            let raw = handle.get_raw_trailers_for(10 + i).unwrap();
+            let expected: i64 = raw.get_label("Charge State").unwrap().value.parse().unwrap();
+            assert_eq!(value, Some(TypedTrailerValue::Int(expected)));
+        }
+        assert!(column.get(10).is_none());
+
+        assert!(handle.trailer_column("Not A Trailer", 0..10)?.is_none());
+        Ok(())
+    }
+
     #[test]
     fn test_read_512kb() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small.RAW")?;
@@ -1654,7 +3518,7 @@ mod test {
         let (m1, mn) =
             handle
                 .iter()
-                .map(|s| s.view().ms_level())
+                .map(|s| s.unwrap().view().ms_level())
                 .fold((0, 0), |(m1, mn), level| {
                     if level > 1 {
                         (m1, mn + 1)
@@ -1672,7 +3536,7 @@ mod test {
     #[test]
     fn test_tic() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small.RAW")?;
-        let tic = handle.tic();
+        let tic = handle.tic()?;
         assert_eq!(tic.trace_type(), TraceType::TIC);
         assert_eq!(tic.start_index(), 0);
         assert_eq!(tic.end_index(), 48);
@@ -1692,7 +3556,7 @@ mod test {
     #[test]
     fn test_bpc() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small.RAW")?;
-        let bpc = handle.bpc();
+        let bpc = handle.bpc()?;
         assert_eq!(bpc.trace_type(), TraceType::BasePeak);
         assert_eq!(bpc.start_index(), 0);
         assert_eq!(bpc.end_index(), 48);
@@ -1709,10 +3573,478 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_filtered_tic() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let tic = handle.summary_chromatogram(TraceType::TIC, None, None)?;
+        assert_eq!(tic.data().unwrap().time().len(), 48);
+
+        let ms1_tic = handle.summary_chromatogram(TraceType::TIC, None, Some("ms"))?;
+        assert_eq!(ms1_tic.trace_type(), TraceType::TIC);
+        assert_eq!(ms1_tic.data().unwrap().time().len(), 14);
+
+        let ms2_bpc = handle.summary_chromatogram(TraceType::BasePeak, None, Some("ms2"))?;
+        assert_eq!(ms2_bpc.trace_type(), TraceType::BasePeak);
+        assert_eq!(ms2_bpc.data().unwrap().time().len(), 34);
+
+        let t5 = handle.get(5)?.time();
+        let t10 = handle.get(10)?.time();
+        let windowed = handle.summary_chromatogram(TraceType::TIC, Some(t5..t10), None)?;
+        let times = windowed.data().unwrap().time();
+        assert_eq!(times.len(), 6);
+        assert!(times.iter().all(|t| *t >= t5 - 1e-6 && *t <= t10 + 1e-6));
+
+        let windowed_ms1 = handle.summary_chromatogram(TraceType::TIC, Some(t5..t10), Some("ms"))?;
+        assert!(windowed_ms1.data().unwrap().time().len() < times.len());
+
+        // Bounds between scans must not pull in the closest scan outside of the window
+        let t6 = handle.get(6)?.time();
+        let t9 = handle.get(9)?.time();
+        let start = (t5 + t6) / 2.0;
+        let end = (t9 + t10) / 2.0;
+        let windowed = handle.summary_chromatogram(TraceType::BasePeak, Some(start..end), None)?;
+        let times = windowed.data().unwrap().time();
+        assert_eq!(times.len(), 4);
+        assert!(times.iter().all(|t| *t >= start && *t <= end));
+
+        let between = handle.summary_chromatogram(TraceType::TIC, Some(start..(start + t6) / 2.0), None)?;
+        assert!(between.data().unwrap().time().is_empty());
+        assert!(handle.summary_chromatogram(TraceType::TIC, Some(t10..t5), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_xic() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let spec = handle.get(0).unwrap();
+        let data = spec.data().unwrap();
+        let (base_peak_mz, _) = data
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+
+        let xic = handle.xic(base_peak_mz, Tolerance::PPM(10.0), None, Some("ms"))?;
+        assert_eq!(xic.trace_type(), TraceType::MassRange);
+        let data = xic.data().unwrap();
+        assert_eq!(data.time().len(), 14);
+        assert!(data.intensity()[0] > 0.0);
+
+        let xics = handle.xics(
+            &[(base_peak_mz, Tolerance::PPM(10.0)), (base_peak_mz, Tolerance::Da(0.5))],
+            None,
+            Some("ms"),
+        )?;
+        assert_eq!(xics.len(), 2);
+        let narrow = xics[0].data().unwrap().intensity().iter().sum::<f32>();
+        let wide = xics[1].data().unwrap().intensity().iter().sum::<f32>();
+        assert!(wide >= narrow);
+
+        let t1 = handle.get(1)?.time();
+        let t14 = handle.get(14)?.time();
+        let start = (spec.time() + t1) / 2.0;
+        let xic = handle.xic(base_peak_mz, Tolerance::PPM(10.0), Some(start..t14), Some("ms"))?;
+        let times = xic.data().unwrap().time();
+        assert!(!times.is_empty());
+        assert!(times.iter().all(|t| *t >= start && *t <= t14));
+        assert!(times.len() < 14);
+
+        assert!(handle.xic(base_peak_mz, Tolerance::PPM(10.0), Some(t14..t1), None).is_err());
+        assert!(handle.xic(base_peak_mz, Tolerance::Da(-1.0), None, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_lookup() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let t5 = handle.get(5).unwrap().time();
+        let t10 = handle.get(10).unwrap().time();
+        assert_eq!(handle.index_for_time(t10)?, Some(10));
+        assert_eq!(handle.indices_in_time_range(t5, t10)?, 5..11);
+        assert!(handle.indices_in_time_range(t10, t5)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ms_controller() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        assert_eq!(handle.ms_controller_count()?, 1);
+        assert_eq!(handle.ms_controller(), 0);
+        let spec = handle.get(2).unwrap();
+        assert_eq!(spec.native_id(), "controllerType=0 controllerNumber=1 scan=3");
+        assert!(RawFileReader::open_ms_controller("../tests/data/small.RAW", 1).is_err());
+        assert!(matches!(
+            RawFileReader::open_lazy_ms_controller("../tests/data/small.RAW", 1),
+            Err(RawFileReaderError::ControllerNotFound(1))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_device_count() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        assert_eq!(handle.device_count(DeviceKind::MS)?, 1);
+        let n_uv = handle.device_count(DeviceKind::UV)?;
+        assert!(matches!(
+            handle.device_chromatogram(DeviceKind::UV, n_uv, TraceType::ChannelA, None),
+            Err(RawFileReaderError::DeviceNotFound(DeviceKind::UV, i)) if i == n_uv
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_wavelength_spectra() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        assert_eq!(handle.device_count(DeviceKind::PDA)?, 0);
+        assert!(matches!(
+            handle.get_wavelength_spectrum(DeviceKind::PDA, 0, 0),
+            Err(RawFileReaderError::DeviceNotFound(DeviceKind::PDA, 0))
+        ));
+        assert!(matches!(
+            handle.iter_wavelength_spectra(DeviceKind::PDA, 0),
+            Err(RawFileReaderError::DeviceNotFound(DeviceKind::PDA, 0))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_wavelength_spectrum_decoding() {
+        use crate::schema::{WavelengthSpectrumT, WavelengthSpectrumTArgs};
+
+        let wavelengths = [200.0, 202.0, 204.0, 206.0, 208.0, 210.0];
+        let absorbances = [0.5f32, 1.25, 3.0, 2.5, 0.75, 0.125];
+
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let wavelength = builder.create_vector(&wavelengths);
+        let absorbance = builder.create_vector(&absorbances);
+        let offset = WavelengthSpectrumT::create(
+            &mut builder,
+            &WavelengthSpectrumTArgs {
+                index: 3,
+                time: 1.5,
+                low_wavelength: 200.0,
+                high_wavelength: 210.0,
+                wavelength: Some(wavelength),
+                absorbance: Some(absorbance),
+            },
+        );
+        builder.finish(offset, None);
+        let spectrum = WavelengthSpectrum::new(RawVec::from_vec(builder.finished_data().to_vec()));
+
+        assert!(spectrum.check());
+        assert_eq!(spectrum.index(), 2);
+        assert_eq!(spectrum.time(), 1.5);
+        assert_eq!(spectrum.len(), 6);
+        assert_eq!(spectrum.wavelength().unwrap().as_ref(), &wavelengths);
+        assert_eq!(spectrum.absorbance().unwrap().as_ref(), &absorbances);
+        assert!(spectrum
+            .wavelength()
+            .unwrap()
+            .iter()
+            .all(|w| *w >= spectrum.low_wavelength() && *w <= spectrum.high_wavelength()));
+    }
+
+    #[test]
+    fn test_precursors() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        for spec in handle.iter() {
+            let spec = spec?;
+            let precursors: Vec<_> = spec.precursors().collect();
+            if spec.ms_level() == 1 {
+                assert!(precursors.is_empty());
+            } else {
+                assert_eq!(precursors.len(), 1);
+                assert_eq!(*precursors[0], *spec.precursor().unwrap());
+
+                // Every MSn level of the method isolates a 2 m/z wide window
+                let trailers = handle.get_raw_trailers_for(spec.index())?;
+                let key = format!("MS{} Isolation Width", spec.ms_level());
+                let width: f64 = trailers.get_label(&key).unwrap().value.parse().unwrap();
+                assert!((width - 2.0).abs() < 1e-6);
+                let window = precursors[0].isolation_window();
+                assert!((window.upper() - window.lower() - width).abs() < 1e-6);
+                assert!(window.lower() < window.target() && window.target() < window.upper());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_lazy() -> io::Result<()> {
+        let handle = RawFileReader::open_lazy("../tests/data/small.RAW")?;
+        assert_eq!(handle.len(), 48);
+        assert_eq!(handle.get(2).unwrap().ms_level(), 2);
+
+        // Counting spectra per MS level visits every scan, so it waits until it is asked for
+        assert!(handle.file_description()?.spectra_per_ms_level().is_none());
+        let counts = handle.spectra_per_ms_level()?;
+        assert_eq!(counts[0], 14);
+        assert_eq!(counts[1], 34);
+        let fd = handle.file_description()?;
+        let described: Vec<_> = fd.spectra_per_ms_level().unwrap().iter().collect();
+        assert_eq!(described, counts);
+
+        let eager = RawFileReader::open("../tests/data/small.RAW")?;
+        assert_eq!(
+            handle.instrument_model()?.configurations().count(),
+            eager.instrument_model()?.configurations().count()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_acquisition_tree() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        let tree = handle.acquisition_tree()?;
+        assert_eq!(tree.len(), 48);
+        assert_eq!(tree.roots().count(), 14);
+        assert!(handle.children_of(1)?.contains(&2));
+        assert_eq!(tree.parent_of(2), Some(1));
+        for spec in handle.iter() {
+            let spec = spec?;
+            if let Some(prec) = spec.precursor() {
+                assert_eq!(tree.parent_of(spec.index()), Some(prec.parent_index() as usize));
+            }
+        }
+        let n_children: usize = tree.roots().map(|i| tree.descendants_of(i).len()).sum();
+        assert_eq!(n_children, 34);
+        Ok(())
+    }
+
+    #[test]
+    fn test_activation_details() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        assert_eq!(handle.get(1).unwrap().precursors().count(), 0);
+
+        let spec = handle.get(2).unwrap();
+        let precursor = spec.precursors().next().unwrap();
+        let details = precursor.activation_details().unwrap();
+        let steps: Vec<_> = details.steps().collect();
+        assert!(!steps.is_empty());
+        let activation = spec.precursor().unwrap().activation();
+        assert_eq!(steps[0].dissociation_method(), activation.dissociation_method());
+        assert_eq!(steps[0].collision_energy(), activation.collision_energy());
+        assert!(!steps[0].supplemental());
+        assert_eq!(details.has_supplemental_activation(), details.supplemental_energy().is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_precursor_intensity() -> io::Result<()> {
+        let mut handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        let spec = handle.get(2).unwrap();
+        let prec = spec.precursor().unwrap();
+        assert_eq!(prec.parent_index(), 1);
+        assert!(prec.intensity() > 0.0);
+
+        let parent = handle.get(1).unwrap();
+        let parent_data = parent.data().unwrap();
+        let (low, high) = Tolerance::PPM(20.0).bounds(prec.mz());
+        let expected = parent_data
+            .iter()
+            .filter(|(mz, _)| *mz >= low && *mz <= high)
+            .map(|(_, int)| int)
+            .fold(0.0f32, f32::max);
+        assert!((prec.intensity() - expected).abs() <= expected * 1e-3);
+
+        handle.set_signal_loading(false);
+        let spec = handle.get(2).unwrap();
+        assert_eq!(spec.precursor().unwrap().intensity(), 0.0);
+        handle.set_signal_loading(true);
+
+        handle.set_precursor_intensity_tolerance(None)?;
+        let spec = handle.get(2).unwrap();
+        assert_eq!(spec.precursor().unwrap().intensity(), 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_statistics() -> io::Result<()> {
+        let mut handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        let spec = handle.get(2).unwrap();
+        let data = spec.data().unwrap();
+        let max_intensity = data.intensity().iter().copied().fold(0.0f32, f32::max);
+        let stats = spec.statistics().unwrap();
+        assert_eq!(stats.is_centroid(), spec.mode() == SpectrumMode::Centroid);
+        assert!(stats.low_mass() < stats.high_mass());
+
+        handle.set_signal_loading(false);
+        let spec = handle.get(2).unwrap();
+        assert!(spec.data().is_none());
+        assert!((spec.base_peak_intensity().unwrap() - max_intensity as f64).abs() / (max_intensity as f64) < 1e-3);
+        assert!(spec.tic().unwrap() > 0.0);
+        assert!(spec.base_peak_mz().unwrap() > 0.0);
+        assert_eq!(spec.spectrum_count(), Some(handle.len()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_centroid_flags() -> io::Result<()> {
+        let mut handle = RawFileReader::open("../tests/data/small.RAW")?;
+        handle.set_centroid_spectra(true);
+
+        // The FTMS profile scan is centroided from its label stream, which flags every peak
+        let spec = handle.get(0).unwrap();
+        assert_eq!(spec.mode(), SpectrumMode::Profile);
+        let data = spec.data().unwrap();
+        let flags = data.flags().unwrap();
+        assert_eq!(flags.len(), data.len());
+        let known = PeakFlags::SATURATED.0
+            | PeakFlags::FRAGMENTED.0
+            | PeakFlags::MERGED.0
+            | PeakFlags::EXCEPTION.0
+            | PeakFlags::REFERENCE.0
+            | PeakFlags::MODIFIED.0
+            | PeakFlags::LOCK_PEAK.0;
+        assert!(flags.iter().all(|f| f.0 & !known == 0));
+        let owned = handle.get_spectrum_data(0, true).unwrap();
+        assert_eq!(owned.flags(), Some(flags));
+
+        // Centroid scans are read as they were stored, without a label stream
+        let spec = handle.get(5).unwrap();
+        assert_eq!(spec.mode(), SpectrumMode::Centroid);
+        assert!(spec.data().unwrap().flags().is_none());
+        assert!(handle.get_spectrum_data(5, true).unwrap().flags().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_segments() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+
+        let spec = handle.get(1).unwrap();
+        assert_eq!(spec.mode(), SpectrumMode::Profile);
+        let acq = Acquisition::new(spec.acquisition().unwrap());
+        let windows: Vec<_> = acq.scan_windows().collect();
+        assert!(!windows.is_empty());
+        let data = spec.data().unwrap();
+        let segments = data.segments();
+        assert_eq!(segments.len(), windows.len());
+        assert_eq!(segments.last().unwrap().end, data.len());
+        let mz = &data.mz()[segments[0].clone()];
+        let (low, high) = windows[0];
+        assert!(mz.iter().all(|v| *v >= low - 1.0 && *v <= high + 1.0));
+
+        let spec = handle.get(5).unwrap();
+        let data = spec.data().unwrap();
+        assert!(data.segment_offsets().is_none());
+        assert_eq!(data.segments().len(), 1);
+        assert_eq!(data.segments()[0], 0..data.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_filter() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let spec = handle.get(0).unwrap();
+        let filter = spec.scan_filter().unwrap();
+        assert_eq!(filter.ms_order(), MSOrder::MS);
+        assert_eq!(filter.polarity(), spec.polarity());
+        assert_eq!(filter.reactions().count(), 0);
+        assert_eq!(filter.mass_ranges().count(), 1);
+
+        let spec = handle.get(2).unwrap();
+        let filter = spec.scan_filter().unwrap();
+        assert_eq!(filter.ms_order(), MSOrder::MS2);
+        assert_eq!(filter.scan_mode(), spec.scan_mode());
+        let reaction = filter.reactions().next().unwrap();
+        let prec = spec.precursor().unwrap();
+        assert_eq!(reaction.dissociation_method(), prec.activation().dissociation_method());
+        assert_eq!(reaction.collision_energy(), prec.activation().collision_energy());
+        assert_eq!(
+            filter.is_dependent(),
+            spec.filter_string().unwrap().contains(" d ")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique_filters() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let groups = handle.unique_filters()?;
+        assert!(!groups.is_empty());
+        let total: usize = groups.iter().map(|g| g.len()).sum();
+        assert_eq!(total, 48);
+
+        let spec = handle.get(2).unwrap();
+        let group = groups.get_filter(spec.filter_string().unwrap()).unwrap();
+        assert!(group.indices().contains(&2));
+        assert_eq!(group.scan_filter().unwrap().ms_order(), MSOrder::MS2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tune_data() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let tune = handle.tune_data()?;
+        assert!(!tune.is_empty());
+        let segment = tune.get(0).unwrap();
+        assert_eq!(segment.index(), 0);
+        assert!(!segment.is_empty());
+        assert_eq!(segment.iter().count(), segment.len());
+        assert!(segment.iter().any(|(_, v)| v.as_float().is_some()));
+        let (label, value) = segment.get(0).unwrap();
+        assert!(!label.is_empty());
+        assert_eq!(segment.get_label(label), Some(value));
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_log() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let log = handle.error_log()?;
+        assert!(log.check());
+        // The instrument recorded no errors while acquiring small.RAW, its run header
+        // declares an error log of length zero.
+        assert!(log.is_empty());
+        assert_eq!(log.len(), 0);
+        assert_eq!(log.iter().count(), 0);
+        assert!(log.times().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_log_decoding() {
+        use crate::schema::{ErrorLogT, ErrorLogTArgs};
+
+        let times = [0.25, 1.5, 3.75];
+        let messages = ["Spray unstable", "Vacuum fault", "Spray restored"];
+
+        let mut builder = flatbuffers::FlatBufferBuilder::new();
+        let time_offsets = builder.create_vector(&times);
+        let message_offsets: Vec<_> = messages.iter().map(|m| builder.create_string(m)).collect();
+        let message_offsets = builder.create_vector(&message_offsets);
+        let offset = ErrorLogT::create(
+            &mut builder,
+            &ErrorLogTArgs {
+                times: Some(time_offsets),
+                messages: Some(message_offsets),
+            },
+        );
+        builder.finish(offset, None);
+        let log = ErrorLog::new(RawVec::from_vec(builder.finished_data().to_vec()));
+
+        assert!(log.check());
+        assert!(!log.is_empty());
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.times().as_ref(), &times);
+        let entries: Vec<_> = log.iter().collect();
+        assert_eq!(
+            entries,
+            times.iter().copied().zip(messages.iter().copied()).collect::<Vec<_>>()
+        );
+        assert_eq!(log.get(1), Some((1.5, "Vacuum fault")));
+        assert_eq!(log.get(3), None);
+    }
+
     #[test]
     fn test_instrument_model() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small.RAW")?;
-        let model = handle.instrument_model();
+        let model = handle.instrument_model()?;
 
         assert_eq!(model.model(), Some("LTQ FT"));
 
@@ -1722,7 +4054,7 @@ mod test {
     #[test]
     fn test_file_description() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small.RAW")?;
-        let fd = handle.file_description();
+        let fd = handle.file_description()?;
         assert_eq!(fd.sample_id(), Some("1"));
         assert_eq!(fd.source_file(), Some("../tests/data/small.RAW"));
         let counts = fd.spectra_per_ms_level().unwrap();
@@ -1731,6 +4063,59 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_file_header() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let fd = handle.file_description()?;
+        assert_eq!(fd.revision(), 57);
+        assert_eq!(fd.description(), Some(""));
+        assert_eq!(fd.creator_id(), Some("LTQ"));
+        assert_eq!(fd.creator_logon(), Some("LTQ"));
+        assert_eq!(fd.modifier_id(), Some("LTQ"));
+        assert_eq!(fd.modifier_logon(), Some("LTQ"));
+
+        // Acquired on 2005-07-20, allowing for the time zone the timestamp was recorded in
+        let created = fd.creation_timestamp().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert!((1121731200..1121990400).contains(&created));
+        let modified_after = fd
+            .modification_timestamp()
+            .duration_since(fd.creation_timestamp())
+            .unwrap()
+            .as_secs_f64();
+        assert!((modified_after - 43.031).abs() < 1e-2);
+
+        let run_header = fd.run_header().unwrap();
+        assert!(run_header.start_time() <= run_header.end_time());
+        assert!((run_header.start_time() - handle.get(0)?.time()).abs() < 1e-3);
+        assert!((run_header.end_time() - handle.get(47)?.time()).abs() < 1e-3);
+        assert!(run_header.low_mass() < run_header.high_mass());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_information() -> io::Result<()> {
+        let handle = RawFileReader::open("../tests/data/small.RAW")?;
+        let fd = handle.file_description()?;
+        assert_eq!(fd.sample_type(), SampleType::Unknown);
+        assert_eq!(fd.row_number(), 2);
+        assert_eq!(fd.sample_barcode(), Some(""));
+        assert_eq!(fd.injection_volume(), 0.0);
+        assert_eq!(fd.dilution_factor(), 1.0);
+        assert_eq!(fd.sample_weight(), 0.0);
+        assert_eq!(fd.sample_volume(), 0.0);
+        assert_eq!(fd.istd_amount(), 0.0);
+        assert_eq!(
+            fd.instrument_method_file(),
+            Some("C:\\Xcalibur\\methods\\20050720_idv_AnalyzeInfusion.meth")
+        );
+        assert_eq!(fd.processing_method_file(), Some(""));
+        let raw_file_path = fd.raw_file_path().unwrap();
+        assert!(raw_file_path.starts_with("C:\\Data\\20050720-aInfusionsForDarren"));
+        assert!(raw_file_path.ends_with("20050720data02.RAW"));
+        assert!(fd.sample_user_text().all(|text| text.is_empty()));
+        Ok(())
+    }
+
     #[test]
     fn test_parent_index() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small.RAW")?;
@@ -1746,7 +4131,7 @@ mod test {
     #[test]
     fn test_open_unicode_filename() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small_µ.RAW")?;
-        let fd = handle.file_description();
+        let fd = handle.file_description()?;
         assert_eq!(fd.sample_id(), Some("1"));
         assert_eq!(fd.source_file(), Some("../tests/data/small_µ.RAW"));
         let counts = fd.spectra_per_ms_level().unwrap();
@@ -1763,7 +4148,7 @@ mod test {
     #[test]
     fn test_status_logs() -> io::Result<()> {
         let handle = RawFileReader::open("../tests/data/small.RAW")?;
-        let logs = handle.get_status_logs().unwrap();
+        let logs = handle.get_status_logs()?;
         assert!(logs.check());
         let view = logs.view();
 
@@ -1814,6 +4199,10 @@ mod test {
             for (a, b) in s1.intensity().iter().zip(s2.intensity().iter()) {
                 assert_eq!(a, b);
             }
+            assert_eq!(s1.flags(), s2.flags());
+            if let Some(flags) = s2.flags() {
+                assert_eq!(flags.len(), s2.len());
+            }
         }
         Ok(())
     }